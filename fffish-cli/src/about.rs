@@ -0,0 +1,78 @@
+use std::fs;
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    text::Line,
+    widgets::{Block, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::{App, storage};
+
+impl App {
+    fn about_lines(&self) -> Vec<Line<'static>> {
+        let report = &self.data_report;
+        let saved = storage::settings_path()
+            .ok()
+            .and_then(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+            .map_or("never".to_string(), |time| {
+                DateTime::<Local>::from(time)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            });
+        let mut lines = vec![
+            Line::from(format!(
+                "fffish-cli {}, ffxivfishing {}",
+                env!("CARGO_PKG_VERSION"),
+                ffxivfishing::VERSION
+            )),
+            Line::from(format!(
+                "Data: {}",
+                self.data_source.version(&self.fish_data)
+            )),
+            Line::from(format!(
+                "Fish: {} loaded, {} skipped",
+                self.fish_data.fishes().len(),
+                report.skipped.len()
+            )),
+        ];
+        if !report.is_empty() {
+            lines.push(Line::from(format!(
+                "{} data values not understood, see check-data",
+                report.issues.len()
+            )));
+        }
+        if !self.fish_data.bite_times().is_empty() {
+            lines.push(Line::from(format!(
+                "Bite times: {} fish",
+                self.fish_data.bite_times().len()
+            )));
+        }
+        lines.push(Line::from(format!("Settings saved: {}", saved)));
+        if let Some((major, minor)) = self.fish_data.latest_patch() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(
+                "Fish added after patch {}.{} are missing. Update fffish-cli or load newer data with --data.",
+                major, minor
+            )));
+        }
+        lines
+    }
+
+    /// Draws the versions and data freshness over the middle of `area`.
+    pub(crate) fn render_about(&self, area: Rect, buf: &mut Buffer) {
+        let lines = self.about_lines();
+        let [area] = Layout::vertical([Constraint::Length(lines.len() as u16 + 4)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [area] = Layout::horizontal([Constraint::Percentage(60)])
+            .flex(Flex::Center)
+            .areas(area);
+        Clear.render(area, buf);
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(Block::bordered().title(format!(" {} ", self.tr("About (Esc: back)"))))
+            .render(area, buf);
+    }
+}
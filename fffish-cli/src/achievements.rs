@@ -0,0 +1,125 @@
+use std::collections::BTreeSet;
+
+use ffxivfishing::{
+    completion::{Completion, Progress},
+    fish::Expansion,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::App;
+
+fn progress_line(progress: Option<&Progress>, label: &str) -> Line<'static> {
+    let progress = progress.copied().unwrap_or_default();
+    let text = format!(
+        "{:<28}{:>4} / {:<4} {:>4} left",
+        label,
+        progress.caught,
+        progress.total,
+        progress.remaining()
+    );
+    match progress.is_complete() {
+        true => Line::styled(text, Style::from(Color::Green)),
+        false => Line::from(text),
+    }
+}
+
+const CELL_WIDTH: usize = 11;
+
+/// A heatmap cell: red for nothing caught through green for complete.
+fn heat_cell(progress: Option<&Progress>) -> Span<'static> {
+    let Some(progress) = progress else {
+        return Span::styled(
+            format!("{:^width$}", "-", width = CELL_WIDTH),
+            Style::from(Color::DarkGray),
+        );
+    };
+    let ratio = progress.ratio();
+    let background = Color::Rgb(
+        (200.0 * (1.0 - ratio)) as u8,
+        (40.0 + 160.0 * ratio) as u8,
+        40,
+    );
+    Span::styled(
+        format!(
+            "{:^width$}",
+            format!("{}/{}", progress.caught, progress.total),
+            width = CELL_WIDTH
+        ),
+        Style::new().fg(Color::Black).bg(background),
+    )
+}
+
+impl App {
+    pub(crate) fn render_achievements(&self, area: Rect, buf: &mut Buffer) {
+        let caught = self.user_data.caught.iter().copied().collect();
+        let completion = Completion::new(&self.fish_data, &caught);
+        let mut lines = vec![
+            progress_line(Some(&completion.all), "All fish"),
+            progress_line(Some(&completion.big_fish), "All big fish"),
+            Line::from(""),
+        ];
+        for expansion in Expansion::ALL {
+            lines.push(Line::from(expansion.to_string()));
+            lines.push(progress_line(
+                completion.by_expansion.get(&expansion),
+                "  Fish",
+            ));
+            lines.push(progress_line(
+                completion.big_fish_by_expansion.get(&expansion),
+                "  Big fish",
+            ));
+        }
+        Paragraph::new(lines)
+            .block(Block::bordered().title(format!(
+                " {} ",
+                self.tr("Progress (h: by patch, Esc: back)")
+            )))
+            .render(area, buf);
+    }
+
+    /// Caught and total fish of every patch, one row per expansion.
+    pub(crate) fn render_heatmap(&self, area: Rect, buf: &mut Buffer) {
+        let caught = self.user_data.caught.iter().copied().collect();
+        let completion = Completion::new(&self.fish_data, &caught);
+        let patches = &completion.by_patch;
+        let majors = patches.keys().map(|(major, _)| *major);
+        let (first, last) = (majors.clone().min(), majors.max());
+        // Point releases like 5.55 get their own column.
+        let minors: BTreeSet<u8> = patches.keys().map(|(_, minor)| *minor).collect();
+
+        let mut header = vec![Span::raw(format!("{:<8}", ""))];
+        for minor in &minors {
+            header.push(Span::raw(format!(
+                "{:^width$}",
+                format!(".{}", minor),
+                width = CELL_WIDTH
+            )));
+        }
+        let mut lines = vec![Line::from(header)];
+        if let (Some(first), Some(last)) = (first, last) {
+            for major in first..=last {
+                let mut row = vec![Span::raw(format!("{:<8}", format!("{}.x", major)))];
+                for minor in &minors {
+                    row.push(heat_cell(patches.get(&(major, *minor))));
+                }
+                row.push(Span::raw(format!(
+                    "  {}",
+                    Expansion::from_patch((major, 0))
+                )));
+                lines.push(Line::from(row));
+            }
+        }
+        Paragraph::new(lines)
+            .block(Block::bordered().title(format!(
+                " {} ",
+                self.tr("Caught by patch (h: totals, Esc: back)")
+            )))
+            .render(area, buf);
+    }
+}
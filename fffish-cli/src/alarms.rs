@@ -0,0 +1,682 @@
+use std::{
+    fmt::Display,
+    io::{Read, Write},
+    process::{Child, Command, Stdio},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use chrono::{DateTime, Local, Timelike, Utc};
+use ffxivfishing::{
+    display::RelativeStyle,
+    eorzea_time::{EorzeaDuration, EorzeaTime, EorzeaTimeSpan, corrected_now},
+    search::{SearchHorizon, WindowQuery},
+    weather::Weather,
+};
+use notify_rust::Notification;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Block, List, ListItem, StatefulWidget},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{App, AppView};
+
+const SNOOZE: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_LEAD_MINUTES: u32 = 5;
+
+/// How an alarm is delivered. The message is always shown in the status line
+/// as well.
+#[derive(PartialEq, Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub(crate) enum Channel {
+    #[default]
+    Bell,
+    Desktop,
+}
+
+impl Display for Channel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Channel::Bell => "Terminal bell",
+            Channel::Desktop => "Desktop notification",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl Channel {
+    fn next(&self) -> Channel {
+        match self {
+            Channel::Bell => Channel::Desktop,
+            Channel::Desktop => Channel::Bell,
+        }
+    }
+
+    fn send(&self, summary: &str, body: &str) -> Result<(), String> {
+        match self {
+            Channel::Bell => {
+                let mut stdout = std::io::stdout();
+                stdout
+                    .write_all(b"\x07")
+                    .and_then(|_| stdout.flush())
+                    .map_err(|e| e.to_string())
+            }
+            Channel::Desktop => Notification::new()
+                .summary(summary)
+                .body(body)
+                .show()
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// POSTs with curl in the background so a slow network does not hold up the
+/// UI. The URL goes through stdin to keep tokens in it out of `ps`. The
+/// returned process is polled by `App::check_posts`.
+fn curl_post(url: &str, args: &[String]) -> Result<Child, String> {
+    let mut child = Command::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--fail",
+            "--max-time",
+            "10",
+            "--config",
+            "-",
+        ])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("running curl failed: {}", e))?;
+    let config = format!("url = {}\n", serde_json::to_string(url).unwrap_or_default());
+    let written = child
+        .stdin
+        .take()
+        .map(|mut stdin| stdin.write_all(config.as_bytes()));
+    match written {
+        Some(Ok(())) => Ok(child),
+        _ => {
+            let _ = child.kill();
+            Err("passing the request to curl failed".to_string())
+        }
+    }
+}
+
+/// A notification POST still running, and the alarm message it is for.
+pub(crate) struct PendingPost {
+    message: String,
+    child: Child,
+}
+
+impl PendingPost {
+    /// `None` while curl runs, then its error if it failed.
+    fn finished(&mut self) -> Option<Result<(), String>> {
+        let status = match self.child.try_wait() {
+            Ok(None) => return None,
+            Ok(Some(status)) => status,
+            Err(e) => return Some(Err(e.to_string())),
+        };
+        if status.success() {
+            return Some(Ok(()));
+        }
+        let mut stderr = String::new();
+        if let Some(mut pipe) = self.child.stderr.take() {
+            let _ = pipe.read_to_string(&mut stderr);
+        }
+        Some(Err(match stderr.trim() {
+            "" => format!("curl exited with {}", status),
+            error => error.to_string(),
+        }))
+    }
+}
+
+/// A Telegram bot that receives every alarm on top of the alarm's own
+/// channel. Sending needs `curl` on the `PATH`.
+#[derive(Serialize, Deserialize, Clone)]
+pub(crate) struct TelegramConfig {
+    bot_token: String,
+    chat_id: String,
+}
+
+impl TelegramConfig {
+    fn send(&self, text: &str) -> Result<Child, String> {
+        curl_post(
+            &format!("https://api.telegram.org/bot{}/sendMessage", self.bot_token),
+            &[
+                "--data-urlencode".to_string(),
+                format!("chat_id={}", self.chat_id),
+                "--data-urlencode".to_string(),
+                format!("text={}", text),
+            ],
+        )
+    }
+}
+
+/// A URL that every alarm is POSTed to as JSON, e.g. for Home Assistant,
+/// ntfy.sh or Slack. `{message}`, `{name}`, `{zone}`, `{bait}`, `{start}`,
+/// `{end}`, `{start_unix}` and `{end_unix}` in the template are replaced,
+/// escaped for use inside JSON strings. Sending needs `curl` on the `PATH`.
+#[derive(Serialize, Deserialize, Clone)]
+pub(crate) struct WebhookConfig {
+    url: String,
+    #[serde(default = "default_template")]
+    template: String,
+}
+
+fn default_template() -> String {
+    r#"{"text": "{message}"}"#.to_string()
+}
+
+/// `template` with each `{name}` replaced by its value, escaped for use
+/// inside a JSON string.
+fn fill_template(template: &str, fields: &[(&str, String)]) -> String {
+    fields
+        .iter()
+        .fold(template.to_string(), |body, (name, value)| {
+            // The JSON string literal without its quotes.
+            let json = serde_json::to_string(value).unwrap_or_default();
+            body.replace(&format!("{{{}}}", name), &json[1..json.len() - 1])
+        })
+}
+
+impl WebhookConfig {
+    fn send(&self, fields: &[(&str, String)]) -> Result<Child, String> {
+        let body = fill_template(&self.template, fields);
+        curl_post(
+            &self.url,
+            &[
+                "--header".to_string(),
+                "Content-Type: application/json".to_string(),
+                "--data-binary".to_string(),
+                body,
+            ],
+        )
+    }
+}
+
+/// What an alarm waits for: a fish window, a weather in a zone or a custom
+/// entry by name.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub(crate) enum AlarmTarget {
+    Fish(u32),
+    Weather { region: u32, weather: Weather },
+    Custom(String),
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub(crate) struct Alarm {
+    target: AlarmTarget,
+    lead_minutes: u32,
+    channel: Channel,
+    /// Unix start time of the occurrence the alarm last fired for.
+    #[serde(default)]
+    fired_for: Option<u64>,
+    #[serde(default)]
+    snoozed_until: Option<u64>,
+}
+
+/// Local hours during which no alarm fires, e.g. 0 to 8 for the night.
+/// Wraps around midnight if `start` is after `end`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub(crate) struct QuietHours {
+    start: u8,
+    end: u8,
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        QuietHours { start: 0, end: 8 }
+    }
+}
+
+impl QuietHours {
+    fn contains(&self, hour: u8) -> bool {
+        match self.start <= self.end {
+            true => self.start <= hour && hour < self.end,
+            false => hour >= self.start || hour < self.end,
+        }
+    }
+}
+
+impl Display for QuietHours {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:00-{:02}:00", self.start, self.end)
+    }
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+impl App {
+    pub(crate) fn open_alarms(&mut self) {
+        self.view = AppView::Alarms;
+        if self.alarm_state.selected().is_none() {
+            self.alarm_state.select_first();
+        }
+    }
+
+    /// A description of the target and the start of its next occurrence,
+    /// which may already be ongoing.
+    fn next_occurrence(&self, target: &AlarmTarget) -> (String, Option<EorzeaTime>) {
+        let now = self.clock().now();
+        match target {
+            AlarmTarget::Fish(id) => match self.fish_data.fish_by_id(*id) {
+                Some(f) => (
+                    f.name().to_string(),
+                    f.next_window(&WindowQuery::new(now).include_ongoing(true))
+                        .ok()
+                        .map(|w| w.start()),
+                ),
+                None => (format!("#{}", id), None),
+            },
+            AlarmTarget::Weather { region, weather } => {
+                match self.fish_data.region_by_id(*region) {
+                    Some(r) => (
+                        format!("{} in {}", self.fish_data.weather_name(weather), r.name()),
+                        r.weather()
+                            .find_pattern(
+                                now,
+                                &[],
+                                std::slice::from_ref(weather),
+                                SearchHorizon::default(),
+                            )
+                            .ok(),
+                    ),
+                    None => (format!("Weather in #{}", region), None),
+                }
+            }
+            AlarmTarget::Custom(name) => (
+                name.clone(),
+                self.custom_by_name(name).and_then(|c| {
+                    c.condition
+                        .next_window(&WindowQuery::new(now).include_ongoing(true))
+                        .ok()
+                        .map(|w| w.start())
+                }),
+            ),
+        }
+    }
+
+    fn push_alarm(&mut self, target: AlarmTarget) {
+        let (name, _) = self.next_occurrence(&target);
+        if self.user_data.alarms.iter().any(|a| a.target == target) {
+            self.status = Some(self.trf("{} already has an alarm", &[&name]));
+            return;
+        }
+        self.user_data.alarms.push(Alarm {
+            target,
+            lead_minutes: DEFAULT_LEAD_MINUTES,
+            channel: Channel::default(),
+            fired_for: None,
+            snoozed_until: None,
+        });
+        let _ = self.save_user_data();
+        self.status = Some(self.trf("Alarm set for {}", &[&name]));
+    }
+
+    pub(crate) fn add_alarm(&mut self) {
+        let target = match self.get_selected_fish() {
+            Some(item) if self.is_custom(item.id) => AlarmTarget::Custom(item.name.clone()),
+            Some(item) => AlarmTarget::Fish(item.id),
+            None => return,
+        };
+        self.push_alarm(target);
+    }
+
+    pub(crate) fn add_weather_alarm(&mut self, region: u32, weather: Weather) {
+        self.push_alarm(AlarmTarget::Weather { region, weather });
+    }
+
+    fn selected_alarm(&mut self) -> Option<&mut Alarm> {
+        let i = self.alarm_state.selected()?;
+        self.user_data.alarms.get_mut(i)
+    }
+
+    pub(crate) fn delete_alarm(&mut self) {
+        if let Some(i) = self.alarm_state.selected()
+            && i < self.user_data.alarms.len()
+        {
+            self.user_data.alarms.remove(i);
+            let _ = self.save_user_data();
+        }
+    }
+
+    /// Snoozes the selected alarm; snoozing again adds to the snooze.
+    pub(crate) fn snooze_alarm(&mut self) {
+        if let Some(alarm) = self.selected_alarm() {
+            let now = unix_secs(corrected_now());
+            let from = alarm.snoozed_until.map_or(now, |until| until.max(now));
+            alarm.snoozed_until = Some(from + SNOOZE.as_secs());
+            let _ = self.save_user_data();
+        }
+    }
+
+    pub(crate) fn toggle_quiet_hours(&mut self) {
+        self.user_data.quiet_hours = match self.user_data.quiet_hours {
+            Some(_) => None,
+            None => Some(QuietHours::default()),
+        };
+        let _ = self.save_user_data();
+    }
+
+    /// Moves the start (or end) of the quiet hours by `hours`.
+    pub(crate) fn shift_quiet_hours(&mut self, end: bool, hours: i8) {
+        if let Some(quiet) = self.user_data.quiet_hours.as_mut() {
+            let hour = match end {
+                true => &mut quiet.end,
+                false => &mut quiet.start,
+            };
+            *hour = (*hour as i8 + hours).rem_euclid(24) as u8;
+            let _ = self.save_user_data();
+        }
+    }
+
+    /// Whether the local time is within the quiet hours.
+    fn is_quiet(&self) -> bool {
+        let Some(quiet) = self.user_data.quiet_hours else {
+            return false;
+        };
+        let now = DateTime::<Utc>::from(corrected_now());
+        let hour = match self.timezone {
+            Some(tz) => now.with_timezone(&tz).hour(),
+            None => now.with_timezone(&Local).hour(),
+        };
+        quiet.contains(hour as u8)
+    }
+
+    pub(crate) fn change_alarm_lead(&mut self, minutes: i64) {
+        if let Some(alarm) = self.selected_alarm() {
+            alarm.lead_minutes = alarm
+                .lead_minutes
+                .saturating_add_signed(minutes as i32)
+                .min(120);
+            let _ = self.save_user_data();
+        }
+    }
+
+    pub(crate) fn cycle_alarm_channel(&mut self) {
+        if let Some(alarm) = self.selected_alarm() {
+            alarm.channel = alarm.channel.next();
+            let _ = self.save_user_data();
+        }
+    }
+
+    /// The values webhook templates can use for an alarm about to fire.
+    fn webhook_fields(
+        &self,
+        target: &AlarmTarget,
+        name: &str,
+        message: &str,
+        start: EorzeaTime,
+    ) -> Vec<(&'static str, String)> {
+        let (end, zone, bait) = match target {
+            AlarmTarget::Fish(id) => {
+                let fish = self.fish_data.fish_by_id(*id);
+                let window = fish.and_then(|f| {
+                    f.next_window(&WindowQuery::new(start).include_ongoing(true))
+                        .ok()
+                });
+                (
+                    window.map_or(start, |w| w.end()),
+                    fish.map_or("".to_string(), |f| f.location.region().name().to_string()),
+                    fish.and_then(|f| f.bait_id())
+                        .and_then(|b| self.fish_data.item_by_id(b))
+                        .map_or("".to_string(), |b| b.name().to_string()),
+                )
+            }
+            AlarmTarget::Weather { region, .. } => (
+                start.next_weather_change(),
+                self.fish_data
+                    .region_by_id(*region)
+                    .map_or("".to_string(), |r| r.name().to_string()),
+                "".to_string(),
+            ),
+            AlarmTarget::Custom(name) => {
+                let custom = self.custom_by_name(name);
+                (
+                    custom
+                        .and_then(|c| {
+                            c.condition
+                                .next_window(&WindowQuery::new(start).include_ongoing(true))
+                                .ok()
+                        })
+                        .map_or(start, |w| w.end()),
+                    custom.map_or("".to_string(), |c| c.condition.region.name().to_string()),
+                    "".to_string(),
+                )
+            }
+        };
+        let clock = self.clock();
+        vec![
+            ("message", message.to_string()),
+            ("name", name.to_string()),
+            ("zone", zone),
+            ("bait", bait),
+            ("start", clock.time(start)),
+            ("end", clock.time(end)),
+            ("start_unix", unix_secs(start.to_system_time()).to_string()),
+            ("end_unix", unix_secs(end.to_system_time()).to_string()),
+        ]
+    }
+
+    /// Fires every alarm whose target starts within its lead time. Each
+    /// occurrence fires once; a snoozed alarm fires again when the snooze ends.
+    pub(crate) fn check_alarms(&mut self) {
+        // Alarms are about the real time, not a simulated one. During quiet
+        // hours they wait, and fire afterwards if still ahead of the window.
+        if !self.time_offset.is_zero() || self.is_quiet() {
+            return;
+        }
+        let now = unix_secs(corrected_now());
+        let clock = self.clock();
+        let upcoming: Vec<(String, Option<EorzeaTime>)> = self
+            .user_data
+            .alarms
+            .iter()
+            .map(|a| match a.target {
+                // Ignored fish stay silent until they are unignored, fish
+                // caught in their open window until it ends.
+                AlarmTarget::Fish(id) if self.is_ignored(id) || self.caught_this_window(id) => {
+                    (String::new(), None)
+                }
+                _ => self.next_occurrence(&a.target),
+            })
+            .collect();
+        let mut messages = vec![];
+        for (alarm, (name, start_time)) in self.user_data.alarms.iter_mut().zip(upcoming) {
+            let start_time = match start_time {
+                Some(t) => t,
+                None => continue,
+            };
+            let start = unix_secs(start_time.to_system_time());
+            let due = start.saturating_sub(alarm.lead_minutes as u64 * 60) <= now && now < start;
+            let fire = match alarm.snoozed_until {
+                _ if !due => false,
+                Some(until) => until <= now,
+                None => alarm.fired_for != Some(start),
+            };
+            if !fire {
+                continue;
+            }
+            alarm.fired_for = Some(start);
+            alarm.snoozed_until = None;
+            let mut body = format!(
+                "{} {} ({})",
+                name,
+                clock.relative(
+                    &EorzeaTimeSpan::new(start_time, EorzeaDuration::from_bells(0)),
+                    RelativeStyle::Long
+                ),
+                clock.time(start_time)
+            );
+            if let AlarmTarget::Fish(id) = alarm.target
+                && let Some(fish) = self.fish_data.fish_by_id(id)
+            {
+                body += &format!(" — {}", self.fish_data.conditions_summary(fish, self.lang));
+            }
+            messages.push((alarm.channel, alarm.target.clone(), name, start_time, body));
+        }
+        if messages.is_empty() {
+            return;
+        }
+        let _ = self.save_user_data();
+        let telegram = self.user_data.telegram.clone();
+        let webhooks = self.user_data.webhooks.clone();
+        for (channel, target, name, start, body) in messages {
+            let fields = self.webhook_fields(&target, &name, &body, start);
+            let title = self.tr("Fishing window");
+            let mut sent = channel.send(title, &body);
+            let telegram = telegram
+                .as_ref()
+                .map(|t| t.send(&format!("{}: {}", title, body)));
+            let webhooks = webhooks.iter().map(|w| w.send(&fields));
+            for post in telegram.into_iter().chain(webhooks) {
+                match post {
+                    Ok(child) => self.posts.push(PendingPost {
+                        message: body.clone(),
+                        child,
+                    }),
+                    Err(e) => sent = sent.and(Err(e)),
+                }
+            }
+            self.status = Some(match sent {
+                Ok(()) => body,
+                Err(e) => self.trf("{} (notification failed: {})", &[&body, &e]),
+            });
+        }
+    }
+
+    /// Reports Telegram and webhook requests that failed once they finish.
+    pub(crate) fn check_posts(&mut self) {
+        let mut failed = vec![];
+        self.posts.retain_mut(|post| match post.finished() {
+            None => true,
+            Some(Ok(())) => false,
+            Some(Err(e)) => {
+                failed.push((post.message.clone(), e));
+                false
+            }
+        });
+        for (message, e) in failed {
+            self.status = Some(self.trf("{} (notification failed: {})", &[&message, &e]));
+        }
+    }
+
+    pub(crate) fn render_alarms(&mut self, area: Rect, buf: &mut Buffer) {
+        let now = unix_secs(corrected_now());
+        let items: Vec<ListItem> = self
+            .user_data
+            .alarms
+            .iter()
+            .map(|alarm| {
+                let (name, start) = self.next_occurrence(&alarm.target);
+                let next = match (&alarm.target, start) {
+                    (AlarmTarget::Fish(id), _) => {
+                        self.fish_data.fish_by_id(*id).map_or("?".to_string(), |f| {
+                            self.cached_item(f).time_to_window_string()
+                        })
+                    }
+                    (AlarmTarget::Weather { .. }, Some(start)) => {
+                        self.trf("next {}", &[&self.clock().date_time(start)])
+                    }
+                    (AlarmTarget::Weather { .. }, None) => self.tr("not in sight").to_string(),
+                    (AlarmTarget::Custom(name), _) => self
+                        .custom_item_by_name(name)
+                        .map_or("?".to_string(), |item| item.time_to_window_string()),
+                };
+                let snoozed = match alarm.snoozed_until {
+                    Some(until) if until > now => {
+                        format!(
+                            " {}",
+                            self.trf("(snoozed {} min)", &[&(until - now).div_ceil(60)])
+                        )
+                    }
+                    _ => "".to_string(),
+                };
+                ListItem::new(self.trf(
+                    "{} - {} - {} min before via {}{}",
+                    &[
+                        &name,
+                        &next,
+                        &alarm.lead_minutes,
+                        &self.tr(&alarm.channel.to_string()),
+                        &snoozed,
+                    ],
+                ))
+            })
+            .collect();
+        let quiet = match self.user_data.quiet_hours {
+            Some(quiet) => format!(
+                " {} ",
+                self.trf(
+                    "Quiet hours {}{} (z: off, [/]: start, {/}: end)",
+                    &[
+                        &quiet,
+                        &match self.is_quiet() {
+                            true => self.tr(", now quiet"),
+                            false => "",
+                        },
+                    ],
+                )
+            ),
+            None => format!(" {} ", self.tr("No quiet hours (z: on)")),
+        };
+        StatefulWidget::render(
+            List::new(items)
+                .block(
+                    Block::bordered()
+                        .title(format!(
+                            " {} ",
+                            self.tr(
+                                "Alarms (m in list or zones: add, d: delete, s: snooze, +/-: lead time, c: channel, Esc: back)"
+                            )
+                        ))
+                        .title_bottom(quiet),
+                )
+                .highlight_symbol("> "),
+            area,
+            buf,
+            &mut self.alarm_state,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn webhook_template() {
+        let fields = [
+            ("name", r#"The "Big" One"#.to_string()),
+            ("message", "C:\\fish\nopens in 5 min".to_string()),
+        ];
+        let body = fill_template(
+            r#"{"title": "{name}", "text": "{message}", "x": "{zone}"}"#,
+            &fields,
+        );
+        assert_eq!(
+            body,
+            r#"{"title": "The \"Big\" One", "text": "C:\\fish\nopens in 5 min", "x": "{zone}"}"#
+        );
+        let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(parsed["title"], r#"The "Big" One"#);
+        assert_eq!(parsed["text"], "C:\\fish\nopens in 5 min");
+    }
+
+    #[test]
+    fn quiet_hours() {
+        let hours = |start, end| -> Vec<u8> {
+            let quiet = QuietHours { start, end };
+            (0..24).filter(|h| quiet.contains(*h)).collect()
+        };
+        assert_eq!(hours(0, 8), (0..8).collect::<Vec<u8>>());
+        assert_eq!(hours(22, 6), [0, 1, 2, 3, 4, 5, 22, 23]);
+        assert_eq!(hours(23, 0), [23]);
+        assert!(hours(7, 7).is_empty());
+    }
+}
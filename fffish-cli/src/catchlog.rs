@@ -0,0 +1,116 @@
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    path::PathBuf,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::App;
+
+/// Opt-in settings for watching a game or ACT log for catches.
+#[derive(Serialize, Deserialize, Clone)]
+pub(crate) struct CatchLogConfig {
+    pub(crate) path: PathBuf,
+    /// Text that starts a catch message, e.g. "You land" in the English client.
+    #[serde(default = "default_marker")]
+    marker: String,
+}
+
+fn default_marker() -> String {
+    "You land".to_string()
+}
+
+impl CatchLogConfig {
+    pub(crate) fn new(path: PathBuf) -> CatchLogConfig {
+        CatchLogConfig {
+            path,
+            marker: default_marker(),
+        }
+    }
+}
+
+/// Follows a log file from its current end, like `tail -f`.
+pub(crate) struct CatchLog {
+    config: CatchLogConfig,
+    position: Option<u64>,
+    partial: String,
+}
+
+impl CatchLog {
+    pub(crate) fn new(config: CatchLogConfig) -> CatchLog {
+        CatchLog {
+            config,
+            position: None,
+            partial: String::new(),
+        }
+    }
+
+    /// Complete lines appended since the last call. The first call only
+    /// remembers the end of the file so old catches are not replayed.
+    fn new_lines(&mut self) -> io::Result<Vec<String>> {
+        let mut file = File::open(&self.config.path)?;
+        let len = file.metadata()?.len();
+        let position = match self.position {
+            None => {
+                self.position = Some(len);
+                return Ok(vec![]);
+            }
+            // The log was truncated or rotated.
+            Some(p) if p > len => 0,
+            Some(p) => p,
+        };
+        file.seek(SeekFrom::Start(position))?;
+        let mut bytes = vec![];
+        file.read_to_end(&mut bytes)?;
+        self.position = Some(position + bytes.len() as u64);
+        self.partial += &String::from_utf8_lossy(&bytes);
+
+        let mut lines: Vec<String> = self.partial.split('\n').map(str::to_string).collect();
+        self.partial = lines.pop().unwrap_or_default();
+        Ok(lines)
+    }
+}
+
+impl App {
+    /// Marks fish caught that appear in new catch messages of the log.
+    pub(crate) fn check_catch_log(&mut self) {
+        let log = match self.catch_log.as_mut() {
+            Some(l) => l,
+            None => return,
+        };
+        let lines = match log.new_lines() {
+            Ok(lines) => lines,
+            Err(e) => {
+                self.status = Some(self.trf("Reading the catch log failed: {}", &[&e]));
+                return;
+            }
+        };
+        let marker = log.config.marker.to_lowercase();
+        let mut caught = vec![];
+        for line in lines {
+            let line = line.to_lowercase();
+            let message = match line.find(&marker) {
+                Some(i) => &line[i + marker.len()..],
+                None => continue,
+            };
+            // Prefer the longest name so "Garik Crab" is not taken for "Crab".
+            let fish = self
+                .fish_data
+                .fishes()
+                .iter()
+                .filter(|f| message.contains(&f.name().to_lowercase()))
+                .max_by_key(|f| f.name().len());
+            if let Some(f) = fish {
+                caught.push((f.id, f.name().to_string()));
+            }
+        }
+        for (id, name) in caught {
+            if !self.is_caught(id) {
+                self.toggle_caught(id);
+                self.item_cache = vec![];
+            }
+            self.status = Some(self.trf("Caught {} (from the game log)", &[&name]));
+        }
+    }
+}
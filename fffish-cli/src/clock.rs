@@ -0,0 +1,75 @@
+use chrono::{DateTime, Local, TimeDelta};
+use chrono_tz::Tz;
+pub(crate) use ffxivfishing::display::TimeDisplay;
+use ffxivfishing::{
+    display::{RelativeStyle, WindowDisplay, WindowFormat},
+    eorzea_time::{EorzeaTime, EorzeaTimeSpan, corrected_now},
+    lang::Lang,
+};
+use std::time::SystemTime;
+
+/// Formats times in one of the clocks, with local time optionally rendered in
+/// a configured timezone instead of the system one. Also the source of the
+/// current time, which may be shifted to simulate another moment.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Clock {
+    display: WindowDisplay,
+    offset: TimeDelta,
+}
+
+impl Clock {
+    pub(crate) fn new(display: TimeDisplay, timezone: Option<Tz>) -> Clock {
+        Clock {
+            display: WindowDisplay::new(display).timezone(timezone),
+            offset: TimeDelta::zero(),
+        }
+    }
+
+    pub(crate) fn with_offset(self, offset: TimeDelta) -> Clock {
+        Clock { offset, ..self }
+    }
+
+    pub(crate) fn with_lang(self, lang: Lang) -> Clock {
+        Clock {
+            display: self.display.lang(lang),
+            ..self
+        }
+    }
+
+    pub(crate) fn local_now(&self) -> DateTime<Local> {
+        DateTime::<Local>::from(corrected_now()) + self.offset
+    }
+
+    pub(crate) fn now(&self) -> EorzeaTime {
+        EorzeaTime::from_time(&SystemTime::from(self.local_now())).unwrap()
+    }
+
+    /// Time of day in this clock, e.g. "14:32 LT".
+    pub(crate) fn time(&self, time: EorzeaTime) -> String {
+        self.display.time(time)
+    }
+
+    pub(crate) fn date_time(&self, time: EorzeaTime) -> String {
+        self.display.date_time(time)
+    }
+
+    pub(crate) fn span(&self, span: &EorzeaTimeSpan) -> String {
+        self.display.span(span)
+    }
+
+    /// Countdown to `window` and its start, e.g. "in 5 min (14:32 LT)".
+    pub(crate) fn countdown(&self, window: &EorzeaTimeSpan) -> String {
+        self.display
+            .window(window, self.now(), WindowFormat::Combined)
+            .to_string()
+    }
+
+    /// Countdown to or since `span`, e.g. "opens in 5 min".
+    pub(crate) fn relative(&self, span: &EorzeaTimeSpan, style: RelativeStyle) -> String {
+        self.display.relative(span, self.now(), style)
+    }
+
+    pub(crate) fn label(&self) -> String {
+        self.display.label()
+    }
+}
@@ -0,0 +1,210 @@
+use std::time::SystemTime;
+
+use chrono::{DateTime, Days, NaiveTime, TimeZone};
+use ffxivfishing::{
+    display::{self, TimeDisplay},
+    eorzea_time::{EorzeaDuration, EorzeaTime},
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::Color,
+    text::Line,
+    widgets::{Block, Clear, Paragraph, Widget},
+};
+use tui_input::Input;
+
+use crate::{App, AppMode, clock::Clock};
+
+/// What the user typed into the converter.
+#[derive(PartialEq, Debug)]
+enum Query {
+    Eorzea { bell: u8, minute: u8 },
+    Local(NaiveTime),
+}
+
+/// Parses "14", "14:30" or "2:05 et". Times ending in "ET" are Eorzea
+/// time, anything else local time.
+fn parse_query(text: &str) -> Option<Query> {
+    let text = text.trim().to_lowercase();
+    let (text, eorzea) = match text.strip_suffix("et") {
+        Some(rest) => (rest.trim_end(), true),
+        None => (text.strip_suffix("lt").unwrap_or(&text).trim_end(), false),
+    };
+    let (bell, minute) = text.split_once(':').unwrap_or((text, "0"));
+    let bell: u8 = bell.trim().parse().ok().filter(|b| *b < 24)?;
+    let minute: u8 = minute.trim().parse().ok().filter(|m| *m < 60)?;
+    match eorzea {
+        true => Some(Query::Eorzea { bell, minute }),
+        false => NaiveTime::from_hms_opt(bell as u32, minute as u32, 0).map(Query::Local),
+    }
+}
+
+/// The first time at or after `now` when the wall clock of its timezone
+/// shows `time`.
+fn next_wall_time<Z: TimeZone>(now: DateTime<Z>, time: NaiveTime) -> Option<SystemTime> {
+    let today = now.date_naive().and_time(time);
+    let at = now.timezone().from_local_datetime(&today).earliest()?;
+    if at >= now {
+        return Some(at.into());
+    }
+    let tomorrow = today.checked_add_days(Days::new(1))?;
+    let at = now.timezone().from_local_datetime(&tomorrow).earliest()?;
+    Some(at.into())
+}
+
+impl App {
+    pub(crate) fn open_converter(&mut self) {
+        self.converter = Input::default();
+        self.mode = AppMode::Convert;
+    }
+
+    fn conversion(&self) -> Vec<Line<'static>> {
+        let local = Clock::new(TimeDisplay::Local, self.timezone).with_offset(self.time_offset);
+        let eorzea = Clock::new(TimeDisplay::Eorzea, None);
+        let now = local.now();
+        let until = |time: EorzeaTime| {
+            let wait = time
+                .duration_since(now)
+                .unwrap_or(EorzeaDuration::from_esecs(0));
+            display::duration_text(wait.to_real(), self.lang)
+        };
+        match parse_query(self.converter.value()) {
+            Some(Query::Eorzea { bell, minute }) => {
+                let next = now.next_time_of_day(bell, minute);
+                let mut lines = vec![Line::from(self.trf(
+                    "{} is next at {}, in {}",
+                    &[&eorzea.time(next), &local.date_time(next), &until(next)],
+                ))];
+                for sun in 1..=3 {
+                    let time = next + EorzeaDuration::from_suns(sun);
+                    lines.push(Line::from(format!(
+                        "  {}",
+                        self.trf("then {}", &[&local.date_time(time)])
+                    )));
+                }
+                lines
+            }
+            Some(Query::Local(time)) => {
+                let at = match self.timezone {
+                    Some(tz) => next_wall_time(local.local_now().with_timezone(&tz), time),
+                    None => next_wall_time(local.local_now(), time),
+                };
+                match at.and_then(|at| EorzeaTime::from_time(&at).ok()) {
+                    Some(at) => vec![Line::from(self.trf(
+                        "{} is {}, in {}",
+                        &[&local.date_time(at), &eorzea.time(at), &until(at)],
+                    ))],
+                    None => vec![Line::from(self.tr("That time does not exist here"))],
+                }
+            }
+            None => vec![Line::from(
+                self.trf("Now: {} / {}", &[&local.time(now), &eorzea.time(now)]),
+            )],
+        }
+    }
+
+    /// Draws the converter over the middle of `area`.
+    pub(crate) fn render_converter(&self, area: Rect, buf: &mut Buffer) {
+        let lines = self.conversion();
+        let [area] = Layout::vertical([Constraint::Length(lines.len() as u16 + 5)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [area] = Layout::horizontal([Constraint::Percentage(60)])
+            .flex(Flex::Center)
+            .areas(area);
+        Clear.render(area, buf);
+        let [input_area, result_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(area);
+        Paragraph::new(self.converter.value())
+            .style(Color::Blue)
+            .block(Block::bordered().title(format!(
+                " {} ",
+                self.tr("Convert time, e.g. 14:30 ET or 20:00 (Esc: close)")
+            )))
+            .render(input_area, buf);
+        Paragraph::new(lines)
+            .block(Block::bordered())
+            .render(result_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use chrono_tz::America::New_York;
+
+    use super::*;
+
+    fn hm(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn queries() {
+        assert_eq!(parse_query("14"), Some(Query::Local(hm(14, 0))));
+        assert_eq!(parse_query(" 14:30 lt "), Some(Query::Local(hm(14, 30))));
+        assert_eq!(
+            parse_query("14:30 et"),
+            Some(Query::Eorzea {
+                bell: 14,
+                minute: 30
+            })
+        );
+        assert_eq!(
+            parse_query("2:05 ET"),
+            Some(Query::Eorzea { bell: 2, minute: 5 })
+        );
+        assert_eq!(parse_query("25:00"), None);
+        assert_eq!(parse_query("12:60"), None);
+        assert_eq!(parse_query("noon"), None);
+        assert_eq!(parse_query(""), None);
+    }
+
+    #[test]
+    fn wall_times() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+        let at = |time| next_wall_time(now, time).map(DateTime::<Utc>::from);
+        assert_eq!(at(hm(12, 0)), Some(now));
+        assert_eq!(
+            at(hm(14, 30)),
+            Some(Utc.with_ymd_and_hms(2026, 1, 1, 14, 30, 0).unwrap())
+        );
+        assert_eq!(
+            at(hm(11, 59)),
+            Some(Utc.with_ymd_and_hms(2026, 1, 2, 11, 59, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn wall_times_across_dst() {
+        let new_york = |y, m, d, h, min| {
+            New_York
+                .from_local_datetime(
+                    &NaiveDate::from_ymd_opt(y, m, d)
+                        .unwrap()
+                        .and_time(hm(h, min)),
+                )
+                .earliest()
+                .unwrap()
+        };
+        // Clocks jump from 2:00 to 3:00 on 2026-03-08, so 2:30 is skipped.
+        assert_eq!(next_wall_time(new_york(2026, 3, 8, 1, 0), hm(2, 30)), None);
+        assert_eq!(next_wall_time(new_york(2026, 3, 7, 3, 0), hm(2, 30)), None);
+        assert_eq!(
+            next_wall_time(new_york(2026, 3, 8, 1, 0), hm(3, 30)),
+            Some(new_york(2026, 3, 8, 3, 30).into())
+        );
+        // They fall back from 2:00 to 1:00 on 2026-11-01; 1:30 happens twice
+        // and the first is next.
+        let first = new_york(2026, 11, 1, 1, 30);
+        assert_eq!(
+            next_wall_time(new_york(2026, 11, 1, 0, 0), hm(1, 30)),
+            Some(first.into())
+        );
+        assert_eq!(
+            first.with_timezone(&Utc),
+            Utc.with_ymd_and_hms(2026, 11, 1, 5, 30, 0).unwrap()
+        );
+    }
+}
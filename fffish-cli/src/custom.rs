@@ -0,0 +1,211 @@
+use std::rc::Rc;
+
+use ffxivfishing::{
+    conditions::ConditionWindow, eorzea_time::EorzeaDuration, fish::FishData, search::WindowQuery,
+    weather::Weather,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::Line,
+    widgets::{Block, Padding, Paragraph, Widget, Wrap},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    App, FishListItem,
+    clock::{Clock, TimeDisplay},
+    focus::Pane,
+    weather_sets_text,
+};
+
+/// List ids of custom entries start here, far above any item id.
+const CUSTOM_ID_BASE: u32 = 1 << 31;
+
+/// A condition from the settings that is listed, sorted and alarmed like a
+/// fish, e.g. an S rank spawn:
+///
+/// ```toml
+/// [[custom]]
+/// name = "Okina"
+/// zone = "Yanxia"
+/// hours = [18, 5]
+/// weather = ["Clear Skies"]
+/// ```
+#[derive(Serialize, Deserialize, Clone)]
+pub(crate) struct CustomEntry {
+    name: String,
+    /// Region name, ignoring case.
+    zone: String,
+    /// Eorzea bells from and to. Any time of day if missing.
+    #[serde(default)]
+    hours: Option<(u8, u8)>,
+    /// Weather names, any weather if empty.
+    #[serde(default)]
+    weather: Vec<String>,
+    #[serde(default)]
+    previous_weather: Vec<String>,
+}
+
+/// A custom entry whose zone and weathers were found.
+pub(crate) struct Custom {
+    pub(crate) name: String,
+    pub(crate) condition: ConditionWindow,
+}
+
+fn weathers(names: &[String]) -> Result<Vec<Weather>, String> {
+    names
+        .iter()
+        .map(|name| {
+            Weather::all()
+                .find(|w| w.name().is_some_and(|n| n.eq_ignore_ascii_case(name)))
+                .ok_or_else(|| format!("unknown weather {}", name))
+        })
+        .collect()
+}
+
+impl CustomEntry {
+    fn resolve(&self, data: &FishData) -> Result<Custom, String> {
+        let region = data
+            .regions()
+            .iter()
+            .find(|r| r.name().eq_ignore_ascii_case(&self.zone))
+            .ok_or_else(|| format!("unknown zone {}", self.zone))?;
+        let bell = |bell: u8| EorzeaDuration::new(bell, 0, 0).map_err(|_| "invalid hours");
+        let et_range = match self.hours {
+            Some((start, end)) => Some((bell(start)?, bell(end)?)),
+            None => None,
+        };
+        Ok(Custom {
+            name: self.name.clone(),
+            condition: ConditionWindow {
+                et_range,
+                previous_weather: weathers(&self.previous_weather)?,
+                weather: weathers(&self.weather)?,
+                region: Rc::clone(region),
+            },
+        })
+    }
+}
+
+impl App {
+    /// Resolves the configured custom entries, reporting the first one that
+    /// cannot be.
+    pub(crate) fn load_custom(&mut self) {
+        self.custom = vec![];
+        for entry in &self.user_data.custom {
+            match entry.resolve(&self.fish_data) {
+                Ok(custom) => self.custom.push(custom),
+                Err(e) => {
+                    let message = self.trf("Custom entry {} skipped: {}", &[&entry.name, &e]);
+                    self.status.get_or_insert(message);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn is_custom(&self, id: u32) -> bool {
+        id >= CUSTOM_ID_BASE
+    }
+
+    fn custom_by_id(&self, id: u32) -> Option<&Custom> {
+        let index = id.checked_sub(CUSTOM_ID_BASE)?;
+        self.custom.get(index as usize)
+    }
+
+    pub(crate) fn custom_by_name(&self, name: &str) -> Option<&Custom> {
+        self.custom.iter().find(|c| c.name == name)
+    }
+
+    pub(crate) fn custom_item_by_name(&self, name: &str) -> Option<FishListItem> {
+        let index = self.custom.iter().position(|c| c.name == name)?;
+        Some(self.custom_item(index, &self.custom[index]))
+    }
+
+    fn custom_item(&self, index: usize, custom: &Custom) -> FishListItem {
+        let condition = &custom.condition;
+        FishListItem {
+            name: custom.name.clone(),
+            id: CUSTOM_ID_BASE + index as u32,
+            bait: None,
+            next_window: condition
+                .next_window(&WindowQuery::new(self.clock().now()).include_ongoing(true))
+                .ok(),
+            rarity: condition.rarity(),
+            favourite: false,
+            caught: false,
+            caught_this_window: false,
+            weather: weather_sets_text(
+                &condition.previous_weather,
+                &condition.weather,
+                ", ",
+                |w| {
+                    w.abbreviation()
+                        .map_or_else(|| self.fish_data.weather_name(w), str::to_string)
+                },
+            ),
+            clock: self.clock(),
+            lang: self.lang,
+            custom: true,
+        }
+    }
+
+    /// List items of the custom entries whose name contains `search`.
+    pub(crate) fn custom_items(&self, search: &str) -> Vec<FishListItem> {
+        self.custom
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.name.contains(search))
+            .map(|(i, c)| self.custom_item(i, c))
+            .collect()
+    }
+
+    /// The selected fish's id. Custom entries have no caught, favourite or
+    /// ignored state, so selecting one gives `None` and says so.
+    pub(crate) fn selected_fish_id(&mut self) -> Option<u32> {
+        let item = self.get_selected_fish()?;
+        if self.is_custom(item.id) {
+            self.status = Some(self.trf("{} is a custom entry", &[&item.name]));
+            return None;
+        }
+        Some(item.id)
+    }
+
+    pub(crate) fn render_custom_info(&self, item: &FishListItem, area: Rect, buf: &mut Buffer) {
+        let Some(custom) = self.custom_by_id(item.id) else {
+            return;
+        };
+        let condition = &custom.condition;
+        let hours = match condition.et_range {
+            Some((start, end)) => format!("{} - {}", start, end),
+            None => "any time".to_string(),
+        };
+        let mut lines = vec![
+            Line::from(format!("Zone: {}", condition.region.name())),
+            Line::from(format!("{}: {}", self.tr("Window"), hours)),
+        ];
+        if let Some(weather) =
+            weather_sets_text(&condition.previous_weather, &condition.weather, ", ", |w| {
+                self.fish_data.weather_name(w)
+            })
+        {
+            lines.push(Line::from(format!("{}: {}", self.tr("Weather"), weather)));
+        }
+        lines.push(Line::default());
+        lines.push(Line::from(format!(
+            "{}: {}",
+            self.tr("Next"),
+            item.window_span(Clock::new(TimeDisplay::Local, self.timezone))
+        )));
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::bordered()
+                    .title(format!(" {} ", item.name))
+                    .title_bottom(Line::from(" Custom entry ").right_aligned())
+                    .border_style(self.pane_style(Pane::Details))
+                    .padding(Padding::new(1, 0, 0, 0)),
+            )
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Local};
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, eyre},
+};
+use ffxivfishing::{
+    bite::BiteTimes,
+    carbuncledata::{DataReport, builtin_data, carbuncle_fishes_with_report},
+    fish::FishData,
+};
+
+/// Where the fish data was loaded from.
+pub(crate) enum DataSource {
+    BuiltIn,
+    File(PathBuf),
+}
+
+impl DataSource {
+    /// Loads the data along with the values the loader did not understand.
+    pub(crate) fn load(&self) -> Result<(FishData, DataReport)> {
+        match self {
+            DataSource::BuiltIn => carbuncle_fishes_with_report(builtin_data()),
+            DataSource::File(path) => carbuncle_fishes_with_report(
+                &fs::read_to_string(path)
+                    .wrap_err_with(|| format!("Reading {} failed", path.display()))?,
+            ),
+        }
+        .map_err(|e| eyre!("Parsing the fish data failed: {}", e))
+    }
+
+    /// Data version shown in the UI. The data carries no version itself, so
+    /// this is the newest patch it covers plus the file date if available.
+    pub(crate) fn version(&self, fish_data: &FishData) -> String {
+        let patch = fish_data
+            .latest_patch()
+            .map_or("no fish".to_string(), |(major, minor)| {
+                format!("patch {}.{}", major, minor)
+            });
+        let modified = match self {
+            DataSource::BuiltIn => None,
+            DataSource::File(path) => fs::metadata(path).and_then(|m| m.modified()).ok(),
+        };
+        match modified {
+            Some(time) => format!(
+                "{}, {}, modified {}",
+                self,
+                patch,
+                DateTime::<Local>::from(time).format("%Y-%m-%d")
+            ),
+            None => format!("{}, {}", self, patch),
+        }
+    }
+}
+
+/// Reads bite times from a JSON map of fish ids to `[min, max]` seconds.
+pub(crate) fn load_bite_times(path: &Path) -> Result<BiteTimes> {
+    let text =
+        fs::read_to_string(path).wrap_err_with(|| format!("Reading {} failed", path.display()))?;
+    serde_json::from_str(&text)
+        .wrap_err_with(|| format!("Parsing the bite times in {} failed", path.display()))
+}
+
+/// `fffish-cli check-data`: prints every value the loader did not understand
+/// and fails if there was any.
+pub(crate) fn check_data(report: &DataReport) -> Result<()> {
+    for issue in &report.issues {
+        println!("{}", issue);
+    }
+    match report.issues.len() {
+        0 => {
+            println!("No problems found");
+            Ok(())
+        }
+        n => Err(eyre!("{} values not understood", n)),
+    }
+}
+
+impl Display for DataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataSource::BuiltIn => write!(f, "built-in"),
+            DataSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
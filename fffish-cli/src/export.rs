@@ -0,0 +1,71 @@
+//! `fffish-cli export`: writes the upcoming windows of the favourites or of
+//! every uncaught fish to a calendar or spreadsheet, without the TUI.
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+};
+
+use clap::ValueEnum;
+use color_eyre::{Result, eyre::WrapErr};
+use ffxivfishing::{
+    eorzea_time::{EorzeaDuration, EorzeaTime, corrected_now},
+    export,
+    fish::FishData,
+    lang::Lang,
+};
+
+use crate::UserData;
+
+#[derive(PartialEq, Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum ExportFormat {
+    Ics,
+    Csv,
+    Json,
+}
+
+/// Writes the windows opening in the next `hours` to `file`, or to stdout
+/// if it is `-`. Without `favorites`, every uncaught fish that is not
+/// ignored or always available is included.
+pub(crate) fn export_windows(
+    fish_data: &FishData,
+    user_data: &UserData,
+    favorites: bool,
+    hours: u64,
+    format: ExportFormat,
+    file: &Path,
+) -> Result<()> {
+    let now = corrected_now();
+    let start = EorzeaTime::from_time(&now).wrap_err("The system clock is before 1970")?;
+    let end = start + EorzeaDuration::from_real(Duration::from_secs(hours * 3600));
+    let windows = fish_data.windows_between(start, end, |fish| match favorites {
+        true => user_data.favorites.contains(fish.id),
+        false => {
+            !user_data.caught.contains(&fish.id)
+                && !user_data.ignored.contains(fish.id)
+                && !fish.always_available()
+        }
+    });
+    let lang = user_data
+        .language
+        .as_deref()
+        .and_then(Lang::from_code)
+        .unwrap_or_default();
+    let text = match format {
+        ExportFormat::Ics => export::to_ics(fish_data, &windows, lang, now),
+        ExportFormat::Csv => export::to_csv(fish_data, &windows, lang),
+        ExportFormat::Json => export::to_json(fish_data, &windows, lang),
+    };
+    if file == Path::new("-") {
+        // Piping into `head` closes stdout early, which is fine.
+        return match io::stdout().write_all(text.as_bytes()) {
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+            result => result.wrap_err("Writing to stdout failed"),
+        };
+    }
+    fs::write(file, text).wrap_err_with(|| format!("Writing {} failed", file.display()))?;
+    println!("Exported {} windows to {}", windows.len(), file.display());
+    Ok(())
+}
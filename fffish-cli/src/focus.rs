@@ -0,0 +1,70 @@
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    style::{Color, Style},
+};
+
+use crate::{App, AppMode};
+
+/// The pane of the list view that receives the keys.
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub(crate) enum Pane {
+    #[default]
+    List,
+    Details,
+}
+
+impl Pane {
+    /// In the order Tab moves through them.
+    const ALL: [Pane; 2] = [Pane::List, Pane::Details];
+
+    fn cycle(self, forward: bool) -> Pane {
+        let i = Pane::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        let n = Pane::ALL.len();
+        match forward {
+            true => Pane::ALL[(i + 1) % n],
+            false => Pane::ALL[(i + n - 1) % n],
+        }
+    }
+}
+
+impl App {
+    pub(crate) fn focus(&mut self, pane: Pane) {
+        self.focus = pane;
+        // The scroll position belongs to the fish that was selected.
+        self.info_scroll = 0;
+    }
+
+    pub(crate) fn cycle_focus(&mut self, forward: bool) {
+        self.focus(self.focus.cycle(forward));
+    }
+
+    /// Border style of `pane`, highlighted while it has the keys.
+    pub(crate) fn pane_style(&self, pane: Pane) -> Style {
+        match self.mode == AppMode::List && self.focus == pane {
+            true => Color::Blue.into(),
+            false => Style::default(),
+        }
+    }
+
+    pub(crate) fn handle_details_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.info_scroll = self.info_scroll.saturating_add(1)
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.info_scroll = self.info_scroll.saturating_sub(1)
+            }
+            KeyCode::PageDown => {
+                self.info_scroll = self.info_scroll.saturating_add(self.info_height)
+            }
+            KeyCode::PageUp => self.info_scroll = self.info_scroll.saturating_sub(self.info_height),
+            KeyCode::Char('g') => self.info_scroll = 0,
+            // Clamped to the content when drawn.
+            KeyCode::Char('G') => self.info_scroll = u16::MAX,
+            KeyCode::Tab => self.cycle_focus(true),
+            KeyCode::BackTab => self.cycle_focus(false),
+            KeyCode::Esc => self.focus(Pane::List),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use ffxivfishing::lookup::NameMatch;
+use tui_input::Input;
+
+use crate::{App, AppMode};
+
+impl App {
+    /// Adds a digit to the count typed before a motion, as in `12j`.
+    pub(crate) fn push_count_digit(&mut self, digit: u32) {
+        let count = self.count.take().unwrap_or(0);
+        self.count = Some(count.saturating_mul(10).saturating_add(digit as usize));
+    }
+
+    /// Moves the selection `count` rows down or up.
+    pub(crate) fn move_selection(&mut self, count: usize, down: bool) {
+        let count = count.min(u16::MAX as usize) as u16;
+        match down {
+            true => self.list_state.scroll_down_by(count),
+            false => self.list_state.scroll_up_by(count),
+        }
+    }
+
+    /// Selects the `row`th fish, counting from 1.
+    pub(crate) fn select_row(&mut self, row: usize) {
+        let last = self.item_cache.len().saturating_sub(1);
+        self.list_state
+            .select(Some(row.saturating_sub(1).min(last)));
+    }
+
+    pub(crate) fn open_goto(&mut self) {
+        self.goto = Input::default();
+        self.mode = AppMode::Goto;
+    }
+
+    /// Selects the fish whose id or exact name was typed at the prompt.
+    pub(crate) fn finish_goto(&mut self) {
+        self.mode = AppMode::List;
+        let query = self.goto.value().trim();
+        let fish = match self.fish_data.resolve_fish(query) {
+            Some((fish, NameMatch::Id | NameMatch::Exact)) => fish,
+            _ => {
+                self.status = Some(self.trf("No fish with id or name {}", &[&query]));
+                return;
+            }
+        };
+        match self.item_cache.iter().position(|item| item.id == fish.id) {
+            Some(i) => self.list_state.select(Some(i)),
+            None => {
+                self.status = Some(self.trf(
+                    "{} is hidden by the search or filter",
+                    &[&self.fish_name(fish)],
+                ))
+            }
+        }
+    }
+}
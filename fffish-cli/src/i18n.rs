@@ -0,0 +1,806 @@
+use std::fmt::Display;
+
+use ffxivfishing::{
+    fish::{Fish, Item},
+    lang::Lang,
+};
+
+use crate::App;
+
+/// UI texts by their English original, followed by Japanese, German, French
+/// and Korean.
+const TABLE: &[(&str, [&str; 4])] = &[
+    ("Window", ["時間帯", "Zeitfenster", "Créneau", "시간대"]),
+    ("Weather", ["天候", "Wetter", "Météo", "날씨"]),
+    ("Next", ["次回", "Nächstes", "Prochain", "다음"]),
+    ("Bait", ["釣り餌", "Köder", "Appât", "미끼"]),
+    ("unknown", ["不明", "unbekannt", "inconnu", "알 수 없음"]),
+    ("Tug", ["アタリ", "Biss", "Touche", "입질"]),
+    ("Hookset", ["フッキング", "Anhieb", "Ferrage", "낚아채기"]),
+    ("needs", ["必要", "benötigt", "nécessite", "필요"]),
+    (
+        "Folklore",
+        ["伝承録", "Überlieferung", "Folklore", "전승록"],
+    ),
+    ("Caught", ["釣り済み", "Gefangen", "Attrapé", "낚음"]),
+    ("Closed", ["時間外", "Geschlossen", "Fermé", "닫힘"]),
+    (
+        "Also here, uncaught",
+        [
+            "この釣り場の未釣り",
+            "Auch hier, noch nicht gefangen",
+            "Aussi ici, pas encore attrapés",
+            "이곳의 미포획 물고기",
+        ],
+    ),
+    ("Data", ["データ", "Daten", "Données", "데이터"]),
+    ("Search", ["検索", "Suche", "Recherche", "검색"]),
+    ("Filter", ["フィルター", "Filter", "Filtre", "필터"]),
+    ("Sort", ["並び順", "Sortierung", "Tri", "정렬"]),
+    ("Time", ["時刻", "Zeit", "Heure", "시간"]),
+    ("None", ["なし", "Keiner", "Aucun", "없음"]),
+    (
+        "Uncaught",
+        ["未釣り", "Nicht gefangen", "Non attrapés", "미포획"],
+    ),
+    (
+        "Favorite",
+        ["お気に入り", "Favoriten", "Favoris", "즐겨찾기"],
+    ),
+    ("Ignored", ["無視", "Ignoriert", "Ignorés", "무시됨"]),
+    (
+        "Next window",
+        [
+            "次の時間帯",
+            "Nächstes Fenster",
+            "Prochain créneau",
+            "다음 시간대",
+        ],
+    ),
+    ("Name", ["名前", "Name", "Nom", "이름"]),
+    ("Duration", ["長さ", "Dauer", "Durée", "기간"]),
+    ("Rarity", ["希少度", "Seltenheit", "Rareté", "희귀도"]),
+    (
+        "no always up",
+        [
+            "常時を除く",
+            "ohne immer offene",
+            "sans les permanents",
+            "상시 제외",
+        ],
+    ),
+    (
+        "favourites pinned",
+        [
+            "お気に入り固定",
+            "Favoriten oben",
+            "favoris épinglés",
+            "즐겨찾기 고정",
+        ],
+    ),
+    (
+        "Bites after {}-{} s",
+        [
+            "{}〜{}秒で食いつく",
+            "Biss nach {}-{} s",
+            "Touche après {}-{} s",
+            "{}-{}초 후 입질",
+        ],
+    ),
+    (
+        "~{} casts possible",
+        [
+            "約{}回キャスト可能",
+            "~{} Würfe möglich",
+            "~{} lancers possibles",
+            "약 {}회 캐스팅 가능",
+        ],
+    ),
+    (
+        "outside its hours",
+        [
+            "時間外",
+            "außerhalb der Zeit",
+            "hors de ses heures",
+            "시간 외",
+        ],
+    ),
+    ("{} now", ["現在{}", "jetzt {}", "{} maintenant", "현재 {}"]),
+    (
+        "{} before",
+        ["直前が{}", "vorher {}", "{} avant", "이전 {}"],
+    ),
+    (
+        "{} of {}, refreshed {} (r)",
+        [
+            "{}/{}、{}に更新 (r)",
+            "{} von {}, aktualisiert {} (r)",
+            "{} sur {}, actualisé à {} (r)",
+            "{}/{}, {} 갱신 (r)",
+        ],
+    ),
+    (
+        "Simulating {} (<, >, =)",
+        [
+            "{}を再現中 (<, >, =)",
+            "Simuliere {} (<, >, =)",
+            "Simulation de {} (<, >, =)",
+            "{} 시뮬레이션 중 (<, >, =)",
+        ],
+    ),
+    (
+        "Fishing window",
+        [
+            "釣りの時間帯",
+            "Angelfenster",
+            "Créneau de pêche",
+            "낚시 시간대",
+        ],
+    ),
+    (
+        "{} already has an alarm",
+        [
+            "{}にはアラームが設定済み",
+            "{} hat schon einen Alarm",
+            "{} a déjà une alarme",
+            "{}에 이미 알람이 있음",
+        ],
+    ),
+    (
+        "Alarm set for {}",
+        [
+            "{}のアラームを設定",
+            "Alarm für {} gesetzt",
+            "Alarme réglée pour {}",
+            "{} 알람 설정됨",
+        ],
+    ),
+    (
+        "{} (notification failed: {})",
+        [
+            "{}(通知失敗: {})",
+            "{} (Benachrichtigung fehlgeschlagen: {})",
+            "{} (échec de la notification : {})",
+            "{} (알림 실패: {})",
+        ],
+    ),
+    (
+        "Saved preset {}",
+        [
+            "プリセット{}を保存",
+            "Vorlage {} gespeichert",
+            "Préréglage {} enregistré",
+            "프리셋 {} 저장됨",
+        ],
+    ),
+    (
+        "Saving preset failed: {}",
+        [
+            "プリセットの保存に失敗: {}",
+            "Speichern der Vorlage fehlgeschlagen: {}",
+            "Échec de l'enregistrement du préréglage : {}",
+            "프리셋 저장 실패: {}",
+        ],
+    ),
+    (
+        "Writing the overlay failed: {}",
+        [
+            "オーバーレイの書き込みに失敗: {}",
+            "Schreiben des Overlays fehlgeschlagen: {}",
+            "Échec de l'écriture de l'overlay : {}",
+            "오버레이 쓰기 실패: {}",
+        ],
+    ),
+    (
+        "Writing the journal failed: {}",
+        [
+            "ジャーナルの書き込みに失敗: {}",
+            "Schreiben des Journals fehlgeschlagen: {}",
+            "Échec de l'écriture du journal : {}",
+            "저널 쓰기 실패: {}",
+        ],
+    ),
+    (
+        "No fish with id or name {}",
+        [
+            "IDまたは名前が{}の魚はいません",
+            "Kein Fisch mit ID oder Name {}",
+            "Aucun poisson avec l'ID ou le nom {}",
+            "ID 또는 이름이 {}인 물고기 없음",
+        ],
+    ),
+    (
+        "{} is hidden by the search or filter",
+        [
+            "{}は検索かフィルターで非表示です",
+            "{} ist durch Suche oder Filter ausgeblendet",
+            "{} est masqué par la recherche ou le filtre",
+            "{}은(는) 검색 또는 필터로 숨겨짐",
+        ],
+    ),
+    (
+        "Caught {}",
+        [
+            "{}を釣り済みにしました",
+            "{} gefangen",
+            "{} attrapé",
+            "{} 낚음",
+        ],
+    ),
+    (
+        "Caught {} (from the game log)",
+        [
+            "{}を釣り済みにしました(ゲームログより)",
+            "{} gefangen (aus dem Spiellog)",
+            "{} attrapé (d'après le journal du jeu)",
+            "{} 낚음 (게임 로그)",
+        ],
+    ),
+    (
+        "Restored {} unsaved changes",
+        [
+            "未保存の変更を{}件復元しました",
+            "{} ungespeicherte Änderungen wiederhergestellt",
+            "{} modifications non enregistrées restaurées",
+            "저장되지 않은 변경 {}건 복원됨",
+        ],
+    ),
+    (
+        "Refreshed",
+        ["更新しました", "Aktualisiert", "Actualisé", "갱신됨"],
+    ),
+    (
+        "Ignored, F shows the ignored fish",
+        [
+            "無視しました。Fで無視した魚を表示",
+            "Ignoriert, F zeigt ignorierte Fische",
+            "Ignoré, F affiche les poissons ignorés",
+            "무시됨, F로 무시한 물고기 표시",
+        ],
+    ),
+    (
+        "No longer ignored",
+        [
+            "無視を解除しました",
+            "Nicht mehr ignoriert",
+            "N'est plus ignoré",
+            "무시 해제됨",
+        ],
+    ),
+    (
+        "Custom entry {} skipped: {}",
+        [
+            "カスタム項目{}をスキップ: {}",
+            "Eigener Eintrag {} übersprungen: {}",
+            "Entrée personnalisée {} ignorée : {}",
+            "사용자 항목 {} 건너뜀: {}",
+        ],
+    ),
+    (
+        "{} is a custom entry",
+        [
+            "{}はカスタム項目です",
+            "{} ist ein eigener Eintrag",
+            "{} est une entrée personnalisée",
+            "{}은(는) 사용자 항목임",
+        ],
+    ),
+    (
+        "Copied to clipboard",
+        [
+            "クリップボードにコピーしました",
+            "In die Zwischenablage kopiert",
+            "Copié dans le presse-papiers",
+            "클립보드에 복사됨",
+        ],
+    ),
+    (
+        "Copy failed: {}",
+        [
+            "コピーに失敗: {}",
+            "Kopieren fehlgeschlagen: {}",
+            "Échec de la copie : {}",
+            "복사 실패: {}",
+        ],
+    ),
+    (
+        "No clipboard available",
+        [
+            "クリップボードを使用できません",
+            "Keine Zwischenablage verfügbar",
+            "Aucun presse-papiers disponible",
+            "클립보드를 사용할 수 없음",
+        ],
+    ),
+    (
+        "Reading the clipboard failed: {}",
+        [
+            "クリップボードの読み込みに失敗: {}",
+            "Lesen der Zwischenablage fehlgeschlagen: {}",
+            "Échec de la lecture du presse-papiers : {}",
+            "클립보드 읽기 실패: {}",
+        ],
+    ),
+    (
+        "Open: t Teamcraft, c Carbuncle Plushy, g GarlandTools",
+        [
+            "開く: t Teamcraft, c Carbuncle Plushy, g GarlandTools",
+            "Öffnen: t Teamcraft, c Carbuncle Plushy, g GarlandTools",
+            "Ouvrir : t Teamcraft, c Carbuncle Plushy, g GarlandTools",
+            "열기: t Teamcraft, c Carbuncle Plushy, g GarlandTools",
+        ],
+    ),
+    (
+        "Opening {} failed: {}",
+        [
+            "{}を開けませんでした: {}",
+            "Öffnen von {} fehlgeschlagen: {}",
+            "Échec de l'ouverture de {} : {}",
+            "{} 열기 실패: {}",
+        ],
+    ),
+    (
+        "Reading the catch log failed: {}",
+        [
+            "釣果ログの読み込みに失敗: {}",
+            "Lesen des Fanglogs fehlgeschlagen: {}",
+            "Échec de la lecture du journal de pêche : {}",
+            "낚시 로그 읽기 실패: {}",
+        ],
+    ),
+    (
+        "Exported to {}",
+        [
+            "{}にエクスポートしました",
+            "Nach {} exportiert",
+            "Exporté vers {}",
+            "{}(으)로 내보냄",
+        ],
+    ),
+    (
+        "Export failed: {}",
+        [
+            "エクスポートに失敗: {}",
+            "Export fehlgeschlagen: {}",
+            "Échec de l'export : {}",
+            "내보내기 실패: {}",
+        ],
+    ),
+    (
+        "Start with --weather-log FILE to log weathers",
+        [
+            "天候を記録するには --weather-log FILE で起動してください",
+            "Zum Protokollieren mit --weather-log DATEI starten",
+            "Lancer avec --weather-log FICHIER pour noter les météos",
+            "날씨를 기록하려면 --weather-log FILE로 시작하세요",
+        ],
+    ),
+    (
+        "Not while simulating another time",
+        [
+            "別の時刻を再現中は使えません",
+            "Nicht während einer simulierten Zeit",
+            "Impossible pendant une simulation d'heure",
+            "다른 시간을 시뮬레이션하는 중에는 불가",
+        ],
+    ),
+    (
+        "Logged {} in {}, the forecast says {}",
+        [
+            "{}({})を記録、予報は{}",
+            "{} in {} protokolliert, laut Vorhersage {}",
+            "{} noté à {}, la prévision indique {}",
+            "{} 기록 ({}), 예보는 {}",
+        ],
+    ),
+    (
+        "Logged {} in {}",
+        [
+            "{}({})を記録",
+            "{} in {} protokolliert",
+            "{} noté à {}",
+            "{} 기록 ({})",
+        ],
+    ),
+    (
+        "Logging the weather failed: {}",
+        [
+            "天候の記録に失敗: {}",
+            "Protokollieren des Wetters fehlgeschlagen: {}",
+            "Échec de l'enregistrement de la météo : {}",
+            "날씨 기록 실패: {}",
+        ],
+    ),
+    (
+        "Nothing to import",
+        [
+            "インポートするものがありません",
+            "Nichts zu importieren",
+            "Rien à importer",
+            "가져올 항목 없음",
+        ],
+    ),
+    (
+        "Marked {} fish caught",
+        [
+            "{}匹を釣り済みにしました",
+            "{} Fische als gefangen markiert",
+            "{} poissons marqués attrapés",
+            "물고기 {}마리를 낚음으로 표시",
+        ],
+    ),
+    (
+        "Saving the import failed: {}",
+        [
+            "インポートの保存に失敗: {}",
+            "Speichern des Imports fehlgeschlagen: {}",
+            "Échec de l'enregistrement de l'import : {}",
+            "가져오기 저장 실패: {}",
+        ],
+    ),
+    (
+        "{} data values not understood, see check-data",
+        [
+            "{}件のデータ値を解釈できません。check-dataを参照",
+            "{} Datenwerte nicht verstanden, siehe check-data",
+            "{} valeurs de données non comprises, voir check-data",
+            "데이터 값 {}개를 해석할 수 없음, check-data 참조",
+        ],
+    ),
+    (
+        "Alarms (m in list or zones: add, d: delete, s: snooze, +/-: lead time, c: channel, Esc: back)",
+        [
+            "アラーム (リストかゾーンでm: 追加, d: 削除, s: スヌーズ, +/-: 事前時間, c: 通知方法, Esc: 戻る)",
+            "Alarme (m in Liste oder Zonen: hinzufügen, d: löschen, s: schlummern, +/-: Vorlauf, c: Kanal, Esc: zurück)",
+            "Alarmes (m dans la liste ou les zones : ajouter, d : supprimer, s : reporter, +/- : avance, c : canal, Esc : retour)",
+            "알람 (목록이나 지역에서 m: 추가, d: 삭제, s: 다시 알림, +/-: 사전 시간, c: 채널, Esc: 뒤로)",
+        ],
+    ),
+    (
+        "Quiet hours {}{} (z: off, [/]: start, {/}: end)",
+        [
+            "おやすみ時間 {}{} (z: オフ, [/]: 開始, {/}: 終了)",
+            "Ruhezeit {}{} (z: aus, [/]: Beginn, {/}: Ende)",
+            "Heures calmes {}{} (z : désactiver, [/] : début, {/} : fin)",
+            "방해 금지 시간 {}{} (z: 끄기, [/]: 시작, {/}: 끝)",
+        ],
+    ),
+    (
+        ", now quiet",
+        [
+            "、現在おやすみ中",
+            ", gerade Ruhezeit",
+            ", calme en ce moment",
+            ", 현재 방해 금지",
+        ],
+    ),
+    (
+        "No quiet hours (z: on)",
+        [
+            "おやすみ時間なし (z: オン)",
+            "Keine Ruhezeit (z: an)",
+            "Pas d'heures calmes (z : activer)",
+            "방해 금지 시간 없음 (z: 켜기)",
+        ],
+    ),
+    (
+        "{} - {} - {} min before via {}{}",
+        [
+            "{} - {} - {}分前に{}で通知{}",
+            "{} - {} - {} min vorher per {}{}",
+            "{} - {} - {} min avant par {}{}",
+            "{} - {} - {}분 전 {}(으)로 알림{}",
+        ],
+    ),
+    (
+        "next {}",
+        ["次回 {}", "nächstes {}", "prochain {}", "다음 {}"],
+    ),
+    (
+        "not in sight",
+        [
+            "見つかりません",
+            "nicht in Sicht",
+            "pas en vue",
+            "보이지 않음",
+        ],
+    ),
+    (
+        "(snoozed {} min)",
+        [
+            "(スヌーズ {}分)",
+            "(schlummert {} min)",
+            "(reportée {} min)",
+            "(다시 알림 {}분)",
+        ],
+    ),
+    (
+        "Terminal bell",
+        [
+            "端末のベル",
+            "Terminalglocke",
+            "Sonnerie du terminal",
+            "터미널 벨",
+        ],
+    ),
+    (
+        "Desktop notification",
+        [
+            "デスクトップ通知",
+            "Desktop-Benachrichtigung",
+            "Notification de bureau",
+            "데스크톱 알림",
+        ],
+    ),
+    (
+        "Zones (v for list)",
+        [
+            "ゾーン (v: リスト)",
+            "Zonen (v für Liste)",
+            "Zones (v pour la liste)",
+            "지역 (v: 목록)",
+        ],
+    ),
+    (
+        "/: search",
+        ["/: 検索", "/: suchen", "/ : rechercher", "/: 검색"],
+    ),
+    (
+        "Now: {} ({} min left)  →  Next: {}",
+        [
+            "現在: {} (残り{}分)  →  次: {}",
+            "Jetzt: {} (noch {} min)  →  Nächstes: {}",
+            "Maintenant : {} (encore {} min)  →  Ensuite : {}",
+            "현재: {} ({}분 남음)  →  다음: {}",
+        ],
+    ),
+    (
+        "Later: {}",
+        ["その後: {}", "Danach: {}", "Plus tard : {}", "이후: {}"],
+    ),
+    (
+        "Alarm weather: {} ({}%)  w: change, m: add alarm, o: log as seen",
+        [
+            "アラームの天候: {} ({}%)  w: 変更, m: アラーム追加, o: 確認済みとして記録",
+            "Alarmwetter: {} ({}%)  w: wechseln, m: Alarm hinzufügen, o: als gesehen protokollieren",
+            "Météo d'alarme : {} ({}%)  w : changer, m : ajouter une alarme, o : noter comme vue",
+            "알람 날씨: {} ({}%)  w: 변경, m: 알람 추가, o: 확인한 날씨로 기록",
+        ],
+    ),
+    (
+        "Presets (Enter: apply, s: save current, d: delete, Esc: back)",
+        [
+            "プリセット (Enter: 適用, s: 現在の設定を保存, d: 削除, Esc: 戻る)",
+            "Vorlagen (Enter: anwenden, s: aktuelle speichern, d: löschen, Esc: zurück)",
+            "Préréglages (Entrée : appliquer, s : enregistrer l'actuel, d : supprimer, Esc : retour)",
+            "프리셋 (Enter: 적용, s: 현재 설정 저장, d: 삭제, Esc: 뒤로)",
+        ],
+    ),
+    (
+        "Name (Enter: save, Esc: cancel)",
+        [
+            "名前 (Enter: 保存, Esc: キャンセル)",
+            "Name (Enter: speichern, Esc: abbrechen)",
+            "Nom (Entrée : enregistrer, Esc : annuler)",
+            "이름 (Enter: 저장, Esc: 취소)",
+        ],
+    ),
+    (
+        "Progress (h: by patch, Esc: back)",
+        [
+            "進捗 (h: パッチ別, Esc: 戻る)",
+            "Fortschritt (h: nach Patch, Esc: zurück)",
+            "Progression (h : par patch, Esc : retour)",
+            "진행도 (h: 패치별, Esc: 뒤로)",
+        ],
+    ),
+    (
+        "Caught by patch (h: totals, Esc: back)",
+        [
+            "パッチ別の釣果 (h: 合計, Esc: 戻る)",
+            "Gefangen nach Patch (h: Summen, Esc: zurück)",
+            "Attrapés par patch (h : totaux, Esc : retour)",
+            "패치별 포획 (h: 합계, Esc: 뒤로)",
+        ],
+    ),
+    (
+        "About (Esc: back)",
+        [
+            "情報 (Esc: 戻る)",
+            "Über (Esc: zurück)",
+            "À propos (Esc : retour)",
+            "정보 (Esc: 뒤로)",
+        ],
+    ),
+    (
+        "Weathers (Esc: back)",
+        [
+            "天候 (Esc: 戻る)",
+            "Wetter (Esc: zurück)",
+            "Météos (Esc : retour)",
+            "날씨 (Esc: 뒤로)",
+        ],
+    ),
+    (
+        "Convert time, e.g. 14:30 ET or 20:00 (Esc: close)",
+        [
+            "時刻を変換 (例: 14:30 ET や 20:00) (Esc: 閉じる)",
+            "Zeit umrechnen, z. B. 14:30 ET oder 20:00 (Esc: schließen)",
+            "Convertir une heure, p. ex. 14:30 ET ou 20:00 (Esc : fermer)",
+            "시간 변환, 예: 14:30 ET 또는 20:00 (Esc: 닫기)",
+        ],
+    ),
+    (
+        "{} is next at {}, in {}",
+        [
+            "次の{}は{}、{}後",
+            "{} ist als Nächstes um {}, in {}",
+            "{} est à {} la prochaine fois, dans {}",
+            "다음 {}은(는) {}, {} 후",
+        ],
+    ),
+    ("then {}", ["その次 {}", "dann {}", "puis {}", "그다음 {}"]),
+    (
+        "{} is {}, in {}",
+        [
+            "{}は{}、{}後",
+            "{} ist {}, in {}",
+            "{} correspond à {}, dans {}",
+            "{}은(는) {}, {} 후",
+        ],
+    ),
+    (
+        "That time does not exist here",
+        [
+            "この時刻はここには存在しません",
+            "Diese Zeit gibt es hier nicht",
+            "Cette heure n'existe pas ici",
+            "이 시간은 이곳에 존재하지 않음",
+        ],
+    ),
+    (
+        "Now: {} / {}",
+        [
+            "現在: {} / {}",
+            "Jetzt: {} / {}",
+            "Maintenant : {} / {}",
+            "현재: {} / {}",
+        ],
+    ),
+    (
+        "Plan (+/-: horizon, [/]: buffer, e: export, c: copy, Esc: back)",
+        [
+            "計画 (+/-: 期間, [/]: 余裕, e: 書き出し, c: コピー, Esc: 戻る)",
+            "Plan (+/-: Zeitraum, [/]: Puffer, e: Export, c: Kopieren, Esc: zurück)",
+            "Plan (+/- : horizon, [/] : marge, e : exporter, c : copier, Esc : retour)",
+            "계획 (+/-: 기간, [/]: 여유, e: 내보내기, c: 복사, Esc: 뒤로)",
+        ],
+    ),
+    (
+        "Targets (Space)",
+        [
+            "対象 (Space)",
+            "Ziele (Leertaste)",
+            "Cibles (Espace)",
+            "대상 (Space)",
+        ],
+    ),
+    (
+        "Favourites: next {} hours (v for zones)",
+        [
+            "お気に入り: 今後{}時間 (v: ゾーン)",
+            "Favoriten: nächste {} Stunden (v für Zonen)",
+            "Favoris : {} prochaines heures (v pour les zones)",
+            "즐겨찾기: 앞으로 {}시간 (v: 지역)",
+        ],
+    ),
+    (
+        "Intuition: {} ({}s buff) — Space: caught, Backspace: undo, Esc: back",
+        [
+            "確信: {} (効果{}秒) — Space: 釣った, Backspace: 取り消し, Esc: 戻る",
+            "Intuition: {} ({} s Effekt) — Leertaste: gefangen, Rücktaste: rückgängig, Esc: zurück",
+            "Intuition : {} (effet {} s) — Espace : attrapé, Retour arrière : annuler, Esc : retour",
+            "직감: {} (효과 {}초) — Space: 낚음, Backspace: 취소, Esc: 뒤로",
+        ],
+    ),
+    (
+        "Recently caught",
+        [
+            "最近の釣果",
+            "Kürzlich gefangen",
+            "Attrapés récemment",
+            "최근 낚은 물고기",
+        ],
+    ),
+    (
+        "Go to fish id or name (Esc: cancel)",
+        [
+            "魚のIDか名前へ移動 (Esc: キャンセル)",
+            "Zu Fisch-ID oder Name springen (Esc: abbrechen)",
+            "Aller à l'ID ou au nom d'un poisson (Esc : annuler)",
+            "물고기 ID나 이름으로 이동 (Esc: 취소)",
+        ],
+    ),
+    ("OPEN {}", ["開放中 {}", "OFFEN {}", "OUVERT {}", "열림 {}"]),
+    (
+        "caught this window",
+        [
+            "この時間帯に釣り済み",
+            "in diesem Fenster gefangen",
+            "attrapé dans ce créneau",
+            "이번 시간대에 낚음",
+        ],
+    ),
+    (
+        "never up",
+        ["出現なし", "nie offen", "jamais ouvert", "등장 안 함"],
+    ),
+    (
+        "always up",
+        ["常時", "immer offen", "toujours ouvert", "상시"],
+    ),
+    (
+        "1 in {}",
+        ["{}回に1回", "1 von {}", "1 sur {}", "{}번 중 1번"],
+    ),
+    (
+        "no window in horizon",
+        [
+            "期間内に時間帯なし",
+            "kein Fenster in Sicht",
+            "aucun créneau en vue",
+            "기간 내 시간대 없음",
+        ],
+    ),
+];
+
+/// `english` in `lang`, or `english` itself if it has no translation.
+pub(crate) fn tr(lang: Lang, english: &str) -> &str {
+    let column = match lang {
+        Lang::English => return english,
+        Lang::Japanese => 0,
+        Lang::German => 1,
+        Lang::French => 2,
+        Lang::Korean => 3,
+    };
+    TABLE
+        .iter()
+        .find(|(key, _)| *key == english)
+        .map_or(english, |(_, texts)| texts[column])
+}
+
+/// `template` in `lang`, each `{}` replaced by the next of `args`.
+pub(crate) fn trf(lang: Lang, template: &str, args: &[&dyn Display]) -> String {
+    let mut parts = tr(lang, template).split("{}");
+    let mut text = parts.next().unwrap_or_default().to_string();
+    for (arg, part) in args.iter().zip(parts) {
+        text += &arg.to_string();
+        text += part;
+    }
+    text
+}
+
+impl App {
+    pub(crate) fn tr<'a>(&self, english: &'a str) -> &'a str {
+        tr(self.lang, english)
+    }
+
+    /// `template` in the configured language, each `{}` replaced by the
+    /// next of `args`.
+    pub(crate) fn trf(&self, template: &str, args: &[&dyn Display]) -> String {
+        trf(self.lang, template, args)
+    }
+
+    /// The fish's name in the configured language.
+    pub(crate) fn fish_name<'a>(&'a self, fish: &'a Fish) -> &'a str {
+        self.fish_data
+            .localized_item_name(fish.id, self.lang)
+            .unwrap_or(fish.name())
+    }
+
+    pub(crate) fn item_name<'a>(&'a self, item: &'a Item) -> &'a str {
+        self.fish_data
+            .localized_item_name(item.id(), self.lang)
+            .unwrap_or(item.name())
+    }
+}
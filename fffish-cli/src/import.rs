@@ -0,0 +1,149 @@
+use arboard::Clipboard;
+use ffxivfishing::lookup::NameMatch;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, List, ListItem, ListState, StatefulWidget},
+};
+
+use crate::{App, AppView, storage::JournalEntry};
+
+/// One pasted line and the fish it was resolved to.
+struct ImportEntry {
+    line: String,
+    fish: Option<(u32, NameMatch)>,
+    /// Whether the fish gets marked caught; fuzzy matches can be dropped.
+    include: bool,
+}
+
+/// The review screen shown before imported names are marked caught.
+#[derive(Default)]
+pub(crate) struct ImportState {
+    entries: Vec<ImportEntry>,
+    pub(crate) list_state: ListState,
+}
+
+impl App {
+    pub(crate) fn import_from_clipboard(&mut self) {
+        if self.clipboard.is_none() {
+            self.clipboard = Clipboard::new().ok();
+        }
+        match self.clipboard.as_mut().map(|c| c.get_text()) {
+            Some(Ok(text)) => self.start_import(&text),
+            Some(Err(e)) => self.status = Some(self.trf("Reading the clipboard failed: {}", &[&e])),
+            None => self.status = Some(self.tr("No clipboard available").to_string()),
+        }
+    }
+
+    /// Resolves `text`, one fish name or id per line, and opens the review.
+    pub(crate) fn start_import(&mut self, text: &str) {
+        self.import.entries = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let fish = self
+                    .fish_data
+                    .resolve_fish(line)
+                    .map(|(fish, how)| (fish.id, how));
+                ImportEntry {
+                    line: line.to_string(),
+                    fish,
+                    include: fish.is_some(),
+                }
+            })
+            .collect();
+        if self.import.entries.is_empty() {
+            self.status = Some(self.tr("Nothing to import").to_string());
+            return;
+        }
+        self.import.list_state.select_first();
+        self.view = AppView::Import;
+    }
+
+    pub(crate) fn toggle_import_entry(&mut self) {
+        if let Some(entry) = self
+            .import
+            .list_state
+            .selected()
+            .and_then(|i| self.import.entries.get_mut(i))
+        {
+            entry.include = !entry.include && entry.fish.is_some();
+        }
+    }
+
+    /// Marks the included fish caught and leaves the review.
+    pub(crate) fn finish_import(&mut self) {
+        let mut added = 0;
+        for entry in std::mem::take(&mut self.import.entries) {
+            if let (true, Some((id, _))) = (entry.include, entry.fish)
+                && !self.is_caught(id)
+            {
+                self.change_caught(JournalEntry::Caught(id));
+                added += 1;
+            }
+        }
+        self.status = Some(match self.save_user_data() {
+            Ok(()) => self.trf("Marked {} fish caught", &[&added]),
+            Err(e) => self.trf("Saving the import failed: {}", &[&e]),
+        });
+        self.item_cache = vec![];
+        self.view = AppView::List;
+    }
+
+    pub(crate) fn cancel_import(&mut self) {
+        self.import.entries.clear();
+        self.view = AppView::List;
+    }
+
+    pub(crate) fn render_import(&mut self, area: Rect, buf: &mut Buffer) {
+        let matched = self.import.entries.iter().filter(|e| e.include).count();
+        let items: Vec<ListItem> = self
+            .import
+            .entries
+            .iter()
+            .map(|entry| {
+                let Some((id, how)) = entry.fish else {
+                    return ListItem::new(format!("[ ] {}: not found", entry.line))
+                        .style(Style::from(Color::Red));
+                };
+                let name = self.fish_data.fish_by_id(id).map_or("?", |f| f.name());
+                let mut text = format!(
+                    "{} {} -> {}",
+                    match entry.include {
+                        true => "[x]",
+                        false => "[ ]",
+                    },
+                    entry.line,
+                    name
+                );
+                if let NameMatch::Fuzzy { .. } = how {
+                    text += " (closest match)";
+                }
+                if self.is_caught(id) {
+                    text += ", already caught";
+                }
+                let style = match how {
+                    NameMatch::Fuzzy { .. } => Style::from(Color::Yellow),
+                    _ => Style::default(),
+                };
+                ListItem::new(Line::from(text)).style(style)
+            })
+            .collect();
+        let title = format!(
+            " Import {} of {} lines as caught (Space: include, Enter: import, Esc: cancel) ",
+            matched,
+            self.import.entries.len()
+        );
+        StatefulWidget::render(
+            List::new(items)
+                .block(Block::bordered().title(title))
+                .highlight_symbol("> "),
+            area,
+            buf,
+            &mut self.import.list_state,
+        );
+    }
+}
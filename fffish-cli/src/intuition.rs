@@ -0,0 +1,204 @@
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local, TimeDelta};
+use ffxivfishing::{
+    display::{RelativeStyle, format_relative},
+    eorzea_time::{EorzeaDuration, EorzeaTime, EorzeaTimeSpan},
+    fish::{Fish, Intuition},
+    lang::Lang,
+    search::WindowQuery,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, List, ListItem, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{App, AppView, timeline::windows_between};
+
+/// How many windows of the target fish are checked for a feasible one.
+const FEASIBLE_SEARCH_WINDOWS: u32 = 20;
+
+type LocalSpan = (DateTime<Local>, DateTime<Local>);
+
+fn local_span(span: &EorzeaTimeSpan) -> LocalSpan {
+    (
+        span.start().to_system_time().into(),
+        span.end().to_system_time().into(),
+    )
+}
+
+fn to_eorzea(time: DateTime<Local>) -> EorzeaTime {
+    EorzeaTime::from_time(&SystemTime::from(time)).unwrap()
+}
+
+fn span_text((start, end): LocalSpan, now: DateTime<Local>, lang: Lang) -> String {
+    let span = EorzeaTimeSpan::new_start_end(to_eorzea(start), to_eorzea(end)).unwrap_or(
+        EorzeaTimeSpan::new(to_eorzea(start), EorzeaDuration::from_bells(0)),
+    );
+    let relative = format_relative(&span, to_eorzea(now), RelativeStyle::Short, lang);
+    format!(
+        "{} - {} ({})",
+        start.format("%m-%d %H:%M"),
+        end.format("%H:%M"),
+        relative
+    )
+}
+
+impl App {
+    /// Number of predators of `fish_id` that still have to be caught this session.
+    fn predators_missing(&self, fish_id: u32, required: u8) -> u8 {
+        required.saturating_sub(*self.session_catches.get(&fish_id).unwrap_or(&0))
+    }
+
+    /// The next stretch of the target's window during which the intuition buff
+    /// can be active, given the predators still missing this session.
+    fn feasible_window(&self, target: &Fish, intuition: &Intuition) -> Option<LocalSpan> {
+        let length = TimeDelta::from_std(intuition.length()).unwrap_or_default();
+        let mut time = self.clock().now();
+        'windows: for _ in 0..FEASIBLE_SEARCH_WINDOWS {
+            let window = target
+                .next_window(&WindowQuery::new(time).include_ongoing(true))
+                .ok()?;
+            time = window.end() + EorzeaDuration::from_esecs(1);
+            let (start, end) = local_span(&window);
+
+            let mut ready = start - length;
+            let mut ready_until = end;
+            for (required, predator_id) in intuition.requirements() {
+                if self.predators_missing(*predator_id, *required) == 0 {
+                    continue;
+                }
+                let predator = match self.fish_data.fish_by_id(*predator_id) {
+                    Some(p) => p,
+                    None => continue,
+                };
+                let first = windows_between(predator, to_eorzea(start - length), to_eorzea(end))
+                    .first()
+                    .map(local_span);
+                let (predator_start, predator_end) = match first {
+                    Some(span) => span,
+                    None => continue 'windows,
+                };
+                if predator_start > ready {
+                    ready = predator_start;
+                    ready_until = predator_end;
+                }
+            }
+
+            let feasible = (ready.max(start), end.min(ready_until + length));
+            if feasible.0 < feasible.1 {
+                return Some(feasible);
+            }
+        }
+        None
+    }
+
+    pub(crate) fn open_intuition(&mut self, fish_id: u32) {
+        let has_intuition = self
+            .fish_data
+            .fish_by_id(fish_id)
+            .is_some_and(|f| f.intuition.is_some());
+        if has_intuition {
+            self.view = AppView::Intuition(fish_id);
+            self.intuition_state.select_first();
+        }
+    }
+
+    pub(crate) fn tick_predator(&mut self, target_id: u32, caught: bool) {
+        let predator = self
+            .fish_data
+            .fish_by_id(target_id)
+            .and_then(|f| f.intuition.as_ref())
+            .and_then(|i| {
+                self.intuition_state
+                    .selected()
+                    .and_then(|s| i.requirements().get(s))
+            })
+            .copied();
+        let (required, predator_id) = match predator {
+            Some(p) => p,
+            None => return,
+        };
+        let count = self.session_catches.entry(predator_id).or_insert(0);
+        *count = match caught {
+            true => (*count + 1).min(required),
+            false => count.saturating_sub(1),
+        };
+    }
+
+    pub(crate) fn render_intuition(&mut self, target_id: u32, area: Rect, buf: &mut Buffer) {
+        let target = match self.fish_data.fish_by_id(target_id) {
+            Some(f) => f,
+            None => return,
+        };
+        let intuition = match &target.intuition {
+            Some(i) => i,
+            None => return,
+        };
+
+        let block = Block::bordered().title(format!(
+            " {} ",
+            self.trf(
+                "Intuition: {} ({}s buff) — Space: caught, Backspace: undo, Esc: back",
+                &[&target.name(), &intuition.length().as_secs()],
+            )
+        ));
+        let [summary_area, predator_area] =
+            Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(block.inner(area));
+        block.render(area, buf);
+
+        let target_window = target
+            .next_window(&WindowQuery::new(self.clock().now()).include_ongoing(true))
+            .ok()
+            .map_or("none found".to_string(), |w| {
+                span_text(local_span(&w), self.clock().local_now(), self.lang)
+            });
+        let feasible = self
+            .feasible_window(target, intuition)
+            .map_or("none in the next windows".to_string(), |s| {
+                span_text(s, self.clock().local_now(), self.lang)
+            });
+        Paragraph::new(vec![
+            Line::from(format!("Target window:   {}", target_window)),
+            Line::styled(
+                format!("Feasible window: {}", feasible),
+                Style::from(Color::Green),
+            ),
+        ])
+        .render(summary_area, buf);
+
+        let predators: Vec<ListItem> = intuition
+            .requirements()
+            .iter()
+            .map(|(required, predator_id)| {
+                let caught = required - self.predators_missing(*predator_id, *required);
+                let (name, window) = match self.fish_data.fish_by_id(*predator_id) {
+                    Some(p) => (
+                        p.name().to_string(),
+                        self.cached_item(p).time_to_window_string(),
+                    ),
+                    None => (format!("#{}", predator_id), "?".to_string()),
+                };
+                let style = match caught == *required {
+                    true => Style::from(Color::DarkGray),
+                    false => Style::default(),
+                };
+                ListItem::new(Line::styled(
+                    format!("[{}/{}] {} - {}", caught, required, name, window),
+                    style,
+                ))
+            })
+            .collect();
+        StatefulWidget::render(
+            List::new(predators)
+                .block(Block::bordered().title(" Predators "))
+                .highlight_symbol("> "),
+            predator_area,
+            buf,
+            &mut self.intuition_state,
+        );
+    }
+}
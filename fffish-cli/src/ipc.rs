@@ -0,0 +1,206 @@
+//! A local socket or TCP port for overlays and plugins. Each connection
+//! sends one JSON request line and receives one JSON response line:
+//!
+//! ```text
+//! {"command": "favourites"}
+//! {"command": "next_windows", "ids": [7678, 8752]}
+//! ```
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    path::PathBuf,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use ffxivfishing::{eorzea_time::EorzeaTime, lang::Lang, search::WindowQuery};
+use serde::{Deserialize, Serialize};
+
+use crate::App;
+
+#[derive(Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+enum Request {
+    Favourites,
+    NextWindows { ids: Vec<u32> },
+}
+
+#[derive(Serialize)]
+struct WindowInfo {
+    id: u32,
+    name: String,
+    location: String,
+    conditions: String,
+    /// Unix timestamps, absent if no window was found.
+    start: Option<u64>,
+    end: Option<u64>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Response {
+    Windows { windows: Vec<WindowInfo> },
+    Error { error: String },
+}
+
+fn unix_secs(time: EorzeaTime) -> u64 {
+    time.to_system_time()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Where IPC requests arrive: a Unix socket, or a localhost TCP port for
+/// clients on Windows such as Dalamud plugins.
+pub(crate) enum IpcServer {
+    #[cfg(unix)]
+    Unix {
+        path: PathBuf,
+        listener: std::os::unix::net::UnixListener,
+    },
+    Tcp(TcpListener),
+}
+
+/// A connection accepted by `IpcServer`.
+enum IpcStream {
+    #[cfg(unix)]
+    Unix(std::os::unix::net::UnixStream),
+    Tcp(TcpStream),
+}
+
+impl IpcServer {
+    #[cfg(unix)]
+    pub(crate) fn bind_socket(path: PathBuf) -> io::Result<IpcServer> {
+        use std::os::unix::fs::FileTypeExt;
+        // Only clean up a leftover socket, never a regular file.
+        if std::fs::metadata(&path).is_ok_and(|m| m.file_type().is_socket()) {
+            std::fs::remove_file(&path)?;
+        }
+        let listener = std::os::unix::net::UnixListener::bind(&path)?;
+        listener.set_nonblocking(true)?;
+        Ok(IpcServer::Unix { path, listener })
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn bind_socket(_path: PathBuf) -> io::Result<IpcServer> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Unix sockets are not available here, use --ipc-addr",
+        ))
+    }
+
+    pub(crate) fn bind_tcp(addr: SocketAddr) -> io::Result<IpcServer> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(IpcServer::Tcp(listener))
+    }
+
+    fn accept(&self) -> Option<IpcStream> {
+        let stream = match self {
+            #[cfg(unix)]
+            IpcServer::Unix { listener, .. } => {
+                let (stream, _) = listener.accept().ok()?;
+                stream.set_nonblocking(false).ok()?;
+                stream
+                    .set_read_timeout(Some(Duration::from_millis(500)))
+                    .ok()?;
+                IpcStream::Unix(stream)
+            }
+            IpcServer::Tcp(listener) => {
+                let (stream, _) = listener.accept().ok()?;
+                stream.set_nonblocking(false).ok()?;
+                stream
+                    .set_read_timeout(Some(Duration::from_millis(500)))
+                    .ok()?;
+                IpcStream::Tcp(stream)
+            }
+        };
+        Some(stream)
+    }
+}
+
+impl Drop for IpcServer {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let IpcServer::Unix { path, .. } = self {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+impl Read for IpcStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            #[cfg(unix)]
+            IpcStream::Unix(stream) => stream.read(buf),
+            IpcStream::Tcp(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for IpcStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            #[cfg(unix)]
+            IpcStream::Unix(stream) => stream.write(buf),
+            IpcStream::Tcp(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            #[cfg(unix)]
+            IpcStream::Unix(stream) => stream.flush(),
+            IpcStream::Tcp(stream) => stream.flush(),
+        }
+    }
+}
+
+impl App {
+    fn window_info(&self, id: u32) -> Option<WindowInfo> {
+        let fish = self.fish_data.fish_by_id(id)?;
+        let window = fish
+            .next_window(&WindowQuery::new(self.clock().now()).include_ongoing(true))
+            .ok();
+        Some(WindowInfo {
+            id,
+            name: fish.name().to_string(),
+            location: fish.location.name().to_string(),
+            conditions: self.fish_data.conditions_summary(fish, Lang::default()),
+            start: window.as_ref().map(|w| unix_secs(w.start())),
+            end: window.as_ref().map(|w| unix_secs(w.end())),
+        })
+    }
+
+    fn ipc_response(&self, line: &str) -> Response {
+        let ids = match serde_json::from_str(line) {
+            Ok(Request::Favourites) => self.user_data.favorites.ids().to_vec(),
+            Ok(Request::NextWindows { ids }) => ids,
+            Err(e) => {
+                return Response::Error {
+                    error: e.to_string(),
+                };
+            }
+        };
+        Response::Windows {
+            windows: ids
+                .into_iter()
+                .filter_map(|id| self.window_info(id))
+                .collect(),
+        }
+    }
+
+    /// Answers all pending connections without blocking the UI for long.
+    pub(crate) fn handle_ipc(&mut self) {
+        while let Some(mut stream) = self.ipc.iter().find_map(|s| s.accept()) {
+            let mut line = String::new();
+            if BufReader::new(&mut stream).read_line(&mut line).is_err() {
+                continue;
+            }
+            let response = self.ipc_response(&line);
+            if let Ok(mut json) = serde_json::to_string(&response) {
+                json.push('\n');
+                let _ = stream.write_all(json.as_bytes());
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use ffxivfishing::weather::Weather;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    text::Line,
+    widgets::{Block, Clear, Paragraph, Widget},
+};
+
+use crate::App;
+
+impl App {
+    /// The weather abbreviations used in the list, over the middle of `area`.
+    pub(crate) fn render_weather_legend(&self, area: Rect, buf: &mut Buffer) {
+        // Only weathers some zone has, once per abbreviation.
+        let mut shown = vec![];
+        let lines: Vec<Line> = Weather::all()
+            .filter(|w| {
+                self.fish_data
+                    .regions()
+                    .iter()
+                    .any(|r| r.weather().weathers().contains(&w))
+            })
+            .filter_map(|w| {
+                let abbreviation = w.abbreviation()?;
+                if shown.contains(&abbreviation) {
+                    return None;
+                }
+                shown.push(abbreviation);
+                Some(Line::from(format!(
+                    "{:<5} {}",
+                    abbreviation,
+                    self.fish_data.weather_name(&w)
+                )))
+            })
+            .collect();
+        let [area] = Layout::vertical([Constraint::Length(lines.len() as u16 + 2)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [area] = Layout::horizontal([Constraint::Length(34)])
+            .flex(Flex::Center)
+            .areas(area);
+        Clear.render(area, buf);
+        Paragraph::new(lines)
+            .block(Block::bordered().title(format!(" {} ", self.tr("Weathers (Esc: back)"))))
+            .render(area, buf);
+    }
+}
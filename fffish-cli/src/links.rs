@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{App, AppMode};
+
+/// URL templates for external fish pages. `{id}` is replaced by the item id
+/// and `{name}` by the English name.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub(crate) struct Links {
+    teamcraft: String,
+    carbuncle_plushy: String,
+    garland_tools: String,
+}
+
+impl Default for Links {
+    fn default() -> Self {
+        Links {
+            teamcraft: "https://ffxivteamcraft.com/db/en/item/{id}".to_string(),
+            carbuncle_plushy: "https://ff14fish.carbuncleplushy.com/?fish={id}".to_string(),
+            garland_tools: "https://www.garlandtools.org/db/#item/{id}".to_string(),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub(crate) enum Site {
+    Teamcraft,
+    CarbunclePlushy,
+    GarlandTools,
+}
+
+impl Site {
+    pub(crate) fn from_key(key: char) -> Option<Site> {
+        match key {
+            't' => Some(Site::Teamcraft),
+            'c' => Some(Site::CarbunclePlushy),
+            'g' => Some(Site::GarlandTools),
+            _ => None,
+        }
+    }
+}
+
+impl Links {
+    fn url(&self, site: Site, id: u32, name: &str) -> String {
+        let template = match site {
+            Site::Teamcraft => &self.teamcraft,
+            Site::CarbunclePlushy => &self.carbuncle_plushy,
+            Site::GarlandTools => &self.garland_tools,
+        };
+        template
+            .replace("{id}", &id.to_string())
+            .replace("{name}", &name.replace(' ', "%20"))
+    }
+}
+
+impl App {
+    pub(crate) fn start_open(&mut self) {
+        if self
+            .get_selected_fish()
+            .is_some_and(|item| !self.is_custom(item.id))
+        {
+            self.mode = AppMode::Open;
+            self.status = Some(
+                self.tr("Open: t Teamcraft, c Carbuncle Plushy, g GarlandTools")
+                    .to_string(),
+            );
+        }
+    }
+
+    pub(crate) fn open_selected(&mut self, site: Site) {
+        let url = match self.get_selected_fish() {
+            Some(item) => self.user_data.links.url(site, item.id, &item.name),
+            None => return,
+        };
+        self.status = match open::that_detached(&url) {
+            Ok(()) => None,
+            Err(e) => Some(self.trf("Opening {} failed: {}", &[&url, &e])),
+        };
+    }
+}
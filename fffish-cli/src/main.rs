@@ -1,47 +1,383 @@
 use std::{
+    borrow::Cow,
     cmp::Ordering,
+    collections::HashMap,
     fmt::Display,
+    fs,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::Mutex,
     time::{Duration, SystemTime},
 };
 
-use chrono::{Local, TimeDelta};
-use color_eyre::Result;
+use arboard::Clipboard;
+use chrono::{DateTime, Local, NaiveDateTime, TimeDelta};
+use chrono_tz::Tz;
+use clap::{Parser, Subcommand, ValueEnum};
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, eyre},
+};
 
 use ffxivfishing::{
-    carbuncledata::carbuncle_fishes,
-    eorzea_time::{EorzeaTime, EorzeaTimeSpan},
-    fish::{FishData, FishingItem},
+    carbuncledata::DataReport,
+    display,
+    eorzea_time::{EorzeaTime, EorzeaTimeSpan, corrected_now, set_clock_offset},
+    fish::{Fish, FishData, FishingItem, Reason},
+    lang::Lang,
+    search::WindowQuery,
+    watchlist::WatchList,
+    weather::Weather,
+};
+use ratatui::crossterm::event::{
+    self, Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers,
 };
-use ratatui::crossterm::event::{self, Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind};
 use ratatui::{
     DefaultTerminal,
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
-    style::{Color, Style},
-    text::Line,
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
     widgets::{
-        Block, Borders, List, ListItem, ListState, Padding, Paragraph, StatefulWidget, Widget,
+        Block, Borders, List, ListItem, ListState, Padding, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, StatefulWidget, Widget, Wrap,
     },
 };
 use serde::{Deserialize, Serialize};
+use tracing_subscriber::fmt::format::FmtSpan;
 use tui_input::{Input, backend::crossterm::EventHandler};
 
+use crate::{
+    alarms::{Alarm, PendingPost, QuietHours, TelegramConfig, WebhookConfig},
+    catchlog::{CatchLog, CatchLogConfig},
+    clock::{Clock, TimeDisplay},
+    custom::{Custom, CustomEntry},
+    data::DataSource,
+    export::ExportFormat,
+    focus::Pane,
+    import::ImportState,
+    ipc::IpcServer,
+    links::{Links, Site},
+    metrics::MetricsServer,
+    overlay::Overlay,
+    planner::PlannerState,
+    presets::{Preset, PresetState},
+    rows::RowCache,
+    storage::JournalEntry,
+    timeline::Timeline,
+    title::TerminalTitle,
+};
+
+mod about;
+mod achievements;
+mod alarms;
+mod catchlog;
+mod clock;
+mod converter;
+mod custom;
+mod data;
+mod export;
+mod focus;
+mod goto;
+mod i18n;
+mod import;
+mod intuition;
+mod ipc;
+mod legend;
+mod links;
+mod metrics;
+mod migrate;
+mod overlay;
+mod planner;
+mod presets;
+mod recent;
+mod rows;
+mod share;
+mod storage;
+mod timeline;
+mod title;
+mod verify;
+mod zones;
+
+/// Terminal companion for FFXIV fishing windows.
+#[derive(Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Initial list filter.
+    #[arg(long, value_enum)]
+    filter: Option<ListFilter>,
+    /// Initial list sort.
+    #[arg(long, value_enum)]
+    sort: Option<ListSort>,
+    /// Initial search text.
+    #[arg(long)]
+    search: Option<String>,
+    /// Start with this saved preset. --filter, --sort and --search override
+    /// its parts.
+    #[arg(long)]
+    preset: Option<String>,
+    /// Review the fish names or ids in this file, one per line, for marking
+    /// them caught.
+    #[arg(long)]
+    import: Option<PathBuf>,
+    /// Start in the zone view with this zone selected.
+    #[arg(long)]
+    zone: Option<String>,
+    /// Load fish data from a Carbuncle Plushy data.json instead of the built-in
+    /// copy. Overrides `data_path` from the config.
+    #[arg(long)]
+    data: Option<PathBuf>,
+    /// Watch this game log file and mark fish as caught when a catch message
+    /// appears. Overrides `catch_log` from the config.
+    #[arg(long)]
+    catch_log: Option<PathBuf>,
+    /// Append the weathers marked as seen in game (o in the zone view) to this
+    /// CSV file, for verify-weather. Overrides `weather_log` from the config.
+    #[arg(long)]
+    weather_log: Option<PathBuf>,
+    /// Answer JSON queries for upcoming windows on this Unix socket. Overrides
+    /// `ipc_socket` from the config.
+    #[arg(long)]
+    ipc: Option<PathBuf>,
+    /// Answer the same queries on this TCP address, e.g. 127.0.0.1:9188, for
+    /// clients without Unix sockets. Overrides `ipc_addr` from the config.
+    #[arg(long)]
+    ipc_addr: Option<SocketAddr>,
+    /// Serve Prometheus metrics at http://ADDR/metrics, e.g. 127.0.0.1:9187.
+    /// Overrides `metrics_addr` from the config.
+    #[arg(long)]
+    metrics: Option<SocketAddr>,
+    /// Keep the selected fish, its countdown and bait in this file for stream
+    /// overlays. `.html` and `.json` select the format, anything else is plain
+    /// text. Overrides `overlay_path` from the config.
+    #[arg(long)]
+    overlay: Option<PathBuf>,
+    /// Show the next favourite window in the terminal title. Also enabled by
+    /// `terminal_title` in the config.
+    #[arg(long)]
+    title: bool,
+    /// Evaluate windows as if it were this time, e.g. "2025-06-14 20:00" in
+    /// local time or an RFC 3339 timestamp. The simulated clock keeps running.
+    #[arg(long, value_parser = parse_at)]
+    at: Option<DateTime<Local>>,
+    /// Write diagnostics like data problems and search timings to this file.
+    #[arg(long)]
+    log_file: Option<PathBuf>,
+    /// Seconds to add to the system clock if it is off, negative if it runs
+    /// ahead. Overrides `clock_offset` from the config.
+    #[arg(long, allow_hyphen_values = true)]
+    clock_offset: Option<i64>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check computed weather against a reference CSV of `zone,unix_time,weather`
+    /// rows and report mismatches. Without a file, the bundled reference is
+    /// used.
+    VerifyWeather { reference: Option<PathBuf> },
+    /// List data values the loader did not understand, like unknown tug codes.
+    CheckData,
+    /// Write the upcoming windows to a calendar or spreadsheet file, or to
+    /// stdout if FILE is "-".
+    Export {
+        /// Only the favourites instead of every uncaught fish.
+        #[arg(long)]
+        favorites: bool,
+        /// How many real hours ahead to look.
+        #[arg(long, default_value_t = 48)]
+        hours: u64,
+        #[arg(long, value_enum, default_value_t = ExportFormat::Ics)]
+        format: ExportFormat,
+        file: PathBuf,
+    },
+}
+
+fn parse_at(text: &str) -> Result<DateTime<Local>, String> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(text) {
+        return Ok(time.with_timezone(&Local));
+    }
+    NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M")
+        .ok()
+        .and_then(|t| t.and_local_timezone(Local).single())
+        .ok_or(format!(
+            "expected \"YYYY-MM-DD HH:MM\" or RFC 3339, got {}",
+            text
+        ))
+}
+
+/// Sends tracing output to `path`, appending to earlier runs. Closing spans
+/// log how long they took.
+fn init_logging(path: &Path) -> Result<()> {
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .wrap_err_with(|| format!("Opening the log file {} failed", path.display()))?;
+    tracing_subscriber::fmt()
+        .with_writer(Mutex::new(file))
+        .with_ansi(false)
+        .with_max_level(tracing::Level::DEBUG)
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
+    Ok(())
+}
+
 fn main() -> Result<()> {
     color_eyre::install()?;
-    let terminal = ratatui::init();
+    let args = Args::parse();
+    if let Some(path) = &args.log_file {
+        init_logging(path)?;
+    }
+    let settings = storage::settings_path()?;
+    let mut user_data = migrate::load_user_data(&settings)?;
+    storage::rotate_backups(
+        &settings,
+        user_data.backups.unwrap_or(storage::DEFAULT_BACKUPS),
+    )?;
+    let replayed = storage::replay_journal(&settings, &mut user_data)?;
+    set_clock_offset(TimeDelta::seconds(
+        args.clock_offset.unwrap_or(user_data.clock_offset),
+    ));
+    let data_source = match args.data.clone().or(user_data.data_path.clone()) {
+        Some(path) => DataSource::File(path),
+        None => DataSource::BuiltIn,
+    };
+    let (mut fish_data, report) = data_source.load()?;
+    if let Some(path) = &user_data.bite_times_path {
+        fish_data = fish_data.with_bite_times(data::load_bite_times(path)?);
+    }
+    match args.command {
+        Some(Command::VerifyWeather { reference }) => {
+            return verify::verify_weather(&fish_data, reference.as_deref());
+        }
+        Some(Command::CheckData) => return data::check_data(&report),
+        Some(Command::Export {
+            favorites,
+            hours,
+            format,
+            file,
+        }) => {
+            return export::export_windows(&fish_data, &user_data, favorites, hours, format, &file);
+        }
+        None => {}
+    }
     let mut app = App {
-        fish_data: carbuncle_fishes().expect("Parsing the fish data failed"),
+        fish_data,
+        data_source,
         user_data: UserData::default(),
         list_state: ListState::default(),
         list_filter: ListFilter::None,
         list_sort: ListSort::NextWindow,
         item_cache: vec![],
+        rows: RowCache::default(),
+        custom: vec![],
         last_refresh: SystemTime::UNIX_EPOCH,
+        next_refresh: SystemTime::UNIX_EPOCH,
         input: Input::default(),
+        converter: Input::default(),
+        goto: Input::default(),
+        count: None,
         mode: AppMode::Search,
+        view: AppView::List,
+        zone_state: ListState::default(),
+        zone_weather: 0,
+        zone_search: Input::default(),
+        intuition_state: ListState::default(),
+        session_catches: HashMap::new(),
+        recent_catches: vec![],
+        planner: PlannerState::default(),
+        alarm_state: ListState::default(),
+        presets: PresetState::default(),
+        import: ImportState::default(),
+        list_height: 0,
+        focus: Pane::default(),
+        info_scroll: 0,
+        info_height: 0,
+        timezone: None,
+        lang: Lang::default(),
+        clipboard: None,
+        status: None,
+        data_report: report,
+        catch_log: None,
+        weather_log: args.weather_log.or(user_data.weather_log.clone()),
+        posts: vec![],
+        ipc: vec![],
+        metrics: None,
+        title: (args.title || user_data.terminal_title).then(TerminalTitle::default),
+        overlay: args
+            .overlay
+            .or(user_data.overlay_path.clone())
+            .map(Overlay::new),
+        time_offset: args.at.map_or(TimeDelta::zero(), |at| {
+            at - DateTime::<Local>::from(corrected_now())
+        }),
     };
+    let catch_log = args
+        .catch_log
+        .map(CatchLogConfig::new)
+        .or(user_data.catch_log.clone());
+    app.catch_log = catch_log.map(CatchLog::new);
+    if let Some(path) = args.ipc.or(user_data.ipc_socket.clone()) {
+        let server = IpcServer::bind_socket(path.clone())
+            .wrap_err_with(|| format!("Opening the IPC socket {} failed", path.display()))?;
+        app.ipc.push(server);
+    }
+    if let Some(addr) = args.ipc_addr.or(user_data.ipc_addr) {
+        let server = IpcServer::bind_tcp(addr)
+            .wrap_err_with(|| format!("Serving IPC on {} failed", addr))?;
+        app.ipc.push(server);
+    }
+    if let Some(addr) = args.metrics.or(user_data.metrics_addr) {
+        let server = MetricsServer::bind(addr)
+            .wrap_err_with(|| format!("Serving metrics on {} failed", addr))?;
+        app.metrics = Some(server);
+    }
+    app.set_user_data(user_data);
+    if !app.data_report.is_empty() {
+        app.status = Some(app.trf(
+            "{} data values not understood, see check-data",
+            &[&app.data_report.issues.len()],
+        ));
+    }
+    if replayed > 0 {
+        app.status = Some(app.trf("Restored {} unsaved changes", &[&replayed]));
+    }
     app.list_state.select_first();
+    app.zone_state.select_first();
+    if let Some(preset) = args.preset {
+        if !app.apply_preset(&preset) {
+            return Err(eyre!("Unknown preset: {}", preset));
+        }
+        app.mode = AppMode::List;
+    }
+    if let Some(filter) = args.filter {
+        app.list_filter = filter;
+    }
+    if let Some(sort) = args.sort {
+        app.list_sort = sort;
+    }
+    if let Some(search) = args.search {
+        app.input = Input::new(search);
+        app.mode = AppMode::List;
+    }
+    if let Some(zone) = args.zone {
+        if !app.select_zone(&zone) {
+            return Err(eyre!("Unknown zone: {}", zone));
+        }
+        app.view = AppView::Zones;
+        app.mode = AppMode::List;
+    }
+    if let Some(path) = args.import {
+        let text = fs::read_to_string(&path)
+            .wrap_err_with(|| format!("Reading {} failed", path.display()))?;
+        app.start_import(&text);
+        app.mode = AppMode::List;
+    }
 
+    // ratatui::init wraps the color_eyre panic hook with one that restores the
+    // terminal first, so it has to run after color_eyre::install.
+    let terminal = ratatui::init();
     let result = app.run(terminal);
     ratatui::restore();
     result
@@ -51,18 +387,65 @@ fn main() -> Result<()> {
 enum AppMode {
     List,
     Search,
+    Open,
+    /// Typing the name of a preset to save.
+    PresetName,
+    /// Typing in the zone view's search.
+    ZoneSearch,
+    /// Typing into the time converter.
+    Convert,
+    /// Typing a fish id or name to jump to.
+    Goto,
 }
 
 #[derive(PartialEq, Debug)]
+enum AppView {
+    List,
+    Timeline,
+    Zones,
+    Intuition(u32),
+    Planner,
+    Achievements,
+    /// The achievements as a grid of patches.
+    PatchHeatmap,
+    Alarms,
+    Presets,
+    Import,
+    /// The weather abbreviations over the list.
+    WeatherLegend,
+    /// Versions and data freshness over the list.
+    About,
+}
+
+#[derive(PartialEq, Debug, Clone, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 enum ListFilter {
     None,
     Uncaught,
     Favorite,
+    /// Only the ignored fish, which every other filter hides.
+    Ignored,
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 enum ListSort {
     NextWindow,
+    Name,
+    Duration,
+    Rarity,
+}
+
+impl Display for ListSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ListSort::NextWindow => "Next window",
+            ListSort::Name => "Name",
+            ListSort::Duration => "Duration",
+            ListSort::Rarity => "Rarity",
+        };
+        write!(f, "{}", s)
+    }
 }
 
 impl Display for ListFilter {
@@ -71,6 +454,7 @@ impl Display for ListFilter {
             ListFilter::None => "None",
             ListFilter::Uncaught => "Uncaught",
             ListFilter::Favorite => "Favorite",
+            ListFilter::Ignored => "Ignored",
         };
         write!(f, "{}", s)
     }
@@ -78,68 +462,294 @@ impl Display for ListFilter {
 
 #[derive(Default, Serialize, Deserialize, Clone)]
 struct UserData {
-    favorites: Vec<u32>,
+    /// Layout version of the file, see `migrate`. Missing in files from
+    /// before versioning.
+    #[serde(default)]
+    version: u32,
+    favorites: WatchList,
     caught: Vec<u32>,
+    /// Fish the user never means to catch. They are hidden and their alarms
+    /// stay silent, but they still count for completion.
+    #[serde(default)]
+    ignored: WatchList,
+    #[serde(default)]
+    time_display: TimeDisplay,
+    #[serde(default)]
+    timezone: Option<String>,
+    /// Language code (en, ja, de, fr, ko) for fish names and labels.
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    links: Links,
+    #[serde(default)]
+    data_path: Option<PathBuf>,
+    /// JSON map of fish ids to `[min, max]` seconds until they bite, used to
+    /// estimate how many casts fit into a window.
+    #[serde(default)]
+    bite_times_path: Option<PathBuf>,
+    #[serde(default)]
+    hide_always_available: bool,
+    #[serde(default)]
+    pin_favorites: bool,
+    #[serde(default)]
+    alarms: Vec<Alarm>,
+    /// Local hours in which alarms hold off.
+    #[serde(default)]
+    quiet_hours: Option<QuietHours>,
+    #[serde(default)]
+    catch_log: Option<CatchLogConfig>,
+    /// Where weathers seen in game are logged, see `--weather-log`.
+    #[serde(default)]
+    weather_log: Option<PathBuf>,
+    #[serde(default)]
+    ipc_socket: Option<PathBuf>,
+    #[serde(default)]
+    ipc_addr: Option<SocketAddr>,
+    #[serde(default)]
+    overlay_path: Option<PathBuf>,
+    #[serde(default)]
+    metrics_addr: Option<SocketAddr>,
+    /// Shows the next favourite window in the terminal title.
+    #[serde(default)]
+    terminal_title: bool,
+    /// Also sends every alarm to this Telegram chat.
+    #[serde(default)]
+    telegram: Option<TelegramConfig>,
+    /// Also POSTs every alarm to these URLs.
+    #[serde(default)]
+    webhooks: Vec<WebhookConfig>,
+    #[serde(default)]
+    presets: Vec<Preset>,
+    /// How many earlier settings files to keep as fish.toml.1, .2, ...
+    #[serde(default)]
+    backups: Option<usize>,
+    /// Seconds to add to the system clock if it is off, negative if it
+    /// runs ahead.
+    #[serde(default)]
+    clock_offset: i64,
+    /// Also recompute the list at least this often, in seconds. Otherwise
+    /// it is only recomputed when a window opens or closes or the weather
+    /// changes.
+    #[serde(default)]
+    refresh_seconds: Option<u64>,
+    /// Conditions listed and alarmed alongside the fish, see `CustomEntry`.
+    #[serde(default)]
+    custom: Vec<CustomEntry>,
 }
 
 struct App {
     fish_data: FishData,
+    data_source: DataSource,
+    data_report: DataReport,
     user_data: UserData,
     item_cache: Vec<FishListItem>,
+    rows: RowCache,
+    /// The resolved `UserData::custom`.
+    custom: Vec<Custom>,
+    /// When the list was last refreshed, with the clock offset applied like
+    /// every time the window math sees.
     last_refresh: SystemTime,
+    /// When the list may change next, see `until_next_change`.
+    next_refresh: SystemTime,
     list_state: ListState,
     list_filter: ListFilter,
     list_sort: ListSort,
     input: Input,
+    converter: Input,
+    /// The `:` prompt.
+    goto: Input,
+    /// Count typed before a motion, as in `12j`.
+    count: Option<usize>,
     mode: AppMode,
+    view: AppView,
+    zone_state: ListState,
+    /// Index into the selected zone's weathers, used for weather alarms.
+    zone_weather: usize,
+    zone_search: Input,
+    intuition_state: ListState,
+    session_catches: HashMap<u32, u8>,
+    /// Fish marked caught in this session and when, oldest first.
+    recent_catches: Vec<(u32, EorzeaTime)>,
+    planner: PlannerState,
+    alarm_state: ListState,
+    presets: PresetState,
+    import: ImportState,
+    list_height: u16,
+    focus: Pane,
+    info_scroll: u16,
+    info_height: u16,
+    timezone: Option<Tz>,
+    lang: Lang,
+    // Kept alive so the copied text stays available on X11.
+    clipboard: Option<Clipboard>,
+    status: Option<String>,
+    catch_log: Option<CatchLog>,
+    weather_log: Option<PathBuf>,
+    /// Telegram and webhook requests still running.
+    posts: Vec<PendingPost>,
+    ipc: Vec<IpcServer>,
+    metrics: Option<MetricsServer>,
+    overlay: Option<Overlay>,
+    title: Option<TerminalTitle>,
+    /// How far the simulated time is ahead of (or behind) the real one.
+    time_offset: TimeDelta,
 }
 
 impl ListSort {
+    /// Orders items by this sort, with open windows pinned to the top.
     fn compare(&self, a: &FishListItem, b: &FishListItem) -> Ordering {
-        match self {
-            ListSort::NextWindow => a
-                .next_window_start_local()
-                .cmp(&b.next_window_start_local()),
-        }
+        let open = b.is_open().cmp(&a.is_open());
+        open.then(match self {
+            // Fish without a window go last.
+            ListSort::NextWindow => {
+                match (a.next_window_start_local(), b.next_window_start_local()) {
+                    (Some(a), Some(b)) => a.cmp(&b),
+                    (a, b) => b.is_some().cmp(&a.is_some()),
+                }
+            }
+            ListSort::Name => a.name.cmp(&b.name),
+            ListSort::Duration => match (a.window_duration(), b.window_duration()) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (a, b) => b.is_some().cmp(&a.is_some()),
+            },
+            ListSort::Rarity => b.rarity.total_cmp(&a.rarity),
+        })
     }
 }
 
 impl App {
     fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
-        let _ = self.load_user_data();
         loop {
-            if self.item_cache.is_empty() || self.last_refresh.elapsed()? > Duration::from_secs(30)
+            // A clock set back puts the last refresh in the future, which
+            // also calls for a refresh.
+            if self.item_cache.is_empty()
+                || corrected_now() >= self.next_refresh
+                || corrected_now().duration_since(self.last_refresh).is_err()
             {
-                self.item_cache = self
-                    .fish_data
-                    .fishes()
-                    .iter()
-                    .filter(|f| f.name.contains(self.input.value()))
-                    .map(|f| FishListItem {
-                        name: f.name().to_string(),
-                        id: f.id,
-                        bait: self.fish_data.item_by_id(f.bait_id().unwrap()).cloned(),
-                        next_window: f.next_window(EorzeaTime::now(), true, 1_000).unwrap(),
-                        favourite: self.is_favourite(f.id),
-                        caught: self.is_caught(f.id),
-                    })
-                    .filter(|item| self.is_displayed(item, &self.list_filter))
-                    .collect();
-                self.item_cache.sort_by(|a, b| self.list_sort.compare(a, b));
-                self.last_refresh = SystemTime::now();
+                self.refresh_items();
             }
+            self.check_alarms();
+            self.check_posts();
+            self.check_catch_log();
+            self.handle_ipc();
+            self.handle_metrics();
+            self.update_overlay();
+            self.update_title();
             terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
-            if event::poll(Duration::from_secs(10))? {
-                if let CrosstermEvent::Key(e) = event::read()? {
-                    if e.code == KeyCode::Char('q') {
-                        break Ok(());
-                    }
-                    self.handle_key(e)
+            // Poll more often while serving IPC or metrics so requests are
+            // answered quickly, and while the title counts down.
+            let timeout = match (!self.ipc.is_empty() || self.metrics.is_some(), &self.title) {
+                (true, _) => Duration::from_millis(200),
+                (false, Some(_)) => Duration::from_secs(5),
+                (false, None) => Duration::from_secs(10),
+            }
+            .min(
+                self.next_refresh
+                    .duration_since(corrected_now())
+                    .unwrap_or_default(),
+            );
+            if event::poll(timeout)?
+                && let CrosstermEvent::Key(e) = event::read()?
+            {
+                let typing = matches!(
+                    self.mode,
+                    AppMode::Search
+                        | AppMode::PresetName
+                        | AppMode::ZoneSearch
+                        | AppMode::Convert
+                        | AppMode::Goto
+                );
+                if e.code == KeyCode::Char('q') && !typing {
+                    self.clear_title();
+                    break Ok(());
                 }
+                self.handle_key(e)
             }
         }
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    fn refresh_items(&mut self) {
+        let mut items: Vec<FishListItem> = self
+            .fish_data
+            .fishes()
+            .iter()
+            .filter(|f| {
+                f.name.contains(self.input.value())
+                    || self.fish_name(f).contains(self.input.value())
+            })
+            .filter(|f| !(self.user_data.hide_always_available && f.always_available()))
+            .map(|f| self.list_item(f))
+            .chain(self.custom_items(self.input.value()))
+            .filter(|item| self.is_displayed(item, &self.list_filter))
+            .collect();
+        items.sort_by(|a, b| self.compare_items(a, b));
+        tracing::debug!(items = items.len(), "refreshed fish list");
+        self.item_cache = items;
+        self.last_refresh = corrected_now();
+        self.next_refresh = self.last_refresh + self.until_next_change();
+    }
+
+    /// Real time until the list can change: the next weather change or the
+    /// next start or end of a listed window, whichever comes first. At least
+    /// a second, and at most `refresh_seconds`.
+    fn until_next_change(&self) -> Duration {
+        let now = self.clock().now();
+        let next = self
+            .item_cache
+            .iter()
+            .filter_map(|item| item.next_window.as_ref())
+            .flat_map(|w| [w.start(), w.end()])
+            .filter(|t| *t > now)
+            .fold(now.next_weather_change(), EorzeaTime::min);
+        let until = next
+            .duration_since(now)
+            .map_or(Duration::ZERO, |d| d.to_real());
+        let until = match self.user_data.refresh_seconds {
+            Some(seconds) => until.min(Duration::from_secs(seconds)),
+            None => until,
+        };
+        until.max(Duration::from_secs(1))
+    }
+
+    fn list_item(&self, f: &Fish) -> FishListItem {
+        FishListItem {
+            name: self.fish_name(f).to_string(),
+            id: f.id,
+            bait: f
+                .bait_id()
+                .and_then(|id| self.fish_data.item_by_id(id))
+                .cloned(),
+            next_window: f
+                .next_window(&WindowQuery::new(self.clock().now()).include_ongoing(true))
+                .ok(),
+            rarity: f.rarity(),
+            favourite: self.is_favourite(f.id),
+            caught: self.is_caught(f.id),
+            caught_this_window: self.caught_this_window(f.id),
+            weather: weather_requirement(f, ", ", |w| {
+                w.abbreviation()
+                    .map_or_else(|| self.fish_data.weather_name(w), str::to_string)
+            }),
+            clock: self.clock(),
+            lang: self.lang,
+            custom: false,
+        }
+    }
+
+    /// The list row of `f` from the last refresh, built anew only for fish
+    /// the list currently leaves out.
+    fn cached_item(&self, f: &Fish) -> Cow<'_, FishListItem> {
+        match self
+            .item_cache
+            .iter()
+            .find(|item| item.id == f.id && !item.custom)
+        {
+            Some(item) => Cow::Borrowed(item),
+            None => Cow::Owned(self.list_item(f)),
+        }
+    }
+
     fn render_info(&mut self, area: Rect, buf: &mut Buffer) {
         let item = match self.get_selected_fish() {
             Some(f) => f,
@@ -147,33 +757,176 @@ impl App {
                 return;
             }
         };
+        if self.is_custom(item.id) {
+            return self.render_custom_info(item, area, buf);
+        }
         let bait_str = format!(
-            "Bait: {}",
+            "{}: {}",
+            self.tr("Bait"),
             item.bait
                 .as_ref()
                 .map(|i| self.bait_text(i))
-                .unwrap_or("".to_string())
+                .unwrap_or(self.tr("unknown").to_string())
         );
-        let fish = self.fish_data.fish_by_id(item.id).unwrap();
+        let fish = match self.fish_data.fish_by_id(item.id) {
+            Some(f) => f,
+            None => return,
+        };
         let (start, end) = fish.time_restriction();
 
         let border_block = Block::new()
             .borders(Borders::ALL)
             .title(format!(" {} ", item.name.clone()))
+            .title_bottom(
+                Line::from(format!(
+                    " {}: {} ",
+                    self.tr("Data"),
+                    self.data_source.version(&self.fish_data)
+                ))
+                .right_aligned(),
+            )
             .padding(Padding::new(1, 0, 0, 0));
+        let border_block = border_block.border_style(self.pane_style(Pane::Details));
 
-        let areas = Layout::default()
-            .constraints([Constraint::Max(3); 9])
-            .split(border_block.inner(area));
+        // Sections are separated by an empty line.
+        let mut sections: Vec<Vec<Line>> = vec![];
+        let mut window = vec![Line::from(format!(
+            "{}: {} - {}",
+            self.tr("Window"),
+            start,
+            end
+        ))];
+        if let Some(weather) = weather_requirement(fish, ", ", |w| self.fish_data.weather_name(w)) {
+            window.push(Line::from(format!("{}: {}", self.tr("Weather"), weather)));
+        }
+        sections.push(window);
+        let next = format!("{}: ", self.tr("Next"));
+        let indent = " ".repeat(Line::from(next.as_str()).width());
+        sections.push(vec![
+            Line::from(format!(
+                "{}{}",
+                next,
+                item.window_span(Clock::new(TimeDisplay::Local, self.timezone))
+            )),
+            Line::from(format!(
+                "{}{}",
+                indent,
+                item.window_span(Clock::new(TimeDisplay::Server, None))
+            )),
+            Line::from(format!(
+                "{}{}",
+                indent,
+                item.window_span(Clock::new(TimeDisplay::Eorzea, None))
+            )),
+        ]);
+        sections.push(vec![Line::from(bait_str)]);
+        let mut tug = vec![Line::from(format!("{}: {}", self.tr("Tug"), fish.tug))];
+        if let Some(bite_time) = self.fish_data.bite_time(fish.id) {
+            let casts = item.next_window.as_ref().and_then(|window| {
+                self.fish_data
+                    .estimated_casts(fish.id, window, self.clock().now())
+            });
+            let bites = self.trf(
+                "Bites after {}-{} s",
+                &[&bite_time.min().as_secs(), &bite_time.max().as_secs()],
+            );
+            tug.push(Line::from(match casts {
+                Some(n) => format!("{}, {}", bites, self.trf("~{} casts possible", &[&n])),
+                None => bites,
+            }));
+        }
+        sections.push(tug);
+        sections.push(vec![Line::from(match fish.lure {
+            Some(lure) => format!(
+                "{}: {}, {} {}",
+                self.tr("Hookset"),
+                fish.hookset,
+                self.tr("needs"),
+                lure
+            ),
+            None => format!("{}: {}", self.tr("Hookset"), fish.hookset),
+        })]);
+        if let Some(book) = self
+            .fish_data
+            .folklore_books()
+            .iter()
+            .find(|b| b.fish.contains(&fish.id))
+        {
+            sections.push(vec![Line::from(format!(
+                "{}: {}",
+                self.tr("Folklore"),
+                book.name
+            ))]);
+        }
+        if self.user_data.caught.contains(&fish.id) {
+            sections.push(vec![Line::from(self.tr("Caught"))]);
+        }
+        // Intuition and folklore depend on the player and are shown above.
+        let closed: Vec<String> = fish
+            .why_not_open(self.clock().now())
+            .iter()
+            .filter_map(|reason| match reason {
+                Reason::WrongHours { .. } => Some(self.tr("outside its hours").to_string()),
+                Reason::WrongWeather { current, .. } => {
+                    Some(self.trf("{} now", &[&self.fish_data.weather_name(current)]))
+                }
+                Reason::WrongPreviousWeather { previous, .. } => {
+                    Some(self.trf("{} before", &[&self.fish_data.weather_name(previous)]))
+                }
+                _ => None,
+            })
+            .collect();
+        if !closed.is_empty() {
+            sections.push(vec![Line::from(format!(
+                "{}: {}",
+                self.tr("Closed"),
+                closed.join(", ")
+            ))]);
+        }
 
-        border_block.render(area, buf);
+        // Something to try while waiting at the spot.
+        let mut others: Vec<FishListItem> = self
+            .fish_data
+            .fishes_at_hole(fish.location.id())
+            .into_iter()
+            .filter(|f| f.id != fish.id && !self.is_caught(f.id) && !self.is_ignored(f.id))
+            .map(|f| self.cached_item(f).into_owned())
+            .collect();
+        others.sort_by(|a, b| ListSort::NextWindow.compare(a, b));
+        if !others.is_empty() {
+            let mut lines = vec![Line::from(format!("{}:", self.tr("Also here, uncaught")))];
+            lines.extend(others.iter().map(|other| {
+                Line::from(format!(
+                    "  {} - {}",
+                    other.name,
+                    other.time_to_window_string()
+                ))
+            }));
+            sections.push(lines);
+        }
 
-        Paragraph::new(format!("Window: {} - {}", start, end)).render(areas[0], buf);
-        Paragraph::new(bait_str).render(areas[1], buf);
-        Paragraph::new(format!("Tug: {}", fish.tug)).render(areas[2], buf);
-        Paragraph::new(format!("Hookset: {}", fish.hookset)).render(areas[3], buf);
-        if self.user_data.caught.contains(&fish.id) {
-            Paragraph::new("Caught").render(areas[4], buf);
+        let lines: Vec<Line> = sections.join(&Line::default());
+        let inner = border_block.inner(area);
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+        // Scroll no further than needed to show the last line.
+        let overflow = paragraph
+            .line_count(inner.width)
+            .saturating_sub(inner.height as usize);
+        self.info_height = inner.height;
+        self.info_scroll = self.info_scroll.min(overflow as u16);
+        paragraph
+            .scroll((self.info_scroll, 0))
+            .block(border_block)
+            .render(area, buf);
+        if overflow > 0 {
+            let mut scrollbar_state =
+                ScrollbarState::new(overflow + 1).position(self.info_scroll as usize);
+            StatefulWidget::render(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                area,
+                buf,
+                &mut scrollbar_state,
+            );
         }
     }
 
@@ -182,14 +935,93 @@ impl App {
             Layout::vertical([Constraint::Max(3), Constraint::Fill(1)]).areas(area);
 
         // List
-        let items: Vec<ListItem> = self.item_cache.iter().map(ListItem::from).collect();
-        let block = Block::bordered().title_top(format!("Filter: {}", self.list_filter));
+        self.rows
+            .update(&self.item_cache, self.user_data.time_display);
+        let items: Vec<ListItem> = self
+            .item_cache
+            .iter()
+            .map(|item| self.rows.item(item))
+            .collect();
+        let position = self.list_state.selected().map_or(0, |i| i + 1);
+        let block = Block::bordered()
+            .border_style(self.pane_style(Pane::List))
+            .title_top(format!(
+                "{}: {}{} | {}: {}{} | {}: {}",
+                self.tr("Filter"),
+                self.tr(&self.list_filter.to_string()),
+                match self.user_data.hide_always_available {
+                    true => format!(", {}", self.tr("no always up")),
+                    false => String::new(),
+                },
+                self.tr("Sort"),
+                self.tr(&self.list_sort.to_string()),
+                match self.user_data.pin_favorites {
+                    true => format!(", {}", self.tr("favourites pinned")),
+                    false => String::new(),
+                },
+                self.tr("Time"),
+                self.clock().label()
+            ))
+            .title_bottom(format!(
+                " {} ",
+                self.trf(
+                    "{} of {}, refreshed {} (r)",
+                    &[
+                        &position,
+                        &items.len(),
+                        &EorzeaTime::from_time(&self.last_refresh).map_or("?".to_string(), |t| {
+                            Clock::new(TimeDisplay::Local, self.timezone).time(t)
+                        }),
+                    ],
+                )
+            ));
+        let block = match self.time_offset.is_zero() {
+            true => block,
+            false => block.title_bottom(
+                Line::from(format!(
+                    " {} ",
+                    self.trf(
+                        "Simulating {} (<, >, =)",
+                        &[&self.clock().date_time(self.clock().now())],
+                    )
+                ))
+                .yellow(),
+            ),
+        };
+        let block = match &self.status {
+            Some(status) => block.title_bottom(Line::from(format!(" {} ", status)).right_aligned()),
+            None => block,
+        };
+        let block = match self.count {
+            Some(count) => block.title_bottom(Line::from(format!(" {} ", count)).centered()),
+            None => block,
+        };
+        self.list_height = block.inner(list_area).height;
+        let mut scrollbar_state =
+            ScrollbarState::new(items.len()).position(self.list_state.selected().unwrap_or(0));
         StatefulWidget::render(
             List::new(items).block(block).highlight_symbol("> "),
             list_area,
             buf,
             &mut self.list_state,
         );
+        StatefulWidget::render(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            list_area,
+            buf,
+            &mut scrollbar_state,
+        );
+
+        if self.mode == AppMode::Goto {
+            Paragraph::new(format!(":{}", self.goto.value()))
+                .style(Color::Blue)
+                .block(Block::bordered().title(format!(
+                    " {} ",
+                    self.tr("Go to fish id or name (Esc: cancel)")
+                )))
+                .render(search_area, buf);
+            return;
+        }
 
         // Search
         let width = search_area.width.max(3) - 3;
@@ -201,17 +1033,26 @@ impl App {
         let input = Paragraph::new(self.input.value())
             .style(style)
             .scroll((0, scroll as u16))
-            .block(Block::bordered().title("Search"));
+            .block(Block::bordered().title(self.tr("Search")));
         if self.mode == AppMode::Search {
             // let x = self.input.visual_cursor().max(scroll) - scroll + 1;
         }
         Widget::render(input, search_area, buf);
     }
 
+    fn render_timeline(&self, area: Rect, buf: &mut Buffer) {
+        let mut timeline = Timeline::new(self.clock().local_now(), self.lang);
+        self.user_data
+            .favorites
+            .fishes(&self.fish_data)
+            .for_each(|f| timeline.add_fish(f));
+        timeline.render(area, buf);
+    }
+
     fn bait_text(&self, bait: &FishingItem) -> String {
         match bait {
-            FishingItem::Fish(name, id) => {
-                let fish = self.fish_data.fish_by_id(*id);
+            FishingItem::Fish(item) => {
+                let fish = self.fish_data.fish_by_id(item.id());
                 let inner_bait = fish
                     .and_then(|f| f.bait_id().and_then(|b| self.fish_data.item_by_id(b)))
                     .map(|i| self.bait_text(i))
@@ -219,11 +1060,11 @@ impl App {
                 format!(
                     "{} -> {} ({})",
                     inner_bait,
-                    name.clone(),
+                    self.item_name(item),
                     fish.map_or("?".to_string(), |f| f.tug.to_string())
                 )
             }
-            FishingItem::Bait(name, _) => name.clone(),
+            FishingItem::Bait(item) => self.item_name(item).to_string(),
         }
     }
 
@@ -231,6 +1072,7 @@ impl App {
         if key.kind != KeyEventKind::Press {
             return;
         }
+        self.status = None;
         match self.mode {
             AppMode::Search => match key.code {
                 KeyCode::Esc => self.mode = AppMode::List,
@@ -242,44 +1084,289 @@ impl App {
                     self.input.handle_event(&CrosstermEvent::Key(key));
                 }
             },
-            AppMode::List => match key.code {
-                KeyCode::Char('j') => self.list_state.select_next(),
-                KeyCode::Char('k') => self.list_state.select_previous(),
-                KeyCode::Char('g') => self.list_state.select_first(),
-                KeyCode::Char('G') => self.list_state.select_last(),
-                KeyCode::Char('/') => self.mode = AppMode::Search,
-                KeyCode::Enter => {
-                    let fish_id = match self.get_selected_fish() {
-                        Some(f) => f.id,
-                        None => return,
-                    };
-                    self.toggle_caught(fish_id);
-                    self.item_cache = vec![];
+            AppMode::PresetName => match key.code {
+                KeyCode::Esc => self.mode = AppMode::List,
+                KeyCode::Enter => self.save_preset(),
+                _ => {
+                    self.presets.name.handle_event(&CrosstermEvent::Key(key));
                 }
-                KeyCode::Char('f') => {
-                    let fish_id = match self.get_selected_fish() {
-                        Some(f) => f.id,
-                        None => return,
-                    };
-                    self.toggle_favourites(fish_id);
-                    self.item_cache = vec![];
+            },
+            AppMode::ZoneSearch => self.handle_zone_search_key(key),
+            AppMode::Goto => match key.code {
+                KeyCode::Esc => self.mode = AppMode::List,
+                KeyCode::Enter => self.finish_goto(),
+                _ => {
+                    self.goto.handle_event(&CrosstermEvent::Key(key));
+                }
+            },
+            AppMode::Convert => match key.code {
+                KeyCode::Esc | KeyCode::Enter => self.mode = AppMode::List,
+                _ => {
+                    self.converter.handle_event(&CrosstermEvent::Key(key));
                 }
-                KeyCode::Char('F') => {
-                    self.next_filter();
-                    self.item_cache = vec![];
+            },
+            AppMode::Open => {
+                self.mode = AppMode::List;
+                if let KeyCode::Char(c) = key.code
+                    && let Some(site) = Site::from_key(c)
+                {
+                    self.open_selected(site);
                 }
+            }
+            AppMode::List if self.view == AppView::Zones => match key.code {
+                KeyCode::Char('j') => {
+                    self.zone_state.select_next();
+                    self.zone_weather = 0;
+                }
+                KeyCode::Char('k') => {
+                    self.zone_state.select_previous();
+                    self.zone_weather = 0;
+                }
+                KeyCode::Char('g') => {
+                    self.zone_state.select_first();
+                    self.zone_weather = 0;
+                }
+                KeyCode::Char('G') => {
+                    self.zone_state.select_last();
+                    self.zone_weather = 0;
+                }
+                KeyCode::Char('w') => self.next_zone_weather(),
+                KeyCode::Char('m') => self.add_zone_weather_alarm(),
+                KeyCode::Char('/') => self.start_zone_search(),
+                KeyCode::Char('o') => self.log_zone_weather(),
+                KeyCode::Char('n') => self.open_alarms(),
+                KeyCode::Char('v') => self.toggle_view(),
+                _ => {}
+            },
+            AppMode::List if let AppView::Intuition(target) = self.view => match key.code {
+                KeyCode::Char('j') => self.intuition_state.select_next(),
+                KeyCode::Char('k') => self.intuition_state.select_previous(),
+                KeyCode::Char(' ') | KeyCode::Enter => self.tick_predator(target, true),
+                KeyCode::Backspace => self.tick_predator(target, false),
+                KeyCode::Esc | KeyCode::Char('i') => self.view = AppView::List,
+                _ => {}
+            },
+            AppMode::List if self.view == AppView::Achievements => match key.code {
+                KeyCode::Char('h') => self.view = AppView::PatchHeatmap,
+                KeyCode::Esc | KeyCode::Char('a') => self.view = AppView::List,
+                _ => {}
+            },
+            AppMode::List if self.view == AppView::PatchHeatmap => match key.code {
+                KeyCode::Char('h') => self.view = AppView::Achievements,
+                KeyCode::Esc | KeyCode::Char('a') => self.view = AppView::List,
+                _ => {}
+            },
+            AppMode::List if self.view == AppView::Alarms => match key.code {
+                KeyCode::Char('j') => self.alarm_state.select_next(),
+                KeyCode::Char('k') => self.alarm_state.select_previous(),
+                KeyCode::Char('d') => self.delete_alarm(),
+                KeyCode::Char('s') => self.snooze_alarm(),
+                KeyCode::Char('+') => self.change_alarm_lead(1),
+                KeyCode::Char('-') => self.change_alarm_lead(-1),
+                KeyCode::Char('c') => self.cycle_alarm_channel(),
+                KeyCode::Char('z') => self.toggle_quiet_hours(),
+                KeyCode::Char('[') => self.shift_quiet_hours(false, -1),
+                KeyCode::Char(']') => self.shift_quiet_hours(false, 1),
+                KeyCode::Char('{') => self.shift_quiet_hours(true, -1),
+                KeyCode::Char('}') => self.shift_quiet_hours(true, 1),
+                KeyCode::Esc | KeyCode::Char('n') => self.view = AppView::List,
+                _ => {}
+            },
+            AppMode::List if self.view == AppView::Presets => match key.code {
+                KeyCode::Char('j') => self.presets.list_state.select_next(),
+                KeyCode::Char('k') => self.presets.list_state.select_previous(),
+                KeyCode::Enter => self.apply_selected_preset(),
+                KeyCode::Char('s') => self.start_save_preset(),
+                KeyCode::Char('d') => self.delete_preset(),
+                KeyCode::Esc | KeyCode::Char('l') => self.view = AppView::List,
                 _ => {}
             },
+            AppMode::List if self.view == AppView::About => {
+                if matches!(key.code, KeyCode::Esc | KeyCode::Char('V')) {
+                    self.view = AppView::List
+                }
+            }
+            AppMode::List if self.view == AppView::WeatherLegend => {
+                if matches!(key.code, KeyCode::Esc | KeyCode::Char('W')) {
+                    self.view = AppView::List
+                }
+            }
+            AppMode::List if self.view == AppView::Import => match key.code {
+                KeyCode::Char('j') => self.import.list_state.select_next(),
+                KeyCode::Char('k') => self.import.list_state.select_previous(),
+                KeyCode::Char(' ') => self.toggle_import_entry(),
+                KeyCode::Enter => self.finish_import(),
+                KeyCode::Esc => self.cancel_import(),
+                _ => {}
+            },
+            AppMode::List if self.view == AppView::Planner => match key.code {
+                KeyCode::Char('j') => self.planner.list_state.select_next(),
+                KeyCode::Char('k') => self.planner.list_state.select_previous(),
+                KeyCode::Char(' ') => self.toggle_planner_target(),
+                KeyCode::Char('+') => self.change_planner_horizon(1),
+                KeyCode::Char('-') => self.change_planner_horizon(-1),
+                KeyCode::Char(']') => self.change_planner_buffer(1),
+                KeyCode::Char('[') => self.change_planner_buffer(-1),
+                KeyCode::Char('e') => self.export_plan(),
+                KeyCode::Char('c') => self.copy_plan(),
+                KeyCode::Esc | KeyCode::Char('p') => self.view = AppView::List,
+                _ => {}
+            },
+            AppMode::List if self.view == AppView::List && self.focus == Pane::Details => {
+                self.handle_details_key(key)
+            }
+            AppMode::List => {
+                let count = self.count.take();
+                match key.code {
+                    KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.list_state.scroll_down_by(self.list_height / 2)
+                    }
+                    KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.list_state.scroll_up_by(self.list_height / 2)
+                    }
+                    KeyCode::PageDown => self.list_state.scroll_down_by(self.list_height),
+                    KeyCode::PageUp => self.list_state.scroll_up_by(self.list_height),
+                    KeyCode::Tab if self.view == AppView::List => self.cycle_focus(true),
+                    KeyCode::BackTab if self.view == AppView::List => self.cycle_focus(false),
+                    KeyCode::Char(c @ '0'..='9') if c != '0' || count.is_some() => {
+                        self.count = count;
+                        self.push_count_digit(c as u32 - '0' as u32);
+                    }
+                    KeyCode::Char('j') => match count {
+                        Some(n) => self.move_selection(n, true),
+                        None => self.list_state.select_next(),
+                    },
+                    KeyCode::Char('k') => match count {
+                        Some(n) => self.move_selection(n, false),
+                        None => self.list_state.select_previous(),
+                    },
+                    KeyCode::Char('g') => match count {
+                        Some(n) => self.select_row(n),
+                        None => self.list_state.select_first(),
+                    },
+                    KeyCode::Char('G') => match count {
+                        Some(n) => self.select_row(n),
+                        None => self.list_state.select_last(),
+                    },
+                    KeyCode::Char(':') => self.open_goto(),
+                    KeyCode::Char('/') => self.mode = AppMode::Search,
+                    KeyCode::Char('v') => self.toggle_view(),
+                    KeyCode::Char('p') => self.open_planner(),
+                    KeyCode::Char('a') => self.view = AppView::Achievements,
+                    KeyCode::Char('n') => self.open_alarms(),
+                    KeyCode::Char('l') => self.open_presets(),
+                    KeyCode::Char('t') => self.open_converter(),
+                    KeyCode::Char('m') => self.add_alarm(),
+                    KeyCode::Char('>') => self.shift_time(1),
+                    KeyCode::Char('<') => self.shift_time(-1),
+                    KeyCode::Char('=') => self.shift_time(0),
+                    KeyCode::Enter => {
+                        let fish_id = match self.selected_fish_id() {
+                            Some(id) => id,
+                            None => return,
+                        };
+                        self.toggle_caught(fish_id);
+                        self.item_cache = vec![];
+                    }
+                    KeyCode::Char('f') => {
+                        let fish_id = match self.selected_fish_id() {
+                            Some(id) => id,
+                            None => return,
+                        };
+                        self.toggle_favourites(fish_id);
+                        self.item_cache = vec![];
+                    }
+                    KeyCode::Char('x') => {
+                        let fish_id = match self.selected_fish_id() {
+                            Some(id) => id,
+                            None => return,
+                        };
+                        self.toggle_ignored(fish_id);
+                        self.item_cache = vec![];
+                    }
+                    KeyCode::Char('i') => {
+                        if let Some(f) = self.get_selected_fish() {
+                            self.open_intuition(f.id);
+                        }
+                    }
+                    KeyCode::Char('y') => self.copy_selected(),
+                    KeyCode::Char('o') => self.start_open(),
+                    KeyCode::Char('T') => {
+                        self.user_data.time_display = self.user_data.time_display.next();
+                        let _ = self.save_user_data();
+                        self.item_cache = vec![];
+                    }
+                    KeyCode::Char('F') => {
+                        self.next_filter();
+                        self.item_cache = vec![];
+                    }
+                    KeyCode::Char('I') => self.import_from_clipboard(),
+                    KeyCode::Char('W') => self.view = AppView::WeatherLegend,
+                    KeyCode::Char('V') => self.view = AppView::About,
+                    KeyCode::Char('J') => self.move_favourite(1),
+                    KeyCode::Char('K') => self.move_favourite(-1),
+                    KeyCode::Char('P') => {
+                        self.user_data.pin_favorites = !self.user_data.pin_favorites;
+                        let _ = self.save_user_data();
+                        self.item_cache = vec![];
+                    }
+                    KeyCode::Char('A') => {
+                        self.user_data.hide_always_available =
+                            !self.user_data.hide_always_available;
+                        let _ = self.save_user_data();
+                        self.item_cache = vec![];
+                    }
+                    KeyCode::Char('r') => {
+                        self.refresh_items();
+                        self.status = Some(self.tr("Refreshed").to_string());
+                    }
+                    KeyCode::Char('S') => {
+                        self.next_sort();
+                        self.item_cache = vec![];
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// The list order: pinned favourites in their manual order, then the rest
+    /// by the active sort.
+    fn compare_items(&self, a: &FishListItem, b: &FishListItem) -> Ordering {
+        if !self.user_data.pin_favorites {
+            return self.list_sort.compare(a, b);
+        }
+        let favorites = &self.user_data.favorites;
+        match (favorites.position(a.id), favorites.position(b.id)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => self.list_sort.compare(a, b),
+        }
+    }
+
+    /// Moves the selected favourite up (negative) or down in the manual order.
+    fn move_favourite(&mut self, offset: isize) {
+        let fish_id = match self.get_selected_fish() {
+            Some(f) => f.id,
+            None => return,
+        };
+        if !self.user_data.favorites.move_by(fish_id, offset) {
+            return;
         }
+        let _ = self.save_user_data();
+        self.refresh_items();
+        let selected = self.item_cache.iter().position(|item| item.id == fish_id);
+        self.list_state.select(selected);
     }
 
     fn get_selected_fish(&self) -> Option<&FishListItem> {
         let selected = self.list_state.selected()?;
-        Some(&self.item_cache[selected])
+        self.item_cache.get(selected)
     }
 
     fn is_favourite(&self, fish_id: u32) -> bool {
-        self.user_data.favorites.contains(&fish_id)
+        self.user_data.favorites.contains(fish_id)
     }
 
     fn is_caught(&self, fish_id: u32) -> bool {
@@ -288,39 +1375,70 @@ impl App {
 
     fn toggle_caught(&mut self, fish_id: u32) {
         if self.is_caught(fish_id) {
-            self.user_data.caught.remove(
-                self.user_data
-                    .caught
-                    .iter()
-                    .position(|x| *x == fish_id)
-                    .unwrap(),
-            );
+            self.change_caught(JournalEntry::Uncaught(fish_id));
+            let _ = self.save_user_data();
+            self.forget_catch(fish_id);
         } else {
-            self.user_data.caught.push(fish_id);
+            self.change_caught(JournalEntry::Caught(fish_id));
             let _ = self.save_user_data();
+            self.record_catch(fish_id);
         }
     }
 
     fn toggle_favourites(&mut self, fish_id: u32) {
-        if self.is_favourite(fish_id) {
-            self.user_data.favorites.remove(
-                self.user_data
-                    .favorites
-                    .iter()
-                    .position(|x| *x == fish_id)
-                    .unwrap(),
-            );
-        } else {
-            self.user_data.favorites.push(fish_id);
+        if self.user_data.favorites.toggle(fish_id) {
+            self.user_data.ignored.remove(fish_id);
             let _ = self.save_user_data();
         }
     }
 
+    pub(crate) fn is_ignored(&self, fish_id: u32) -> bool {
+        self.user_data.ignored.contains(fish_id)
+    }
+
+    /// Hides the fish everywhere but the ignored filter, or shows it again.
+    /// Ignoring also drops it from the favourites.
+    fn toggle_ignored(&mut self, fish_id: u32) {
+        let ignored = self.user_data.ignored.toggle(fish_id);
+        if ignored {
+            self.user_data.favorites.remove(fish_id);
+        }
+        let _ = self.save_user_data();
+        self.status = Some(
+            self.tr(match ignored {
+                true => "Ignored, F shows the ignored fish",
+                false => "No longer ignored",
+            })
+            .to_string(),
+        );
+    }
+
     fn is_displayed(&self, item: &FishListItem, filter: &ListFilter) -> bool {
+        if *filter != ListFilter::Ignored && self.is_ignored(item.id) {
+            return false;
+        }
         match filter {
             ListFilter::None => true,
             ListFilter::Uncaught => !self.is_caught(item.id),
             ListFilter::Favorite => self.is_favourite(item.id),
+            ListFilter::Ignored => self.is_ignored(item.id),
+        }
+    }
+
+    fn toggle_view(&mut self) {
+        self.view = match self.view {
+            AppView::List => AppView::Timeline,
+            AppView::Timeline => AppView::Zones,
+            AppView::Zones
+            | AppView::Intuition(_)
+            | AppView::Planner
+            | AppView::Achievements
+            | AppView::PatchHeatmap
+            | AppView::Alarms
+            | AppView::Presets
+            | AppView::Import
+            | AppView::WeatherLegend
+            | AppView::About => AppView::List,
         }
     }
 
@@ -328,37 +1446,140 @@ impl App {
         self.list_filter = match self.list_filter {
             ListFilter::None => ListFilter::Uncaught,
             ListFilter::Uncaught => ListFilter::Favorite,
-            ListFilter::Favorite => ListFilter::None,
+            ListFilter::Favorite => ListFilter::Ignored,
+            ListFilter::Ignored => ListFilter::None,
         }
     }
 
-    fn save_user_data(&self) -> Result<(), confy::ConfyError> {
-        confy::store("fffish-cli", "fish", self.user_data.clone())
+    fn next_sort(&mut self) {
+        self.list_sort = match self.list_sort {
+            ListSort::NextWindow => ListSort::Name,
+            ListSort::Name => ListSort::Duration,
+            ListSort::Duration => ListSort::Rarity,
+            ListSort::Rarity => ListSort::NextWindow,
+        }
     }
-    fn load_user_data(&mut self) -> Result<(), confy::ConfyError> {
-        let data: UserData = confy::load("fffish-cli", "fish")?;
+
+    fn set_user_data(&mut self, data: UserData) {
+        self.timezone = data.timezone.as_deref().and_then(|tz| tz.parse().ok());
+        self.lang = data
+            .language
+            .as_deref()
+            .and_then(Lang::from_code)
+            .unwrap_or_default();
+        // Rows hold names in the previous language.
+        self.rows.clear();
         self.user_data = data;
-        Ok(())
+        self.load_custom();
+    }
+
+    fn clock(&self) -> Clock {
+        Clock::new(self.user_data.time_display, self.timezone)
+            .with_offset(self.time_offset)
+            .with_lang(self.lang)
+    }
+
+    fn shift_time(&mut self, hours: i64) {
+        self.time_offset = match hours {
+            0 => TimeDelta::zero(),
+            h => self.time_offset + TimeDelta::hours(h),
+        };
+        self.refresh_items();
     }
 }
 
 impl Widget for &mut App {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        match self.view {
+            AppView::Timeline => return self.render_timeline(area, buf),
+            AppView::Zones => return self.render_zones(area, buf),
+            AppView::Intuition(target) => return self.render_intuition(target, area, buf),
+            AppView::Planner => return self.render_planner(area, buf),
+            AppView::Achievements => return self.render_achievements(area, buf),
+            AppView::PatchHeatmap => return self.render_heatmap(area, buf),
+            AppView::Alarms => return self.render_alarms(area, buf),
+            AppView::Import => return self.render_import(area, buf),
+            AppView::List | AppView::Presets | AppView::WeatherLegend | AppView::About => {}
+        }
         let [list_area, info_area] =
             Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(area);
+        let [info_area, recent_area] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(self.recent_height()),
+        ])
+        .areas(info_area);
         self.render_list(list_area, buf);
         self.render_info(info_area, buf);
+        if !self.recent_catches.is_empty() {
+            self.render_recent(recent_area, buf);
+        }
+        match self.view {
+            AppView::Presets => self.render_presets(area, buf),
+            AppView::WeatherLegend => self.render_weather_legend(area, buf),
+            AppView::About => self.render_about(area, buf),
+            _ => {}
+        }
+        if self.mode == AppMode::Convert {
+            self.render_converter(area, buf);
+        }
     }
 }
 
+const NO_WINDOW: &str = "no window in horizon";
+
+/// The weathers `fish` needs, e.g. "Rain -> Clouds" for Clouds after Rain.
+/// `None` if it does not care.
+fn weather_requirement(
+    fish: &Fish,
+    separator: &str,
+    name: impl Fn(&Weather) -> String,
+) -> Option<String> {
+    weather_sets_text(
+        &fish.previous_weather_set,
+        &fish.weather_set,
+        separator,
+        name,
+    )
+}
+
+/// `weather_requirement` of any weather sets.
+fn weather_sets_text(
+    previous: &[Weather],
+    current: &[Weather],
+    separator: &str,
+    name: impl Fn(&Weather) -> String,
+) -> Option<String> {
+    let names = |set: &[Weather]| set.iter().map(&name).collect::<Vec<_>>().join(separator);
+    match (previous, current) {
+        ([], []) => None,
+        ([], current) => Some(names(current)),
+        (previous, []) => Some(format!("{} -> any", names(previous))),
+        (previous, current) => Some(format!("{} -> {}", names(previous), names(current))),
+    }
+}
+
+fn duration_text(t: TimeDelta, lang: Lang) -> String {
+    display::duration_text(t.to_std().unwrap_or_default(), lang)
+}
+
 #[derive(Clone)]
 struct FishListItem {
     name: String,
     id: u32,
     bait: Option<FishingItem>,
-    next_window: EorzeaTimeSpan,
+    /// `None` if no window was found within the search horizon.
+    next_window: Option<EorzeaTimeSpan>,
+    rarity: f64,
     favourite: bool,
     caught: bool,
+    /// Caught in this session while the current window was open.
+    caught_this_window: bool,
+    /// Abbreviated weather requirement, see `weather_requirement`.
+    weather: Option<String>,
+    clock: Clock,
+    lang: Lang,
+    /// A custom entry rather than a fish, see `custom`.
+    custom: bool,
 }
 
 impl FishListItem {
@@ -376,49 +1597,153 @@ impl FishListItem {
 
 impl From<&FishListItem> for ListItem<'_> {
     fn from(value: &FishListItem) -> Self {
-        let style = match value.next_window_start_local() - chrono::Local::now() {
-            t if t < TimeDelta::minutes(0) => Color::Blue.into(),
-            t if t < TimeDelta::minutes(10) => Color::Red.into(),
-            t if t < TimeDelta::minutes(30) => Color::Yellow.into(),
-            _ => Style::new(),
+        ListItem::new(value.line())
+    }
+}
+
+impl FishListItem {
+    /// The item as a list row.
+    fn line(&self) -> Line<'static> {
+        let style = match self
+            .next_window_start_local()
+            .map(|start| start - self.clock.local_now())
+        {
+            Some(t) if t < TimeDelta::minutes(0) => Color::Blue.into(),
+            Some(t) if t < TimeDelta::minutes(10) => Color::Red.into(),
+            Some(t) if t < TimeDelta::minutes(30) => Color::Yellow.into(),
+            Some(_) => Style::new(),
+            None => Color::DarkGray.into(),
         };
-        let line = Line::styled(
+        // Custom entries have no item id worth showing.
+        let id = match self.custom {
+            true => String::new(),
+            false => format!("{} - ", self.id),
+        };
+        let text = Span::styled(
             format!(
-                "{}{} - {} - {}",
-                value.get_icon(),
-                value.id,
-                value.name,
-                value.time_to_window_string(),
+                "{}{}{} - {}",
+                self.get_icon(),
+                id,
+                self.name,
+                self.time_to_window_string(),
             ),
             style,
         );
-        ListItem::new(line)
+        let details = Span::styled(
+            format!(
+                "  {} · {}{}",
+                self.window_duration()
+                    .map_or("-".to_string(), |t| duration_text(t, self.lang)),
+                self.rarity_text(),
+                self.weather
+                    .as_ref()
+                    .map_or(String::new(), |w| format!(" · {}", w))
+            ),
+            Style::from(Color::DarkGray),
+        );
+        let mut spans = match self.open_remaining() {
+            Some(remaining) => vec![
+                Span::styled(
+                    format!(
+                        " {} ",
+                        i18n::trf(
+                            self.lang,
+                            "OPEN {}",
+                            &[&duration_text(remaining, self.lang)]
+                        )
+                    ),
+                    Style::new().fg(Color::Black).bg(Color::Blue).bold(),
+                ),
+                Span::raw(" "),
+                text,
+                details,
+            ],
+            None => vec![text, details],
+        };
+        if self.caught_this_window {
+            // Ahead of the name so it stays visible in a narrow list.
+            let text_index = spans.len() - 2;
+            spans.insert(
+                text_index,
+                Span::styled(
+                    format!("{} ", i18n::tr(self.lang, "caught this window")),
+                    Style::from(Color::Green),
+                ),
+            );
+        }
+        Line::from(spans)
     }
 }
 
 impl FishListItem {
-    fn next_window_start_local(&self) -> chrono::DateTime<Local> {
-        self.next_window.start().to_system_time().into()
+    fn next_window_start_local(&self) -> Option<chrono::DateTime<Local>> {
+        self.next_window
+            .as_ref()
+            .map(|w| w.start().to_system_time().into())
+    }
+    /// Real time left in the current window, if it is open.
+    fn open_remaining(&self) -> Option<TimeDelta> {
+        let window = self.next_window.as_ref()?;
+        let now = self.clock.local_now();
+        let start: chrono::DateTime<Local> = window.start().to_system_time().into();
+        let end: chrono::DateTime<Local> = window.end().to_system_time().into();
+        (start <= now && now < end).then_some(end - now)
+    }
+    /// Real length of the next window.
+    fn window_duration(&self) -> Option<TimeDelta> {
+        let window = self.next_window.as_ref()?;
+        TimeDelta::from_std(window.duration().to_real()).ok()
     }
-    fn next_window_end_local(&self) -> chrono::DateTime<Local> {
-        self.next_window.end().to_system_time().into()
+    fn rarity_text(&self) -> String {
+        match self.rarity {
+            r if r.is_infinite() => i18n::tr(self.lang, "never up").to_string(),
+            r if r < 1.05 => i18n::tr(self.lang, "always up").to_string(),
+            r => i18n::trf(self.lang, "1 in {}", &[&format!("{:.0}", r)]),
+        }
+    }
+    fn is_open(&self) -> bool {
+        self.open_remaining().is_some()
+    }
+    fn window_span(&self, clock: Clock) -> String {
+        self.next_window
+            .as_ref()
+            .map_or(i18n::tr(self.lang, NO_WINDOW).to_string(), |w| {
+                clock.span(w)
+            })
     }
     fn time_to_window_string(&self) -> String {
-        match self.next_window_start_local() - chrono::Local::now() {
-            t if t < TimeDelta::minutes(0) => {
-                let t2 = self.next_window_end_local() - chrono::Local::now();
-                format!("for {} more min", t2.num_minutes() % 60)
-            }
-            t if t < TimeDelta::minutes(60) => {
-                format!("in {} min", t.num_minutes() % 60)
-            }
-            t if t < TimeDelta::days(1) => {
-                format!("in {}h {:0>2}min", t.num_hours() % 24, t.num_minutes() % 60)
-            }
-            _ => self
-                .next_window_start_local()
-                .format("%Y-%m-%d %H:%M:%S")
-                .to_string(),
+        self.next_window
+            .as_ref()
+            .map_or(i18n::tr(self.lang, NO_WINDOW).to_string(), |w| {
+                self.clock.countdown(w)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn at_times() {
+        let at = parse_at("2026-03-01 14:30").unwrap();
+        assert_eq!(
+            at.naive_local(),
+            NaiveDate::from_ymd_opt(2026, 3, 1)
+                .unwrap()
+                .and_hms_opt(14, 30, 0)
+                .unwrap()
+        );
+        let at = parse_at("2026-03-01T14:30:00+09:00").unwrap();
+        assert_eq!(
+            at.with_timezone(&Utc),
+            Utc.with_ymd_and_hms(2026, 3, 1, 5, 30, 0).unwrap()
+        );
+        for text in ["2026-03-01", "14:30", "2026-03-01 25:00", "tomorrow"] {
+            let error = parse_at(text).unwrap_err();
+            assert!(error.ends_with(text), "{}", error);
         }
     }
 }
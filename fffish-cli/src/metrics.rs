@@ -0,0 +1,129 @@
+//! A Prometheus text endpoint at `/metrics`, so whoever relies on the alarms
+//! can alert when this instance stops refreshing.
+
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use ffxivfishing::eorzea_time::EorzeaDuration;
+
+use crate::App;
+
+/// How far ahead `fffish_upcoming_windows` counts.
+const UPCOMING_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+pub(crate) struct MetricsServer {
+    listener: TcpListener,
+}
+
+impl MetricsServer {
+    pub(crate) fn bind(addr: SocketAddr) -> io::Result<MetricsServer> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(MetricsServer { listener })
+    }
+
+    fn accept(&self) -> Option<TcpStream> {
+        let (stream, _) = self.listener.accept().ok()?;
+        stream.set_nonblocking(false).ok()?;
+        stream
+            .set_read_timeout(Some(Duration::from_millis(500)))
+            .ok()?;
+        Some(stream)
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn gauge(out: &mut String, name: &str, help: &str, value: impl std::fmt::Display) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+impl App {
+    fn metrics(&self) -> String {
+        let now = self.clock().now();
+        let until = now + EorzeaDuration::from_real(UPCOMING_WINDOW);
+        let favorites = &self.user_data.favorites;
+        let upcoming = favorites
+            .upcoming_windows(&self.fish_data, now, favorites.len() * 8)
+            .iter()
+            .filter(|w| w.window.start() < until)
+            .count();
+        let last_refresh = self
+            .last_refresh
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        let mut out = String::new();
+        gauge(
+            &mut out,
+            "fffish_tracked_fish",
+            "Fish on the favourites list.",
+            favorites.len(),
+        );
+        gauge(
+            &mut out,
+            "fffish_upcoming_windows",
+            "Windows of favourites opening within the next hour.",
+            upcoming,
+        );
+        gauge(
+            &mut out,
+            "fffish_alarms",
+            "Configured alarms.",
+            self.user_data.alarms.len(),
+        );
+        gauge(
+            &mut out,
+            "fffish_last_refresh_timestamp_seconds",
+            "When the fish list was last recomputed.",
+            last_refresh,
+        );
+        let _ = writeln!(out, "# HELP fffish_data_info The loaded fish data.");
+        let _ = writeln!(out, "# TYPE fffish_data_info gauge");
+        let _ = writeln!(
+            out,
+            "fffish_data_info{{version=\"{}\"}} 1",
+            escape_label(&self.data_source.version(&self.fish_data))
+        );
+        out
+    }
+
+    /// Answers all pending scrapes without blocking the UI for long.
+    pub(crate) fn handle_metrics(&mut self) {
+        while let Some(mut stream) = self.metrics.as_ref().and_then(|s| s.accept()) {
+            let mut request = String::new();
+            {
+                let mut reader = BufReader::new(&mut stream);
+                if reader.read_line(&mut request).is_err() {
+                    continue;
+                }
+                // Drain the headers so closing does not reset the connection.
+                let mut header = String::new();
+                while reader.read_line(&mut header).is_ok_and(|n| n > 2) {
+                    header.clear();
+                }
+            }
+            let response = match request.split_whitespace().nth(1) {
+                Some("/metrics") => {
+                    let body = self.metrics();
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                }
+                _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string(),
+            };
+            let _ = stream.write_all(response.as_bytes());
+        }
+    }
+}
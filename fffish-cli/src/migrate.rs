@@ -0,0 +1,144 @@
+use std::{fs, path::Path};
+
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, eyre},
+};
+use toml::Table;
+
+use crate::{UserData, storage};
+
+/// Version of the settings layout written by this build. Bump it together
+/// with a new entry in `MIGRATIONS` whenever a field is renamed, moved or
+/// changes meaning.
+pub(crate) const USER_DATA_VERSION: u32 = 1;
+
+/// `MIGRATIONS[n]` turns settings of version `n` into version `n + 1`.
+const MIGRATIONS: [fn(&mut Table); USER_DATA_VERSION as usize] = [
+    // Files from before versioning only lack fields that have defaults.
+    |_| {},
+];
+
+/// Loads the settings, migrating older layouts step by step. The file as it
+/// was is kept next to it before a migrated version replaces it. Unreadable
+/// settings are an error instead of being replaced by defaults, so a caught
+/// list is never lost to a typo or a downgrade.
+pub(crate) fn load_user_data(path: &Path) -> Result<UserData> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(UserData {
+                version: USER_DATA_VERSION,
+                ..UserData::default()
+            });
+        }
+        Err(e) => return Err(e).wrap_err_with(|| format!("Reading {} failed", path.display())),
+    };
+    let mut table: Table = text
+        .parse()
+        .wrap_err_with(|| format!("Parsing {} failed", path.display()))?;
+    let version = match table.get("version") {
+        None => 0,
+        Some(v) => v
+            .as_integer()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| eyre!("{} has an invalid version: {}", path.display(), v))?,
+    };
+    if version > USER_DATA_VERSION {
+        return Err(eyre!(
+            "{} was written by a newer fffish-cli (settings version {}, this one knows {})",
+            path.display(),
+            version,
+            USER_DATA_VERSION
+        ));
+    }
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(&mut table);
+    }
+    table.insert("version".to_string(), USER_DATA_VERSION.into());
+    let data: UserData = table
+        .try_into()
+        .wrap_err_with(|| format!("Parsing {} failed", path.display()))?;
+    if version < USER_DATA_VERSION {
+        let backup = path.with_extension(format!("v{}.toml", version));
+        fs::copy(path, &backup)
+            .wrap_err_with(|| format!("Backing up {} failed", path.display()))?;
+        storage::write_settings(path, &data)
+            .wrap_err_with(|| format!("Saving the migrated {} failed", path.display()))?;
+        tracing::info!(from = version, to = USER_DATA_VERSION, "migrated settings");
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    fn settings_in(name: &str) -> std::path::PathBuf {
+        let dir = env::temp_dir().join(format!("fffish-migrate-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("fish.toml")
+    }
+
+    fn settings_text(version: Option<i64>) -> String {
+        let mut table = Table::try_from(UserData {
+            caught: vec![3, 5],
+            ..UserData::default()
+        })
+        .unwrap();
+        match version {
+            Some(version) => table.insert("version".to_string(), version.into()),
+            None => table.remove("version"),
+        };
+        toml::to_string(&table).unwrap()
+    }
+
+    #[test]
+    fn missing_settings() {
+        let path = settings_in("missing");
+        let data = load_user_data(&path).unwrap();
+        assert_eq!(data.version, USER_DATA_VERSION);
+        assert!(data.caught.is_empty());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn unversioned_settings() {
+        let path = settings_in("unversioned");
+        let text = settings_text(None);
+        fs::write(&path, &text).unwrap();
+        let data = load_user_data(&path).unwrap();
+        assert_eq!(data.version, USER_DATA_VERSION);
+        assert_eq!(data.caught, [3, 5]);
+        assert_eq!(
+            fs::read_to_string(path.with_extension("v0.toml")).unwrap(),
+            text
+        );
+        let saved: Table = fs::read_to_string(&path).unwrap().parse().unwrap();
+        assert_eq!(
+            saved["version"].as_integer(),
+            Some(USER_DATA_VERSION as i64)
+        );
+        // Loading it again migrates nothing.
+        fs::remove_file(path.with_extension("v0.toml")).unwrap();
+        load_user_data(&path).unwrap();
+        assert!(!path.with_extension("v0.toml").exists());
+    }
+
+    #[test]
+    fn unreadable_settings() {
+        let path = settings_in("unreadable");
+        for text in [
+            "caught = [3, 5".to_string(),
+            settings_text(Some(USER_DATA_VERSION as i64 + 1)),
+            settings_text(Some(-1)),
+        ] {
+            fs::write(&path, &text).unwrap();
+            assert!(load_user_data(&path).is_err(), "{}", text);
+            assert_eq!(fs::read_to_string(&path).unwrap(), text);
+        }
+    }
+}
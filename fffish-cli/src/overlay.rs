@@ -0,0 +1,172 @@
+//! Writes the selected fish to a file for stream overlays, e.g. an OBS browser
+//! or text source. The format follows the file extension: `.html`, `.json`,
+//! or plain text for anything else.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use ffxivfishing::{eorzea_time::EorzeaTime, lang::Lang};
+use serde::Serialize;
+
+use crate::{App, FishListItem};
+
+pub(crate) struct Overlay {
+    path: PathBuf,
+    /// Last content written, so the file is only touched when it changes.
+    written: String,
+}
+
+#[derive(Serialize)]
+struct OverlayTarget<'a> {
+    id: u32,
+    name: &'a str,
+    bait: Option<String>,
+    conditions: Option<String>,
+    open: bool,
+    countdown: String,
+    /// Unix timestamps of the next or current window.
+    start: Option<u64>,
+    end: Option<u64>,
+}
+
+fn unix_secs(time: EorzeaTime) -> u64 {
+    time.to_system_time()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Refreshes itself to pick up a new target and counts down in the browser,
+/// so the countdown ticks even though the file changes less often.
+fn html(target: &OverlayTarget) -> String {
+    let bait = target.bait.as_deref().map_or("".to_string(), |b| {
+        format!("<div class=\"bait\">{}</div>", escape_html(b))
+    });
+    let conditions = target.conditions.as_deref().map_or("".to_string(), |c| {
+        format!("<div class=\"conditions\">{}</div>", escape_html(c))
+    });
+    let (deadline, prefix) = match (target.open, target.start, target.end) {
+        (true, _, Some(end)) => (end, "open for "),
+        (false, Some(start), _) => (start, "in "),
+        _ => (0, ""),
+    };
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta http-equiv="refresh" content="10">
+<style>body {{ font-family: sans-serif; color: white; text-shadow: 1px 1px 2px black; }}</style>
+</head>
+<body>
+<div class="name">{name}</div>
+<div class="countdown" id="countdown">{countdown}</div>
+{conditions}
+{bait}
+<script>
+const deadline = {deadline};
+const tick = () => {{
+  const left = deadline - Math.floor(Date.now() / 1000);
+  if (deadline === 0 || left < 0) return;
+  const m = Math.floor(left / 60), s = String(left % 60).padStart(2, "0");
+  document.getElementById("countdown").textContent = "{prefix}" + m + ":" + s;
+}};
+tick();
+setInterval(tick, 1000);
+</script>
+</body>
+</html>
+"#,
+        name = escape_html(target.name),
+        countdown = escape_html(&target.countdown),
+    )
+}
+
+fn text(target: &OverlayTarget) -> String {
+    let mut lines = vec![target.name.to_string(), target.countdown.clone()];
+    if let Some(conditions) = &target.conditions {
+        lines.push(conditions.clone());
+    }
+    if let Some(bait) = &target.bait {
+        lines.push(format!("Bait: {}", bait));
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Writes via a temporary file so the overlay never reads half a file.
+fn write_atomic(path: &Path, content: &str) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(&tmp, path)
+}
+
+impl Overlay {
+    pub(crate) fn new(path: PathBuf) -> Overlay {
+        Overlay {
+            path,
+            written: String::new(),
+        }
+    }
+
+    fn render(&self, target: Option<&OverlayTarget>) -> String {
+        let extension = self.path.extension().and_then(|e| e.to_str());
+        match (extension, target) {
+            (Some("json"), target) => serde_json::to_string(&target).unwrap_or_default(),
+            (Some("html" | "htm"), Some(target)) => html(target),
+            (Some("html" | "htm"), None) => {
+                "<!DOCTYPE html>\n<meta http-equiv=\"refresh\" content=\"10\">\n".to_string()
+            }
+            (_, Some(target)) => text(target),
+            (_, None) => "".to_string(),
+        }
+    }
+}
+
+impl App {
+    fn overlay_target<'a>(&self, item: &'a FishListItem) -> OverlayTarget<'a> {
+        OverlayTarget {
+            id: item.id,
+            name: &item.name,
+            bait: item.bait.as_ref().map(|b| self.bait_text(b)),
+            conditions: self
+                .fish_data
+                .fish_by_id(item.id)
+                .map(|f| self.fish_data.conditions_summary(f, Lang::default())),
+            open: item.is_open(),
+            countdown: item.time_to_window_string(),
+            start: item.next_window.as_ref().map(|w| unix_secs(w.start())),
+            end: item.next_window.as_ref().map(|w| unix_secs(w.end())),
+        }
+    }
+
+    /// Writes the selected fish to the overlay file if anything changed.
+    pub(crate) fn update_overlay(&mut self) {
+        let overlay = match &self.overlay {
+            Some(o) => o,
+            None => return,
+        };
+        let target = self.get_selected_fish().map(|i| self.overlay_target(i));
+        let content = overlay.render(target.as_ref());
+        if content == overlay.written {
+            return;
+        }
+        match write_atomic(&overlay.path, &content) {
+            Ok(()) => {
+                if let Some(o) = self.overlay.as_mut() {
+                    o.written = content;
+                }
+            }
+            Err(e) => self.status = Some(self.trf("Writing the overlay failed: {}", &[&e])),
+        }
+    }
+}
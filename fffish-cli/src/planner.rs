@@ -0,0 +1,193 @@
+use std::{fs, path, time::Duration};
+
+use arboard::Clipboard;
+
+use ffxivfishing::{
+    eorzea_time::EorzeaDuration,
+    planner::{PlanWarning, Planner},
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    text::Line,
+    widgets::{Block, List, ListItem, ListState, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{
+    App, AppView,
+    clock::{Clock, TimeDisplay},
+};
+
+const EXPORT_PATH: &str = "fffish-plan.txt";
+
+pub(crate) struct PlannerState {
+    pub(crate) list_state: ListState,
+    targets: Vec<u32>,
+    hours: u64,
+    buffer_minutes: u64,
+    status: Option<String>,
+}
+
+impl Default for PlannerState {
+    fn default() -> Self {
+        PlannerState {
+            list_state: ListState::default(),
+            targets: vec![],
+            hours: 4,
+            buffer_minutes: 5,
+            status: None,
+        }
+    }
+}
+
+impl App {
+    pub(crate) fn open_planner(&mut self) {
+        self.planner.targets = self.user_data.favorites.ids().to_vec();
+        self.planner.list_state.select_first();
+        self.planner.status = None;
+        self.view = AppView::Planner;
+    }
+
+    pub(crate) fn toggle_planner_target(&mut self) {
+        let fish_id = match self
+            .planner
+            .list_state
+            .selected()
+            .and_then(|i| self.user_data.favorites.get(i))
+        {
+            Some(id) => id,
+            None => return,
+        };
+        match self.planner.targets.iter().position(|id| *id == fish_id) {
+            Some(i) => {
+                self.planner.targets.remove(i);
+            }
+            None => self.planner.targets.push(fish_id),
+        }
+    }
+
+    pub(crate) fn change_planner_horizon(&mut self, hours: i64) {
+        self.planner.hours = self.planner.hours.saturating_add_signed(hours).clamp(1, 72);
+    }
+
+    pub(crate) fn change_planner_buffer(&mut self, minutes: i64) {
+        self.planner.buffer_minutes = self
+            .planner
+            .buffer_minutes
+            .saturating_add_signed(minutes)
+            .min(60);
+    }
+
+    fn plan_lines(&self) -> Vec<String> {
+        let targets: Vec<_> = self
+            .planner
+            .targets
+            .iter()
+            .filter_map(|id| self.fish_data.fish_by_id(*id))
+            .collect();
+        let planner = Planner::new(
+            EorzeaDuration::from_real(Duration::from_secs(self.planner.hours * 3600)),
+            EorzeaDuration::from_real(Duration::from_secs(self.planner.buffer_minutes * 60)),
+        );
+        let now = self.clock().now();
+        let plan = planner.plan(&targets, now);
+        let clock = Clock::new(TimeDisplay::Local, self.timezone).with_offset(self.time_offset);
+
+        let mut lines = vec![format!(
+            "Fishing plan from {} ({}h, {} min travel buffer)",
+            clock.date_time(now),
+            self.planner.hours,
+            self.planner.buffer_minutes
+        )];
+        for entry in &plan.entries {
+            let warning = match entry.warning {
+                Some(PlanWarning::LateArrival(missed)) => {
+                    format!("  ! arrives {} min late", missed.to_real().as_secs() / 60)
+                }
+                Some(PlanWarning::Conflict) => "  ! overlaps another target".to_string(),
+                None => "".to_string(),
+            };
+            lines.push(format!(
+                "{}  {} @ {}{}",
+                clock.span(&entry.window),
+                entry.fish.name(),
+                entry.fish.location.name(),
+                warning
+            ));
+        }
+        if !plan.unplanned.is_empty() {
+            lines.push(format!(
+                "No window within horizon: {}",
+                plan.unplanned
+                    .iter()
+                    .map(|f| f.name())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ));
+        }
+        lines
+    }
+
+    fn plan_text(&self) -> String {
+        self.plan_lines().join("\n") + "\n"
+    }
+
+    /// Writes the plan to `fffish-plan.txt` in the working directory.
+    pub(crate) fn export_plan(&mut self) {
+        let path = path::absolute(EXPORT_PATH).unwrap_or_else(|_| EXPORT_PATH.into());
+        self.planner.status = Some(match fs::write(&path, self.plan_text()) {
+            Ok(()) => self.trf("Exported to {}", &[&path.display()]),
+            Err(e) => self.trf("Export failed: {}", &[&e]),
+        });
+    }
+
+    pub(crate) fn copy_plan(&mut self) {
+        let text = self.plan_text();
+        if self.clipboard.is_none() {
+            self.clipboard = Clipboard::new().ok();
+        }
+        self.planner.status = Some(match self.clipboard.as_mut().map(|c| c.set_text(text)) {
+            Some(Ok(())) => self.tr("Copied to clipboard").to_string(),
+            Some(Err(e)) => self.trf("Copy failed: {}", &[&e]),
+            None => self.tr("No clipboard available").to_string(),
+        });
+    }
+
+    pub(crate) fn render_planner(&mut self, area: Rect, buf: &mut Buffer) {
+        let [target_area, plan_area] =
+            Layout::horizontal([Constraint::Length(36), Constraint::Fill(1)]).areas(area);
+
+        let targets: Vec<ListItem> = self
+            .user_data
+            .favorites
+            .fishes(&self.fish_data)
+            .map(|f| {
+                let mark = match self.planner.targets.contains(&f.id) {
+                    true => "[x]",
+                    false => "[ ]",
+                };
+                ListItem::new(format!("{} {}", mark, f.name()))
+            })
+            .collect();
+        StatefulWidget::render(
+            List::new(targets)
+                .block(Block::bordered().title(format!(" {} ", self.tr("Targets (Space)"))))
+                .highlight_symbol("> "),
+            target_area,
+            buf,
+            &mut self.planner.list_state,
+        );
+
+        let title = match &self.planner.status {
+            Some(status) => format!(" {} ", status),
+            None => format!(
+                " {} ",
+                self.tr("Plan (+/-: horizon, [/]: buffer, e: export, c: copy, Esc: back)")
+            ),
+        };
+        let lines: Vec<Line> = self.plan_lines().into_iter().map(Line::from).collect();
+        Paragraph::new(lines)
+            .block(Block::bordered().title(title))
+            .render(plan_area, buf);
+    }
+}
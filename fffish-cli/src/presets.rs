@@ -0,0 +1,175 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::Color,
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph, StatefulWidget, Widget},
+};
+use serde::{Deserialize, Serialize};
+use tui_input::Input;
+
+use crate::{App, AppMode, AppView, ListFilter, ListSort};
+
+/// A saved combination of list filter, sort and search.
+#[derive(Serialize, Deserialize, Clone)]
+pub(crate) struct Preset {
+    name: String,
+    filter: ListFilter,
+    sort: ListSort,
+    #[serde(default)]
+    search: String,
+    #[serde(default)]
+    hide_always_available: bool,
+}
+
+#[derive(Default)]
+pub(crate) struct PresetState {
+    pub(crate) list_state: ListState,
+    /// The name typed while saving a preset.
+    pub(crate) name: Input,
+}
+
+impl App {
+    pub(crate) fn open_presets(&mut self) {
+        self.view = AppView::Presets;
+        if self.presets.list_state.selected().is_none() {
+            self.presets.list_state.select_first();
+        }
+    }
+
+    pub(crate) fn start_save_preset(&mut self) {
+        self.presets.name = Input::default();
+        self.mode = AppMode::PresetName;
+    }
+
+    /// Saves the current list settings under the typed name, replacing a
+    /// preset of the same name.
+    pub(crate) fn save_preset(&mut self) {
+        self.mode = AppMode::List;
+        let name = self.presets.name.value().trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        let preset = Preset {
+            name: name.clone(),
+            filter: self.list_filter.clone(),
+            sort: self.list_sort.clone(),
+            search: self.input.value().to_string(),
+            hide_always_available: self.user_data.hide_always_available,
+        };
+        let presets = &mut self.user_data.presets;
+        let index = match presets.iter().position(|p| p.name == name) {
+            Some(index) => {
+                presets[index] = preset;
+                index
+            }
+            None => {
+                presets.push(preset);
+                presets.len() - 1
+            }
+        };
+        self.presets.list_state.select(Some(index));
+        self.status = Some(match self.save_user_data() {
+            Ok(()) => self.trf("Saved preset {}", &[&name]),
+            Err(e) => self.trf("Saving preset failed: {}", &[&e]),
+        });
+    }
+
+    pub(crate) fn delete_preset(&mut self) {
+        if let Some(index) = self.presets.list_state.selected()
+            && index < self.user_data.presets.len()
+        {
+            self.user_data.presets.remove(index);
+            let _ = self.save_user_data();
+        }
+    }
+
+    /// Applies the preset called `name`. Returns false if there is none.
+    pub(crate) fn apply_preset(&mut self, name: &str) -> bool {
+        let Some(preset) = self.user_data.presets.iter().find(|p| p.name == name) else {
+            return false;
+        };
+        self.list_filter = preset.filter.clone();
+        self.list_sort = preset.sort.clone();
+        self.input = Input::new(preset.search.clone());
+        if self.user_data.hide_always_available != preset.hide_always_available {
+            self.user_data.hide_always_available = preset.hide_always_available;
+            let _ = self.save_user_data();
+        }
+        self.item_cache = vec![];
+        self.list_state.select_first();
+        true
+    }
+
+    pub(crate) fn apply_selected_preset(&mut self) {
+        let name = self
+            .presets
+            .list_state
+            .selected()
+            .and_then(|i| self.user_data.presets.get(i))
+            .map(|p| p.name.clone());
+        if let Some(name) = name {
+            self.apply_preset(&name);
+            self.view = AppView::List;
+        }
+    }
+
+    /// Draws the preset picker over the middle of `area`.
+    pub(crate) fn render_presets(&mut self, area: Rect, buf: &mut Buffer) {
+        let naming = self.mode == AppMode::PresetName;
+        let name_height = match naming {
+            true => 3,
+            false => 0,
+        };
+        let height = self.user_data.presets.len() as u16 + 2 + name_height;
+        let [area] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [area] = Layout::horizontal([Constraint::Percentage(60)])
+            .flex(Flex::Center)
+            .areas(area);
+        Clear.render(area, buf);
+        let [list_area, name_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(name_height)]).areas(area);
+
+        let items: Vec<ListItem> = self
+            .user_data
+            .presets
+            .iter()
+            .map(|p| {
+                let search = match p.search.as_str() {
+                    "" => "".to_string(),
+                    search => format!(", \"{}\"", search),
+                };
+                ListItem::new(format!(
+                    "{}: {} by {}{}{}",
+                    p.name,
+                    p.filter,
+                    p.sort,
+                    search,
+                    match p.hide_always_available {
+                        true => ", no always up",
+                        false => "",
+                    }
+                ))
+            })
+            .collect();
+        StatefulWidget::render(
+            List::new(items)
+                .block(Block::bordered().title(format!(
+                    " {} ",
+                    self.tr("Presets (Enter: apply, s: save current, d: delete, Esc: back)")
+                )))
+                .highlight_symbol("> "),
+            list_area,
+            buf,
+            &mut self.presets.list_state,
+        );
+
+        if naming {
+            Paragraph::new(self.presets.name.value())
+                .style(Color::Blue)
+                .block(Block::bordered().title(self.tr("Name (Enter: save, Esc: cancel)")))
+                .render(name_area, buf);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use ffxivfishing::{eorzea_time::EorzeaTimeSpan, search::WindowQuery};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::App;
+
+/// How many catches the recently caught section shows.
+const RECENT_SHOWN: usize = 5;
+
+impl App {
+    /// Remembers a fish marked caught in this session.
+    pub(crate) fn record_catch(&mut self, fish_id: u32) {
+        self.recent_catches.retain(|(id, _)| *id != fish_id);
+        self.recent_catches.push((fish_id, self.clock().now()));
+        if let Some(fish) = self.fish_data.fish_by_id(fish_id) {
+            self.status = Some(self.trf("Caught {}", &[&self.fish_name(fish)]));
+        }
+    }
+
+    pub(crate) fn forget_catch(&mut self, fish_id: u32) {
+        self.recent_catches.retain(|(id, _)| *id != fish_id);
+    }
+
+    /// Whether the fish was caught in this session during `window`.
+    pub(crate) fn caught_in(&self, fish_id: u32, window: &EorzeaTimeSpan) -> bool {
+        self.recent_catches
+            .iter()
+            .any(|(id, at)| *id == fish_id && window.start() <= *at && *at < window.end())
+    }
+
+    /// Whether the fish's window is open now and it was already caught in it.
+    pub(crate) fn caught_this_window(&self, fish_id: u32) -> bool {
+        // Most fish were not caught this session, which saves their search.
+        if !self.recent_catches.iter().any(|(id, _)| *id == fish_id) {
+            return false;
+        }
+        let Some(fish) = self.fish_data.fish_by_id(fish_id) else {
+            return false;
+        };
+        let now = self.clock().now();
+        fish.next_window(&WindowQuery::new(now).include_ongoing(true))
+            .is_ok_and(|w| w.start() <= now && self.caught_in(fish_id, &w))
+    }
+
+    pub(crate) fn recent_height(&self) -> u16 {
+        match self.recent_catches.len() {
+            0 => 0,
+            n => n.min(RECENT_SHOWN) as u16 + 2,
+        }
+    }
+
+    /// The newest catches of this session, marking the ones whose window is
+    /// still open.
+    pub(crate) fn render_recent(&self, area: Rect, buf: &mut Buffer) {
+        let clock = self.clock();
+        let lines: Vec<Line> = self
+            .recent_catches
+            .iter()
+            .rev()
+            .take(RECENT_SHOWN)
+            .map(|(id, at)| {
+                let name = self
+                    .fish_data
+                    .fish_by_id(*id)
+                    .map_or(format!("#{}", id), |f| self.fish_name(f).to_string());
+                let line = Line::from(format!("{}  {}", clock.time(*at), name));
+                match self.caught_this_window(*id) {
+                    true => line.fg(Color::Green),
+                    false => line,
+                }
+            })
+            .collect();
+        Paragraph::new(lines)
+            .block(Block::bordered().title(format!(" {} ", self.tr("Recently caught"))))
+            .render(area, buf);
+    }
+}
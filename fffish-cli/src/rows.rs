@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use chrono::TimeDelta;
+use ffxivfishing::{display::TimeDisplay, eorzea_time::EorzeaTimeSpan};
+use ratatui::{
+    text::{Line, Span},
+    widgets::ListItem,
+};
+
+use crate::FishListItem;
+
+/// What a list row's text depends on besides the fish. Countdowns are shown
+/// in whole minutes, so a row only changes when its minute does.
+#[derive(PartialEq)]
+struct RowKey {
+    window: Option<EorzeaTimeSpan>,
+    open: bool,
+    minutes: i64,
+    favourite: bool,
+    caught: bool,
+    caught_this_window: bool,
+    display: TimeDisplay,
+}
+
+impl RowKey {
+    fn new(item: &FishListItem, display: TimeDisplay) -> RowKey {
+        let now = item.clock.local_now();
+        let (open, remaining) = match item.open_remaining() {
+            Some(remaining) => (true, remaining),
+            None => (
+                false,
+                item.next_window_start_local()
+                    .map_or(TimeDelta::zero(), |start| start - now),
+            ),
+        };
+        RowKey {
+            window: item.next_window.clone(),
+            open,
+            minutes: remaining.num_minutes(),
+            favourite: item.favourite,
+            caught: item.caught,
+            caught_this_window: item.caught_this_window,
+            display,
+        }
+    }
+}
+
+/// Rendered list rows by fish id, rebuilt only when their text changes so
+/// an idle list is redrawn without formatting every row again.
+#[derive(Default)]
+pub(crate) struct RowCache {
+    rows: HashMap<u32, (RowKey, Line<'static>)>,
+}
+
+impl RowCache {
+    /// Forgets every row, for changes the keys do not cover such as the
+    /// language.
+    pub(crate) fn clear(&mut self) {
+        self.rows.clear();
+    }
+
+    /// Rebuilds the rows of `items` that changed since they were last drawn.
+    pub(crate) fn update(&mut self, items: &[FishListItem], display: TimeDisplay) {
+        for item in items {
+            let key = RowKey::new(item, display);
+            match self.rows.get_mut(&item.id) {
+                Some((cached, _)) if *cached == key => {}
+                Some(row) => *row = (key, item.line()),
+                None => {
+                    self.rows.insert(item.id, (key, item.line()));
+                }
+            }
+        }
+    }
+
+    /// The row of `item`, borrowing its text from the cache.
+    pub(crate) fn item(&self, item: &FishListItem) -> ListItem<'_> {
+        match self.rows.get(&item.id) {
+            Some((_, line)) => ListItem::new(Line::from(
+                line.spans
+                    .iter()
+                    .map(|span| Span::styled(span.content.as_ref(), span.style))
+                    .collect::<Vec<_>>(),
+            )),
+            None => ListItem::new(item.line()),
+        }
+    }
+}
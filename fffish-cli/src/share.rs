@@ -0,0 +1,59 @@
+use arboard::Clipboard;
+use ffxivfishing::fish::Fish;
+
+use crate::{App, FishListItem, NO_WINDOW};
+
+impl App {
+    /// E.g. "intuition: 3× Shall Shell, 1× Cupfish (60s)".
+    fn intuition_text(&self, fish: &Fish) -> Option<String> {
+        let length = fish.intuition_length()?;
+        let predators: Vec<String> = self
+            .fish_data
+            .predators(fish)
+            .iter()
+            .map(|(count, predator)| format!("{}× {}", count, predator.name()))
+            .collect();
+        if predators.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "intuition: {} ({}s)",
+            predators.join(", "),
+            length.as_secs()
+        ))
+    }
+
+    /// A one-line summary of the fish, meant for pasting into chat.
+    fn share_text(&self, item: &FishListItem) -> String {
+        let mut parts = vec![match &item.next_window {
+            Some(window) => format!("window {}", item.clock.span(window)),
+            None => self.tr(NO_WINDOW).to_string(),
+        }];
+        if let Some(fish) = self.fish_data.fish_by_id(item.id) {
+            parts.push(self.fish_data.conditions_summary(fish, self.lang));
+        }
+        if let Some(intuition) = self
+            .fish_data
+            .fish_by_id(item.id)
+            .and_then(|f| self.intuition_text(f))
+        {
+            parts.push(intuition);
+        }
+        format!("{} — {}", item.name, parts.join(", "))
+    }
+
+    pub(crate) fn copy_selected(&mut self) {
+        let text = match self.get_selected_fish() {
+            Some(item) => self.share_text(item),
+            None => return,
+        };
+        if self.clipboard.is_none() {
+            self.clipboard = Clipboard::new().ok();
+        }
+        self.status = Some(match self.clipboard.as_mut().map(|c| c.set_text(text)) {
+            Some(Ok(())) => self.tr("Copied to clipboard").to_string(),
+            Some(Err(e)) => self.trf("Copy failed: {}", &[&e]),
+            None => self.tr("No clipboard available").to_string(),
+        });
+    }
+}
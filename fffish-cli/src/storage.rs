@@ -0,0 +1,179 @@
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+use color_eyre::{Result, eyre::WrapErr};
+use serde::{Deserialize, Serialize};
+
+use crate::{App, UserData};
+
+/// Backups kept unless the settings say otherwise.
+pub(crate) const DEFAULT_BACKUPS: usize = 5;
+
+/// A change to the caught list, written to the journal before the settings
+/// are saved. Replaying it is idempotent.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum JournalEntry {
+    Caught(u32),
+    Uncaught(u32),
+}
+
+impl JournalEntry {
+    fn apply(self, data: &mut UserData) {
+        match self {
+            JournalEntry::Caught(id) if !data.caught.contains(&id) => data.caught.push(id),
+            JournalEntry::Uncaught(id) => data.caught.retain(|c| *c != id),
+            JournalEntry::Caught(_) => {}
+        }
+    }
+}
+
+pub(crate) fn settings_path() -> Result<PathBuf> {
+    confy::get_configuration_file_path("fffish-cli", "fish")
+        .wrap_err("Finding the settings file failed")
+}
+
+fn journal_path(settings: &Path) -> PathBuf {
+    settings.with_extension("journal")
+}
+
+fn backup_path(settings: &Path, n: usize) -> PathBuf {
+    settings.with_extension(format!("toml.{}", n))
+}
+
+/// Writes `data` to a temporary file and renames it over `path`, so a crash
+/// leaves either the old or the new settings but never half of them.
+pub(crate) fn write_settings(path: &Path, data: &UserData) -> Result<()> {
+    let text = toml::to_string_pretty(data).wrap_err("Serializing the settings failed")?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).wrap_err_with(|| format!("Creating {} failed", dir.display()))?;
+    }
+    let tmp = path.with_extension("toml.tmp");
+    let mut file =
+        fs::File::create(&tmp).wrap_err_with(|| format!("Writing {} failed", tmp.display()))?;
+    file.write_all(text.as_bytes())
+        .and_then(|()| file.sync_all())
+        .wrap_err_with(|| format!("Writing {} failed", tmp.display()))?;
+    fs::rename(&tmp, path).wrap_err_with(|| format!("Replacing {} failed", path.display()))
+}
+
+/// Copies the settings to `fish.toml.1`, moving older backups up by one and
+/// dropping the ones past `keep`. Done once per start, so the backups span
+/// sessions rather than single clicks. Settings unchanged since the last
+/// backup are not copied again, so quick restarts keep the older backups.
+pub(crate) fn rotate_backups(path: &Path, keep: usize) -> Result<()> {
+    if keep == 0 || !path.exists() {
+        return Ok(());
+    }
+    let current = fs::read(path).wrap_err_with(|| format!("Reading {} failed", path.display()))?;
+    if fs::read(backup_path(path, 1)).is_ok_and(|backup| backup == current) {
+        return Ok(());
+    }
+    let _ = fs::remove_file(backup_path(path, keep));
+    for n in (1..keep).rev() {
+        let from = backup_path(path, n);
+        if from.exists() {
+            fs::rename(&from, backup_path(path, n + 1))
+                .wrap_err_with(|| format!("Rotating {} failed", from.display()))?;
+        }
+    }
+    fs::copy(path, backup_path(path, 1))
+        .wrap_err_with(|| format!("Backing up {} failed", path.display()))?;
+    Ok(())
+}
+
+/// Applies changes that were journaled but never saved, e.g. because the
+/// program crashed, and saves the result. Returns how many were applied.
+pub(crate) fn replay_journal(path: &Path, data: &mut UserData) -> Result<usize> {
+    let journal = journal_path(path);
+    let file = match fs::File::open(&journal) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e).wrap_err_with(|| format!("Reading {} failed", journal.display())),
+    };
+    let mut applied = 0;
+    for line in BufReader::new(file).lines() {
+        let line = line.wrap_err_with(|| format!("Reading {} failed", journal.display()))?;
+        // The last line may have been cut off by the crash.
+        if let Ok(entry) = serde_json::from_str::<JournalEntry>(&line) {
+            entry.apply(data);
+            applied += 1;
+        }
+    }
+    if applied > 0 {
+        write_settings(path, data)?;
+        tracing::info!(applied, "replayed settings journal");
+    }
+    fs::remove_file(&journal).wrap_err_with(|| format!("Removing {} failed", journal.display()))?;
+    Ok(applied)
+}
+
+impl App {
+    /// Applies `entry` to the caught list, journaling it first so it
+    /// survives a failed save.
+    pub(crate) fn change_caught(&mut self, entry: JournalEntry) {
+        if let Err(e) = self.journal(entry) {
+            self.status = Some(self.trf("Writing the journal failed: {}", &[&e]));
+        }
+        entry.apply(&mut self.user_data);
+    }
+
+    fn journal(&self, entry: JournalEntry) -> Result<()> {
+        let path = journal_path(&settings_path()?);
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        file.sync_data()?;
+        Ok(())
+    }
+
+    /// Saves the settings and clears the journal they now contain.
+    pub(crate) fn save_user_data(&self) -> Result<()> {
+        let path = settings_path()?;
+        write_settings(&path, &self.user_data)?;
+        match fs::remove_file(journal_path(&path)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn replay_truncated_journal() {
+        let dir = env::temp_dir().join(format!("fffish-journal-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("fish.toml");
+        let entries = [
+            JournalEntry::Caught(3),
+            JournalEntry::Caught(5),
+            JournalEntry::Uncaught(3),
+        ];
+        let mut journal: String = entries
+            .iter()
+            .map(|entry| serde_json::to_string(entry).unwrap() + "\n")
+            .collect();
+        journal.push_str("{\"caught\":");
+        fs::write(journal_path(&path), journal).unwrap();
+
+        let mut data = UserData::default();
+        assert_eq!(replay_journal(&path, &mut data).unwrap(), 3);
+        assert_eq!(data.caught, [5]);
+        assert!(!journal_path(&path).exists());
+        let saved: UserData = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.caught, [5]);
+        // Without a journal there is nothing to do.
+        assert_eq!(replay_journal(&path, &mut data).unwrap(), 0);
+    }
+}
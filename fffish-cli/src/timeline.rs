@@ -0,0 +1,177 @@
+use chrono::{DateTime, Local, TimeDelta, Timelike};
+use ffxivfishing::{
+    eorzea_time::{EorzeaDuration, EorzeaTime, EorzeaTimeSpan, et_hours_between},
+    fish::Fish,
+    lang::Lang,
+    search::WindowQuery,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::i18n;
+
+const TIMELINE_HOURS: i64 = 24;
+const LABEL_WIDTH: usize = 24;
+
+struct TimelineRow {
+    name: String,
+    windows: Vec<(DateTime<Local>, DateTime<Local>)>,
+}
+
+pub struct Timeline {
+    start: DateTime<Local>,
+    rows: Vec<TimelineRow>,
+    lang: Lang,
+}
+
+impl Timeline {
+    pub fn new(start: DateTime<Local>, lang: Lang) -> Timeline {
+        Timeline {
+            start,
+            rows: vec![],
+            lang,
+        }
+    }
+
+    fn end(&self) -> DateTime<Local> {
+        self.start + TimeDelta::hours(TIMELINE_HOURS)
+    }
+
+    pub fn add_fish(&mut self, fish: &Fish) {
+        let end = EorzeaTime::from_time(&self.end().into()).unwrap();
+        let windows = windows_between(
+            fish,
+            EorzeaTime::from_time(&self.start.into()).unwrap(),
+            end,
+        )
+        .iter()
+        .map(|w| {
+            (
+                w.start().to_system_time().into(),
+                w.end().to_system_time().into(),
+            )
+        })
+        .collect();
+        self.rows.push(TimelineRow {
+            name: fish.name().to_string(),
+            windows,
+        });
+        let timeline_end = self.end();
+        self.rows
+            .sort_by_key(|r| r.windows.first().map_or(timeline_end, |w| w.0));
+    }
+
+    fn header(&self, width: usize) -> Line<'_> {
+        let step = TimeDelta::hours(TIMELINE_HOURS) / width as i32;
+        let cols_per_hour = width as f64 / TIMELINE_HOURS as f64;
+        let label_every = (6.0 / cols_per_hour).ceil().max(1.0) as i64;
+
+        let mut header = vec![' '; width];
+        let first_hour = self.start.with_minute(0).unwrap().with_second(0).unwrap();
+        for h in 1..=TIMELINE_HOURS {
+            let tick = first_hour + TimeDelta::hours(h);
+            if tick.hour() as i64 % label_every != 0 {
+                continue;
+            }
+            let col = ((tick - self.start).num_seconds() / step.num_seconds().max(1)) as usize;
+            let label = tick.format("%H:%M").to_string();
+            if col + label.len() > width {
+                break;
+            }
+            for (i, c) in label.chars().enumerate() {
+                header[col + i] = c;
+            }
+        }
+        Line::from(format!(
+            "{:LABEL_WIDTH$}{}",
+            "",
+            header.into_iter().collect::<String>()
+        ))
+    }
+
+    /// Marks where each Eorzea day begins, as daily windows repeat then.
+    fn eorzea_days(&self, width: usize) -> Line<'_> {
+        let step = TimeDelta::hours(TIMELINE_HOURS) / width as i32;
+        let mut marks = vec![' '; width];
+        for (_, real) in et_hours_between(self.start.into(), self.end().into())
+            .into_iter()
+            .filter(|(time, _)| time.bell() == 0)
+        {
+            let col = (DateTime::<Local>::from(real) - self.start).num_seconds()
+                / step.num_seconds().max(1);
+            if let Some(mark) = marks.get_mut(col as usize) {
+                *mark = '|';
+            }
+        }
+        Line::styled(
+            format!(
+                "{:LABEL_WIDTH$}{}",
+                "0:00 ET",
+                marks.into_iter().collect::<String>()
+            ),
+            Style::from(Color::DarkGray),
+        )
+    }
+
+    fn row_line(&self, row: &TimelineRow, width: usize) -> Line<'_> {
+        let step = TimeDelta::hours(TIMELINE_HOURS) / width as i32;
+        let mut name: String = row.name.chars().take(LABEL_WIDTH - 1).collect();
+        name = format!("{:LABEL_WIDTH$}", name);
+        let mut spans = vec![Span::raw(name)];
+        for col in 0..width {
+            let col_start = self.start + step * col as i32;
+            let col_end = col_start + step;
+            let open = row
+                .windows
+                .iter()
+                .any(|(start, end)| *start < col_end && *end > col_start);
+            spans.push(match (open, col) {
+                (true, 0) => Span::styled("█", Style::from(Color::Blue)),
+                (true, _) => Span::styled("█", Style::from(Color::Green)),
+                (false, _) => Span::styled("·", Style::from(Color::DarkGray)),
+            });
+        }
+        Line::from(spans)
+    }
+}
+
+impl Widget for Timeline {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered().title(format!(
+            " {} ",
+            i18n::trf(
+                self.lang,
+                "Favourites: next {} hours (v for zones)",
+                &[&TIMELINE_HOURS],
+            )
+        ));
+        let width = (block.inner(area).width as usize).saturating_sub(LABEL_WIDTH);
+        if width == 0 {
+            block.render(area, buf);
+            return;
+        }
+        let mut lines = vec![self.header(width), self.eorzea_days(width)];
+        lines.extend(self.rows.iter().map(|r| self.row_line(r, width)));
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+}
+
+/// All windows of `fish` that overlap `[start, end)`, including one that is
+/// ongoing at `start`.
+pub fn windows_between(fish: &Fish, start: EorzeaTime, end: EorzeaTime) -> Vec<EorzeaTimeSpan> {
+    let mut windows = vec![];
+    let mut time = start;
+    while let Ok(window) = fish.next_window(&WindowQuery::new(time).include_ongoing(true)) {
+        if window.start() >= end {
+            break;
+        }
+        time = window.end() + EorzeaDuration::from_esecs(1);
+        windows.push(window);
+    }
+    windows
+}
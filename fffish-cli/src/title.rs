@@ -0,0 +1,58 @@
+//! Puts the next favourite window into the terminal title, so the countdown
+//! is visible while the terminal sits in a background tab.
+
+use std::io::stdout;
+
+use ffxivfishing::{display::RelativeStyle, search::WindowQuery};
+use ratatui::crossterm::{execute, terminal::SetTitle};
+
+use crate::App;
+
+#[derive(Default)]
+pub(crate) struct TerminalTitle {
+    /// Last title set, so it is only sent when it changes.
+    written: String,
+}
+
+impl App {
+    fn title_text(&self) -> String {
+        let clock = self.clock();
+        let now = clock.now();
+        let query = WindowQuery::new(now).include_ongoing(true);
+        let next = self
+            .user_data
+            .favorites
+            .fishes(&self.fish_data)
+            .filter(|f| !self.is_ignored(f.id))
+            .filter_map(|f| Some((f, f.next_window(&query).ok()?)))
+            .min_by_key(|(_, window)| window.start());
+        match next {
+            Some((fish, window)) => format!(
+                "fffish: {} {}",
+                fish.name(),
+                clock.relative(&window, RelativeStyle::Long)
+            ),
+            None => "fffish".to_string(),
+        }
+    }
+
+    pub(crate) fn update_title(&mut self) {
+        if self.title.is_none() {
+            return;
+        }
+        let text = self.title_text();
+        if let Some(title) = self.title.as_mut()
+            && title.written != text
+            && execute!(stdout(), SetTitle(&text)).is_ok()
+        {
+            title.written = text;
+        }
+    }
+
+    /// Leaves no stale countdown behind after quitting.
+    pub(crate) fn clear_title(&self) {
+        if self.title.is_some() {
+            let _ = execute!(stdout(), SetTitle(""));
+        }
+    }
+}
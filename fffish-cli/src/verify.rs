@@ -0,0 +1,58 @@
+//! `fffish-cli verify-weather`: compares computed forecasts with observed or
+//! third-party weather. The reference is a CSV file with one
+//! `zone,unix_time,weather` row per line, e.g. exported from Garland Tools or
+//! the `--weather-log` of weathers noted in game. Lines starting with `#` are
+//! ignored. Without a file, `weather_reference.csv` is used, see its header.
+
+use std::path::Path;
+
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, eyre},
+};
+use ffxivfishing::{fish::FishData, weather_log};
+
+/// Weathers of every zone over a few periods, bundled so the forecast can be
+/// checked without a file.
+const BUNDLED_REFERENCE: &str = include_str!("weather_reference.csv");
+
+/// Prints every mismatch and fails if there was any.
+pub(crate) fn verify_weather(fish_data: &FishData, reference: Option<&Path>) -> Result<()> {
+    let samples = match reference {
+        Some(path) => weather_log::read(path)
+            .wrap_err_with(|| format!("Reading {} failed", path.display()))?,
+        None => weather_log::parse(BUNDLED_REFERENCE)
+            .wrap_err("Reading the bundled reference failed")?,
+    };
+
+    let mut mismatches = 0;
+    for (line, sample) in &samples {
+        let computed = sample
+            .computed(fish_data)
+            .ok_or_else(|| eyre!("line {}: unknown zone {}", line, sample.zone))?;
+        if !computed.eq_ignore_ascii_case(&sample.weather) {
+            mismatches += 1;
+            println!(
+                "line {}: {} at {}: expected {}, computed {}",
+                line, sample.zone, sample.time, sample.weather, computed
+            );
+        }
+    }
+    println!("{} samples, {} mismatches", samples.len(), mismatches);
+    match mismatches {
+        0 => Ok(()),
+        n => Err(eyre!("{} forecasts differ from the reference", n)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ffxivfishing::carbuncledata::carbuncle_fishes;
+
+    use super::*;
+
+    #[test]
+    fn bundled_reference_matches() {
+        verify_weather(&carbuncle_fishes().unwrap(), None).unwrap();
+    }
+}
@@ -0,0 +1,253 @@
+use std::{borrow::Cow, rc::Rc};
+
+use chrono::{Local, TimeDelta};
+use ffxivfishing::{
+    eorzea_time::corrected_now,
+    fish::Region,
+    weather_log::{self, Observation},
+};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{Event, KeyCode, KeyEvent},
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::{Block, List, ListItem, Paragraph, StatefulWidget, Widget},
+};
+use tui_input::{Input, backend::crossterm::EventHandler};
+
+use crate::{App, AppMode, FishListItem};
+
+/// Weather periods shown after the next one.
+const FORECAST_PERIODS: usize = 4;
+
+impl App {
+    /// Regions with weather whose name contains the zone search, ignoring
+    /// case, grouped by area and sorted by name within each.
+    fn zones(&self) -> Vec<Rc<Region>> {
+        let search = self.zone_search.value().to_lowercase();
+        let mut zones: Vec<Rc<Region>> = self
+            .fish_data
+            .regions()
+            .iter()
+            .filter(|r| !r.weather().weathers().is_empty())
+            .filter(|r| r.name().to_lowercase().contains(&search))
+            .cloned()
+            .collect();
+        zones.sort_by(|a, b| (a.area_id(), a.name()).cmp(&(b.area_id(), b.name())));
+        zones
+    }
+
+    /// Selects the zone called `name`, ignoring case.
+    pub(crate) fn select_zone(&mut self, name: &str) -> bool {
+        let position = self
+            .zones()
+            .iter()
+            .position(|z| z.name().eq_ignore_ascii_case(name));
+        self.zone_state
+            .select(position.or(self.zone_state.selected()));
+        position.is_some()
+    }
+
+    fn zone_weather_text(&self, zone: &Region) -> String {
+        let period_start = self.clock().now().weather_period_start();
+        let next_period = period_start.next_weather_change();
+        let forecast = zone.weather();
+        let change_in: chrono::DateTime<Local> = next_period.to_system_time().into();
+        let minutes = (change_in - self.clock().local_now())
+            .max(TimeDelta::zero())
+            .num_minutes();
+        self.trf(
+            "Now: {} ({} min left)  →  Next: {}",
+            &[
+                &self
+                    .fish_data
+                    .weather_name(forecast.weather_at(period_start)),
+                &minutes,
+                &self
+                    .fish_data
+                    .weather_name(forecast.weather_at(next_period)),
+            ],
+        )
+    }
+
+    /// Local start and weather of the periods after the next one.
+    fn zone_forecast_text(&self, zone: &Region) -> String {
+        let clock = self.clock();
+        let mut period = clock.now().next_weather_change();
+        let mut periods = vec![];
+        for _ in 0..FORECAST_PERIODS {
+            period = period.next_weather_change();
+            periods.push(format!(
+                "{} {}",
+                clock.time(period),
+                self.fish_data
+                    .weather_name(zone.weather().weather_at(period))
+            ));
+        }
+        self.trf("Later: {}", &[&periods.join(", ")])
+    }
+
+    fn selected_zone(&self) -> Option<Rc<Region>> {
+        self.zone_state
+            .selected()
+            .and_then(|i| self.zones().get(i).cloned())
+    }
+
+    pub(crate) fn start_zone_search(&mut self) {
+        self.mode = AppMode::ZoneSearch;
+    }
+
+    /// Applies a key typed into the zone search, keeping the selected zone
+    /// if it still matches.
+    pub(crate) fn handle_zone_search_key(&mut self, key: KeyEvent) {
+        let selected = self.selected_zone();
+        match key.code {
+            KeyCode::Esc => {
+                self.zone_search = Input::default();
+                self.mode = AppMode::List;
+            }
+            KeyCode::Enter => self.mode = AppMode::List,
+            _ => {
+                self.zone_search.handle_event(&Event::Key(key));
+            }
+        }
+        let zones = self.zones();
+        let position = selected.and_then(|s| zones.iter().position(|z| z.id() == s.id()));
+        self.zone_state
+            .select(position.or((!zones.is_empty()).then_some(0)));
+        if position.is_none() {
+            self.zone_weather = 0;
+        }
+    }
+
+    pub(crate) fn next_zone_weather(&mut self) {
+        let count = self
+            .selected_zone()
+            .map_or(1, |z| z.weather().weathers().len().max(1));
+        self.zone_weather = (self.zone_weather + 1) % count;
+    }
+
+    pub(crate) fn add_zone_weather_alarm(&mut self) {
+        let zone = match self.selected_zone() {
+            Some(z) => z,
+            None => return,
+        };
+        if let Some(weather) = zone.weather().weathers().get(self.zone_weather) {
+            self.add_weather_alarm(zone.id(), **weather);
+        }
+    }
+
+    /// Logs the selected weather as seen in the zone now, for checking the
+    /// forecast later.
+    pub(crate) fn log_zone_weather(&mut self) {
+        let (Some(zone), Some(path)) = (self.selected_zone(), &self.weather_log) else {
+            self.status = Some(
+                self.tr("Start with --weather-log FILE to log weathers")
+                    .to_string(),
+            );
+            return;
+        };
+        if !self.time_offset.is_zero() {
+            self.status = Some(self.tr("Not while simulating another time").to_string());
+            return;
+        }
+        let Some(weather) = zone.weather().weathers().get(self.zone_weather).copied() else {
+            return;
+        };
+        let observation = Observation::new(
+            zone.name().to_string(),
+            corrected_now(),
+            self.fish_data.weather_name(weather),
+        );
+        self.status = Some(match weather_log::append(path, &observation) {
+            Ok(()) => match observation.matches_forecast(&self.fish_data) {
+                Some(false) => self.trf(
+                    "Logged {} in {}, the forecast says {}",
+                    &[
+                        &observation.weather,
+                        &zone.name(),
+                        &observation.computed(&self.fish_data).unwrap_or_default(),
+                    ],
+                ),
+                _ => self.trf("Logged {} in {}", &[&observation.weather, &zone.name()]),
+            },
+            Err(e) => self.trf("Logging the weather failed: {}", &[&e]),
+        });
+    }
+
+    pub(crate) fn render_zones(&mut self, area: Rect, buf: &mut Buffer) {
+        let [zone_area, detail_area] =
+            Layout::horizontal([Constraint::Length(32), Constraint::Fill(1)]).areas(area);
+        let zones = self.zones();
+
+        let search_title = match (&self.mode, self.zone_search.value()) {
+            (AppMode::ZoneSearch, search) => Line::from(format!(" /{}_ ", search)).blue(),
+            (_, "") => Line::from(format!(" {} ", self.tr("/: search"))),
+            (_, search) => Line::from(format!(" /{} ", search)),
+        };
+        let zone_items: Vec<ListItem> = zones.iter().map(|z| ListItem::new(z.name())).collect();
+        StatefulWidget::render(
+            List::new(zone_items)
+                .block(
+                    Block::bordered()
+                        .title(format!(" {} ", self.tr("Zones (v for list)")))
+                        .title_bottom(search_title),
+                )
+                .highlight_symbol("> "),
+            zone_area,
+            buf,
+            &mut self.zone_state,
+        );
+
+        let zone = match self.zone_state.selected().and_then(|i| zones.get(i)) {
+            Some(z) => z,
+            None => return,
+        };
+        let [weather_area, fish_area] =
+            Layout::vertical([Constraint::Length(5), Constraint::Fill(1)]).areas(detail_area);
+        let forecast = zone.weather();
+        let weathers = forecast.weathers();
+        let alarm_weather = match weathers.get(self.zone_weather) {
+            Some(w) => self.trf(
+                "Alarm weather: {} ({}%)  w: change, m: add alarm, o: log as seen",
+                &[
+                    &self.fish_data.weather_name(w),
+                    &format!(
+                        "{:.0}",
+                        forecast.probability(std::slice::from_ref(*w)) * 100.0
+                    ),
+                ],
+            ),
+            None => "".to_string(),
+        };
+        Paragraph::new(vec![
+            Line::from(self.zone_weather_text(zone)),
+            Line::from(self.zone_forecast_text(zone)),
+            Line::from(alarm_weather),
+        ])
+        .block(
+            Block::bordered()
+                .title(format!(" {} ", zone.name()))
+                .title_bottom(self.status.as_deref().map_or(Line::default(), |status| {
+                    Line::from(format!(" {} ", status)).right_aligned()
+                })),
+        )
+        .render(weather_area, buf);
+
+        let mut fish: Vec<Cow<FishListItem>> = self
+            .fish_data
+            .fishes()
+            .iter()
+            .filter(|f| f.location.region().id() == zone.id() && !self.is_ignored(f.id))
+            .map(|f| self.cached_item(f))
+            .collect();
+        fish.sort_by(|a, b| self.list_sort.compare(a, b));
+        let fish_items: Vec<ListItem> = fish.iter().map(|f| ListItem::from(&**f)).collect();
+        Widget::render(
+            List::new(fish_items).block(Block::bordered().title(" Fish ")),
+            fish_area,
+            buf,
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use ffxivfishing::prelude::*;
+
+/// A fixed start so runs are comparable.
+fn start() -> EorzeaTime {
+    EorzeaTime::from_esecs(35_000_000_000)
+}
+
+fn window_search(c: &mut Criterion) {
+    let data = carbuncle_fishes().unwrap();
+    // The rarest fish that still has a window, so the search has to walk far.
+    let rare = data
+        .fishes()
+        .iter()
+        .filter(|f| f.rarity().is_finite())
+        .max_by(|a, b| a.rarity().total_cmp(&b.rarity()))
+        .unwrap();
+    let region = rare.location.region();
+
+    c.bench_function("find_pattern no match, limit 10000", |b| {
+        b.iter(|| {
+            region.weather().find_pattern(
+                start(),
+                &[],
+                &[Weather::UNKNOWN],
+                SearchHorizon::Periods(10_000),
+            )
+        })
+    });
+    c.bench_function("next_window rarest fish, limit 10000", |b| {
+        b.iter(|| {
+            rare.next_window(&WindowQuery::new(start()).horizon(SearchHorizon::Periods(10_000)))
+        })
+    });
+    c.bench_function("next_window all fish, limit 10000", |b| {
+        b.iter(|| {
+            data.fishes()
+                .iter()
+                .filter_map(|f| {
+                    f.next_window(
+                        &WindowQuery::new(start()).horizon(SearchHorizon::Periods(10_000)),
+                    )
+                    .ok()
+                })
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, window_search);
+criterion_main!(benches);
@@ -0,0 +1,202 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    rc::Rc,
+};
+
+use crate::{
+    eorzea_time::{EORZEA_WEATHER_PERIOD, EorzeaDuration, EorzeaTime, EorzeaTimeSpan},
+    fish::{Fish, FishData, FishingHole},
+    search::{SearchHorizon, WindowQuery},
+    weather::{Weather, WeatherForecast},
+};
+
+/// How far `available_now` follows a window that keeps going.
+const MAX_LOOKAHEAD: EorzeaDuration = EorzeaDuration::from_suns(7);
+
+/// A fish whose window is open, from `FishData::available_now`.
+#[derive(Debug)]
+pub struct AvailableFish<'a> {
+    pub fish: &'a Fish,
+    pub location: &'a Rc<FishingHole>,
+    /// When the window closes, `None` for fish that are always available.
+    /// Windows are followed at most a week of Eorzea days ahead.
+    pub closes: Option<EorzeaTime>,
+    /// Time left until `closes`.
+    pub remaining: Option<EorzeaDuration>,
+}
+
+/// A window opening, from `FishData::upcoming_windows`.
+#[derive(Debug)]
+pub struct UpcomingWindow<'a> {
+    pub fish: &'a Fish,
+    pub window: EorzeaTimeSpan,
+}
+
+fn weather_matches(set: &[Weather], weather: &Weather) -> bool {
+    set.is_empty() || set.contains(weather)
+}
+
+/// End of the window that is open at `time`, joining windows that continue
+/// into the next weather period.
+fn window_end(fish: &Fish, time: EorzeaTime) -> EorzeaTime {
+    let limit = time + MAX_LOOKAHEAD;
+    let mut end = time;
+    while end < limit {
+        let query = WindowQuery::new(end)
+            .include_ongoing(true)
+            .horizon(SearchHorizon::Periods(1));
+        match fish.next_window(&query) {
+            Ok(window) if window.start() <= end && window.end() > end => end = window.end(),
+            _ => break,
+        }
+    }
+    end.min(limit)
+}
+
+impl FishData {
+    /// Every fish whose window is open at `time`, closing soonest first.
+    /// The weather of each forecast is looked up once and shared by all
+    /// fish that use it.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    pub fn available_now(&self, time: EorzeaTime) -> Vec<AvailableFish<'_>> {
+        let previous_period = time - EORZEA_WEATHER_PERIOD;
+        let mut weathers: HashMap<*const WeatherForecast, (Weather, Weather)> = HashMap::new();
+        let mut available: Vec<AvailableFish> = self
+            .fishes()
+            .iter()
+            .filter(|fish| {
+                let forecast = fish.location.region().shared_weather();
+                let (previous, current) =
+                    *weathers.entry(Rc::as_ptr(forecast)).or_insert_with(|| {
+                        (
+                            *forecast.weather_at(previous_period),
+                            *forecast.weather_at(time),
+                        )
+                    });
+                weather_matches(&fish.previous_weather_set, &previous)
+                    && weather_matches(&fish.weather_set, &current)
+                    && fish.in_hours(time)
+            })
+            .map(|fish| {
+                let closes = (!fish.always_available()).then(|| window_end(fish, time));
+                AvailableFish {
+                    fish,
+                    location: &fish.location,
+                    closes,
+                    remaining: closes.and_then(|c| c.duration_since(time).ok()),
+                }
+            })
+            .collect();
+        available.sort_by_key(|a| (a.closes.is_none(), a.closes));
+        available
+    }
+
+    /// The `n` soonest windows opening at or after `start`, sorted by start.
+    /// A fish can appear more than once.
+    pub fn upcoming_windows(&self, start: EorzeaTime, n: usize) -> Vec<UpcomingWindow<'_>> {
+        self.upcoming_windows_where(start, n, |_| true)
+    }
+
+    /// Like `upcoming_windows`, but only for the fish `filter` returns true
+    /// for.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, filter))
+    )]
+    pub fn upcoming_windows_where(
+        &self,
+        start: EorzeaTime,
+        n: usize,
+        filter: impl Fn(&Fish) -> bool,
+    ) -> Vec<UpcomingWindow<'_>> {
+        let next = |fish: &Fish, time| fish.next_window(&WindowQuery::new(time)).ok();
+        let fishes: Vec<&Fish> = self.fishes().iter().filter(|f| filter(f)).collect();
+        // Only each fish's next window is queued; taking one queues the one
+        // after it.
+        let mut pending: Vec<Option<EorzeaTimeSpan>> =
+            fishes.iter().map(|fish| next(fish, start)).collect();
+        let mut queue: BinaryHeap<Reverse<(EorzeaTime, usize)>> = pending
+            .iter()
+            .enumerate()
+            .filter_map(|(i, w)| Some(Reverse((w.as_ref()?.start(), i))))
+            .collect();
+        let mut windows = vec![];
+        while windows.len() < n {
+            let Some(Reverse((_, i))) = queue.pop() else {
+                break;
+            };
+            let Some(window) = pending[i].take() else {
+                break;
+            };
+            pending[i] = next(fishes[i], window.end());
+            if let Some(after) = &pending[i] {
+                queue.push(Reverse((after.start(), i)));
+            }
+            windows.push(UpcomingWindow {
+                fish: fishes[i],
+                window,
+            });
+        }
+        windows
+    }
+}
+
+#[cfg(all(test, feature = "carbuncle-data"))]
+mod tests {
+    use super::*;
+    use crate::carbuncledata::carbuncle_fishes;
+
+    #[test]
+    fn available_now() {
+        let data = carbuncle_fishes().unwrap();
+        let time = EorzeaTime::new(1000, 3, 12, 9, 30, 0).unwrap();
+        let available = data.available_now(time);
+        assert!(!available.is_empty());
+        for a in &available {
+            let query = WindowQuery::new(time).include_ongoing(true);
+            let window = a.fish.next_window(&query).unwrap();
+            assert!(window.start() <= time, "{} is not open", a.fish.name());
+            if let Some(closes) = a.closes {
+                assert!(closes >= window.end());
+            }
+        }
+        // Every open fish is found.
+        let open = data
+            .fishes()
+            .iter()
+            .filter(|f| {
+                let query = WindowQuery::new(time)
+                    .include_ongoing(true)
+                    .horizon(SearchHorizon::Periods(1));
+                f.next_window(&query).is_ok_and(|w| w.start() <= time)
+            })
+            .count();
+        assert_eq!(available.len(), open);
+    }
+
+    #[test]
+    fn upcoming_windows() {
+        let data = carbuncle_fishes().unwrap();
+        let start = EorzeaTime::new(1000, 3, 12, 9, 30, 0).unwrap();
+        let windows = data.upcoming_windows(start, 50);
+        assert_eq!(windows.len(), 50);
+        assert!(windows[0].window.start() >= start);
+        assert!(
+            windows
+                .windows(2)
+                .all(|w| w[0].window.start() <= w[1].window.start())
+        );
+        // The soonest opening of any fish comes first.
+        let soonest = data
+            .fishes()
+            .iter()
+            .filter_map(|f| f.next_window(&WindowQuery::new(start)).ok())
+            .map(|w| w.start())
+            .min();
+        assert_eq!(Some(windows[0].window.start()), soonest);
+
+        let big_fish = data.upcoming_windows_where(start, 10, |f| f.big_fish);
+        assert!(big_fish.iter().all(|w| w.fish.big_fish));
+    }
+}
@@ -0,0 +1,180 @@
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+    eorzea_time::{EorzeaTime, EorzeaTimeSpan},
+    fish::FishData,
+};
+
+/// Rough time a cast takes besides waiting for the bite: casting, hooking
+/// and reeling in.
+pub const CAST_OVERHEAD: Duration = Duration::from_secs(8);
+
+/// How long after the cast a fish bites, in real time.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BiteTime {
+    min: Duration,
+    max: Duration,
+}
+
+impl BiteTime {
+    pub fn new(min: Duration, max: Duration) -> BiteTime {
+        BiteTime {
+            min: min.min(max),
+            max: max.max(min),
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.min
+    }
+
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Time for one attempt: the average bite time plus `CAST_OVERHEAD`.
+    pub fn cast_duration(&self) -> Duration {
+        (self.min + self.max) / 2 + CAST_OVERHEAD
+    }
+
+    /// About how many casts fit into `time`.
+    pub fn casts_in(&self, time: Duration) -> u32 {
+        (time.as_secs_f64() / self.cast_duration().as_secs_f64()) as u32
+    }
+}
+
+/// Bite times by fish id. They are not part of the game data and come from
+/// community measurements. Serializes as a map of ids to `[min, max]` in
+/// seconds.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "HashMap<u32, [f64; 2]>", into = "HashMap<u32, [f64; 2]>")
+)]
+pub struct BiteTimes {
+    fish: HashMap<u32, BiteTime>,
+}
+
+impl BiteTimes {
+    pub fn new() -> BiteTimes {
+        BiteTimes::default()
+    }
+
+    pub fn insert(&mut self, fish_id: u32, bite_time: BiteTime) {
+        self.fish.insert(fish_id, bite_time);
+    }
+
+    pub fn get(&self, fish_id: u32) -> Option<&BiteTime> {
+        self.fish.get(&fish_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.fish.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fish.is_empty()
+    }
+
+    /// Adds the bite times of `other`, which win over ones already known.
+    pub fn merge(&mut self, other: BiteTimes) {
+        self.fish.extend(other.fish);
+    }
+}
+
+impl From<HashMap<u32, [f64; 2]>> for BiteTimes {
+    /// Skips negative and non-finite seconds.
+    fn from(seconds: HashMap<u32, [f64; 2]>) -> BiteTimes {
+        let fish = seconds
+            .into_iter()
+            .filter_map(|(id, [min, max])| {
+                let min = Duration::try_from_secs_f64(min).ok()?;
+                let max = Duration::try_from_secs_f64(max).ok()?;
+                Some((id, BiteTime::new(min, max)))
+            })
+            .collect();
+        BiteTimes { fish }
+    }
+}
+
+impl From<BiteTimes> for HashMap<u32, [f64; 2]> {
+    fn from(times: BiteTimes) -> HashMap<u32, [f64; 2]> {
+        times
+            .fish
+            .into_iter()
+            .map(|(id, t)| (id, [t.min.as_secs_f64(), t.max.as_secs_f64()]))
+            .collect()
+    }
+}
+
+impl FishData {
+    pub fn bite_time(&self, fish_id: u32) -> Option<&BiteTime> {
+        self.bite_times().get(fish_id)
+    }
+
+    /// About how many casts at fish `fish_id` fit into what is left of
+    /// `window` at `now`. `None` if its bite time is unknown.
+    pub fn estimated_casts(
+        &self,
+        fish_id: u32,
+        window: &EorzeaTimeSpan,
+        now: EorzeaTime,
+    ) -> Option<u32> {
+        let bite_time = self.bite_time(fish_id)?;
+        let left = window
+            .end()
+            .duration_since(window.start().max(now))
+            .map_or(Duration::ZERO, |d| d.to_real());
+        Some(bite_time.casts_in(left))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eorzea_time::EorzeaDuration, fixtures::*};
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn casts() {
+        let bite_time = BiteTime::new(secs(20), secs(10));
+        assert_eq!(bite_time.min(), secs(10));
+        assert_eq!(bite_time.cast_duration(), secs(23));
+        assert_eq!(bite_time.casts_in(secs(70)), 3);
+        assert_eq!(bite_time.casts_in(secs(10)), 0);
+    }
+
+    #[test]
+    fn estimated_casts() {
+        let mut times = BiteTimes::new();
+        times.insert(DAWN_PERCH, BiteTime::new(secs(10), secs(20)));
+        let data = fixture_data().with_bite_times(times);
+        // Dawn Perch's window is 4 bells, 700 real seconds.
+        let window = EorzeaTimeSpan::new(
+            epoch() + EorzeaDuration::from_bells(4),
+            EorzeaDuration::from_bells(4),
+        );
+        assert_eq!(data.estimated_casts(DAWN_PERCH, &window, epoch()), Some(30));
+        let halfway = window.start() + EorzeaDuration::from_bells(2);
+        assert_eq!(data.estimated_casts(DAWN_PERCH, &window, halfway), Some(15));
+        assert_eq!(
+            data.estimated_casts(DAWN_PERCH, &window, window.end()),
+            Some(0)
+        );
+        assert_eq!(data.estimated_casts(NIGHT_EEL, &window, epoch()), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let times: BiteTimes = serde_json::from_str(r#"{"5": [12.5, 18], "6": [-1, 3]}"#).unwrap();
+        assert_eq!(times.len(), 1);
+        assert_eq!(times.get(5).unwrap().min(), Duration::from_millis(12500));
+        let json = serde_json::to_string(&times).unwrap();
+        assert_eq!(json, r#"{"5":[12.5,18.0]}"#);
+    }
+}
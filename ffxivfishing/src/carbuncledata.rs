@@ -1,15 +1,60 @@
-use std::{collections::HashMap, error::Error, rc::Rc, time::Duration};
+use std::{collections::HashMap, error::Error, fmt::Display, rc::Rc, time::Duration};
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::{
     eorzea_time::EorzeaDuration,
-    fish::{Bait, Fish, FishData, FishingHole, FishingItem, Intuition, Lure, Region},
+    fish::{
+        Area, Bait, Fish, FishData, FishingHole, FishingItem, FolkloreBook, Hookset, Intuition,
+        Item, Region, Tug, UnknownValue,
+    },
+    lang::LocalizedName,
     weather::{Weather, WeatherForecast},
 };
 
+#[cfg(feature = "carbuncle-data")]
 const DATA: &str = include_str!("data.json");
 
+/// Built-in data, for `carbuncle_fishes_with_report`.
+#[cfg(feature = "carbuncle-data")]
+pub fn builtin_data() -> &'static str {
+    DATA
+}
+
+/// A field value the loader did not understand.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DataIssue {
+    pub fish: u32,
+    pub field: &'static str,
+    pub value: Value,
+}
+
+impl Display for DataIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "fish {}: unknown {} {}",
+            self.fish, self.field, self.value
+        )
+    }
+}
+
+/// What went wrong while loading, without failing the load.
+#[derive(Debug, Default)]
+pub struct DataReport {
+    pub issues: Vec<DataIssue>,
+    /// Ids of fish left out, e.g. spearfishing fish without a fishing hole.
+    /// Expected in the bundled data, so not counted by `is_empty`.
+    pub skipped: Vec<u32>,
+}
+
+impl DataReport {
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 enum OneOrVec<T> {
@@ -27,6 +72,28 @@ struct CarbuncleData {
     fishing_spots: HashMap<String, CarbuncleFishingSpot>,
     #[serde(rename = "ITEMS")]
     items: HashMap<String, CarbuncleItem>,
+    #[serde(rename = "REGIONS", default)]
+    regions: HashMap<String, CarbuncleName>,
+    #[serde(rename = "ZONES")]
+    zones: HashMap<String, CarbuncleName>,
+    #[serde(rename = "WEATHER_TYPES")]
+    weather_types: HashMap<String, CarbuncleName>,
+    #[serde(rename = "FOLKLORE", default)]
+    folklore: HashMap<String, CarbuncleName>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CarbuncleName {
+    #[serde(rename = "name_en")]
+    name: String,
+    #[serde(rename = "name_ja", default)]
+    name_ja: String,
+    #[serde(rename = "name_de", default)]
+    name_de: String,
+    #[serde(rename = "name_fr", default)]
+    name_fr: String,
+    #[serde(rename = "name_ko", default)]
+    name_ko: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -49,28 +116,30 @@ struct CarbuncleFish {
     intuition_length: Option<u32>,
     #[serde(rename = "predators")]
     predators: Vec<[u32; 2]>,
-    #[serde(rename = "tug")]
-    tug: Option<String>,
-    #[serde(rename = "hookset")]
-    hookset: Option<String>,
-    #[serde(rename = "lure")]
-    lure: Option<String>,
+    #[serde(rename = "tug", default)]
+    tug: Value,
+    #[serde(rename = "hookset", default)]
+    hookset: Value,
+    #[serde(rename = "lure", default)]
+    lure: Value,
     #[serde(rename = "fishEyes")]
     fish_eyes: bool,
     #[serde(rename = "bigFish")]
-    bg_fish: bool,
+    big_fish: bool,
     #[serde(rename = "snagging")]
     snagging: Option<bool>,
     #[serde(rename = "patch")]
     patch: f32,
+    #[serde(rename = "folklore")]
+    folklore: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct CarbuncleFishingSpot {
     #[serde(rename = "_id")]
     id: u32,
-    #[serde(rename = "name_en")]
-    name: String,
+    #[serde(flatten)]
+    names: CarbuncleName,
     #[serde(rename = "map_coords")]
     map_coords: [f32; 3],
     #[serde(rename = "territory_id")]
@@ -83,18 +152,36 @@ struct CarbuncleFishingSpot {
 struct CarbuncleItem {
     #[serde(rename = "_id")]
     id: u32,
-    #[serde(rename = "name_en")]
-    name: String,
+    #[serde(flatten)]
+    names: CarbuncleName,
     #[serde(rename = "icon")]
     icon: String,
     #[serde(rename = "ilvl")]
     ilvl: u32,
 }
+impl CarbuncleName {
+    fn localized(&self) -> LocalizedName {
+        LocalizedName {
+            en: self.name.clone(),
+            ja: self.name_ja.clone(),
+            de: self.name_de.clone(),
+            fr: self.name_fr.clone(),
+            ko: self.name_ko.clone(),
+        }
+    }
+}
+
 impl CarbuncleItem {
     fn to_fishing_item(&self, fishes: &[Fish]) -> FishingItem {
-        match fishes.iter().find(|f| f.id == self.id) {
-            Some(f) => FishingItem::Fish(self.name.clone(), f.id),
-            None => FishingItem::Bait(self.name.clone(), self.id),
+        let item = Item::new(
+            self.id,
+            self.names.name.clone(),
+            self.icon.parse().unwrap_or_default(),
+            self.ilvl,
+        );
+        match fishes.iter().any(|f| f.id == self.id) {
+            true => FishingItem::Fish(item),
+            false => FishingItem::Bait(item),
         }
     }
 }
@@ -119,7 +206,7 @@ impl From<&CarbuncleWeatherRates> for WeatherForecast {
             cwr.map_id.to_string(),
             cwr.weather_rates
                 .iter()
-                .map(|(weather_id, rate)| (*rate, Weather::Id(*weather_id)))
+                .map(|(weather_id, rate)| (*rate, Weather::from_id(*weather_id)))
                 .collect(),
         )
     }
@@ -127,10 +214,9 @@ impl From<&CarbuncleWeatherRates> for WeatherForecast {
 
 impl CarbuncleFishingSpot {
     fn to_fishinghole(&self, regions: &[Rc<Region>]) -> Option<FishingHole> {
-        let region = regions
-            .iter()
-            .find(|r| r.name() == self.territory_id.to_string())?;
-        Some(FishingHole::new(self.id.to_string(), region.clone()))
+        let region = regions.iter().find(|r| r.id() == self.territory_id)?;
+        let hole = FishingHole::new(self.id, self.names.name.clone(), region.clone());
+        Some(hole.with_localized_name(self.names.localized()))
     }
 }
 
@@ -144,62 +230,80 @@ impl CarbuncleFish {
         })
     }
 
-    fn to_fish(&self, fishing_holes: &[Rc<FishingHole>], items: &[&CarbuncleItem]) -> Option<Fish> {
+    /// Parses one of the coded fields, noting a value no parser understood.
+    fn parse<T: for<'v> TryFrom<&'v Value, Error = UnknownValue>>(
+        &self,
+        field: &'static str,
+        value: &Value,
+        report: &mut DataReport,
+    ) -> Option<T> {
+        T::try_from(value)
+            .map_err(|e| {
+                report.issues.push(DataIssue {
+                    fish: self.id,
+                    field,
+                    value: e.0,
+                })
+            })
+            .ok()
+    }
+
+    fn to_fish(
+        &self,
+        fishing_holes: &[Rc<FishingHole>],
+        items: &[&CarbuncleItem],
+        report: &mut DataReport,
+    ) -> Option<Fish> {
         let fish_hole = fishing_holes
             .iter()
-            .find(|fh| fh.name() == self.location.unwrap_or(0).to_string())?;
+            .find(|fh| Some(fh.id()) == self.location)?;
         let item = items.iter().find(|i| self.id == i.id)?;
 
-        let bait = match self.best_catch_path.last() {
-            Some(OneOrVec::One(o)) => Bait::Bait(*o),
-            Some(OneOrVec::Vec(o)) if o.is_empty() => Bait::Unknown,
-            Some(OneOrVec::Vec(o)) => Bait::Bait(*o.last().unwrap()),
+        let bait_id = match self.best_catch_path.last() {
+            Some(OneOrVec::One(o)) => Some(*o),
+            Some(OneOrVec::Vec(o)) => o.last().copied(),
+            None => None,
+        };
+        // Every step after the first catches the fish used as the next bait.
+        let bait = match bait_id {
+            Some(id) if self.best_catch_path.len() > 1 => Bait::Mooch(id),
+            Some(id) => Bait::Bait(id),
             None => Bait::Unknown,
         };
         Some(Fish::new(
             self.id,
-            item.name.clone(),
+            item.names.name.clone(),
             Rc::clone(fish_hole),
             EorzeaDuration::from_esecs((self.start_hour * 3600.0) as u64),
             EorzeaDuration::from_esecs((self.end_hour * 3600.0) as u64),
             bait,
             self.previous_weather_set
                 .iter()
-                .map(|id| Weather::Id(*id))
+                .map(|id| Weather::from_id(*id))
+                .collect(),
+            self.weather_set
+                .iter()
+                .map(|id| Weather::from_id(*id))
                 .collect(),
-            self.weather_set.iter().map(|id| Weather::Id(*id)).collect(),
-            self.tug.clone().unwrap_or("".to_string()).as_str().into(),
-            self.hookset
-                .clone()
-                .unwrap_or("".to_string())
-                .as_str()
-                .into(),
+            self.parse("tug", &self.tug, report).unwrap_or(Tug::Unknown),
+            self.parse("hookset", &self.hookset, report)
+                .unwrap_or(Hookset::Unknown),
             self.try_get_intuition(),
-            Lure::Moderate,
-            self.lure.is_some(),
+            match self.lure {
+                Value::Null => None,
+                _ => self.parse("lure", &self.lure, report),
+            },
             self.snagging.unwrap_or(false),
             false,
-            false,
+            self.folklore.is_some(),
             self.fish_eyes,
-            (self.patch.trunc() as u8, self.patch.fract() as u8),
+            parse_patch(self.patch),
+            self.big_fish,
         ))
     }
 }
 
-fn parse_fishes() -> Result<Vec<CarbuncleFish>, serde_json::Error> {
-    let data: serde_json::Value = serde_json::from_str(DATA)?;
-
-    let fishes = match data["FISH"].as_object() {
-        Some(f) => f.clone(),
-        None => return Ok(vec![]),
-    };
-
-    Ok(fishes
-        .values()
-        .filter_map(|f| serde_json::from_value::<CarbuncleFish>(f.clone()).ok())
-        .collect())
-}
-
+#[cfg(all(test, feature = "carbuncle-data"))]
 fn parse_fishing_spots() -> Result<Vec<CarbuncleFishingSpot>, serde_json::Error> {
     let data: serde_json::Value = serde_json::from_str(DATA)?;
 
@@ -214,6 +318,7 @@ fn parse_fishing_spots() -> Result<Vec<CarbuncleFishingSpot>, serde_json::Error>
         .collect())
 }
 
+#[cfg(all(test, feature = "carbuncle-data"))]
 fn parse_weather() -> Result<Vec<CarbuncleWeatherRates>, serde_json::Error> {
     let data: serde_json::Value = serde_json::from_str(DATA)?;
 
@@ -228,24 +333,45 @@ fn parse_weather() -> Result<Vec<CarbuncleWeatherRates>, serde_json::Error> {
         .collect())
 }
 
-fn parse_data() -> Result<CarbuncleData, serde_json::Error> {
-    serde_json::from_str(DATA)
+/// Splits a patch number like `5.55` into `(5, 55)`.
+fn parse_patch(patch: f32) -> (u8, u8) {
+    let text = patch.to_string();
+    let (major, minor) = text.split_once('.').unwrap_or((&text, "0"));
+    (major.parse().unwrap_or(0), minor.parse().unwrap_or(0))
 }
 
-impl CarbuncleData {
-    fn convert_to_fishdata(&self) -> FishData {
-        let weather_rates: HashMap<String, WeatherForecast> = self
-            .weather_rates
-            .clone()
-            .into_iter()
-            .map(|(id, w)| (id, (&w).into()))
-            .collect();
+fn parse_data(data: &str) -> Result<CarbuncleData, serde_json::Error> {
+    serde_json::from_str(data)
+}
 
+impl CarbuncleData {
+    fn convert_to_fishdata(&self) -> (FishData, DataReport) {
+        let mut report = DataReport::default();
         let items: Vec<&CarbuncleItem> = self.items.values().collect();
 
-        let regions: Vec<Rc<Region>> = weather_rates
+        // Territories with the same rates share one forecast.
+        let mut forecasts: HashMap<&Vec<(u32, u8)>, Rc<WeatherForecast>> = HashMap::new();
+        let regions: Vec<Rc<Region>> = self
+            .weather_rates
+            .iter()
+            .filter_map(|(id, w)| {
+                let forecast = forecasts
+                    .entry(&w.weather_rates)
+                    .or_insert_with(|| Rc::new(w.into()));
+                let region = match self.zones.get(&w.zone_id.to_string()) {
+                    Some(zone) => {
+                        Region::new(id.parse().ok()?, zone.name.clone(), Rc::clone(forecast))
+                            .with_localized_name(zone.localized())
+                    }
+                    None => Region::new(id.parse().ok()?, id.clone(), Rc::clone(forecast)),
+                };
+                Some(Rc::new(region.with_area(w.region_id)))
+            })
+            .collect();
+        let areas = self
+            .regions
             .iter()
-            .map(|(id, w)| Rc::new(Region::new(id.to_string(), w.clone())))
+            .filter_map(|(id, r)| Some(Area::new(id.parse().ok()?, r.localized())))
             .collect();
 
         let fishing_holes: Vec<Rc<FishingHole>> = self
@@ -255,30 +381,109 @@ impl CarbuncleData {
             .map(Rc::new)
             .collect();
 
-        let fishes: Vec<Fish> = self
-            .fishes
-            .values()
-            .filter_map(|f| f.to_fish(&fishing_holes, &items))
-            .collect();
+        let mut fishes: Vec<Fish> = vec![];
+        for f in self.fishes.values() {
+            match f.to_fish(&fishing_holes, &items, &mut report) {
+                Some(fish) => fishes.push(fish),
+                None => report.skipped.push(f.id),
+            }
+        }
         let fishing_items = items
             .iter()
             .map(|item| item.to_fishing_item(&fishes))
             .collect();
-        FishData::new(fishes, fishing_holes, regions, fishing_items)
+        let weather_names = self
+            .weather_types
+            .iter()
+            .filter_map(|(id, w)| Some((id.parse().ok()?, w.name.clone())))
+            .collect();
+        let folklore_books = self.folklore_books(&fishes);
+        let localized_items = items.iter().map(|i| (i.id, i.names.localized())).collect();
+        let localized_weathers = self
+            .weather_types
+            .iter()
+            .filter_map(|(id, w)| Some((id.parse().ok()?, w.localized())))
+            .collect();
+        let data = FishData::new(
+            fishes,
+            fishing_holes,
+            regions,
+            fishing_items,
+            weather_names,
+            folklore_books,
+        )
+        .with_localized_names(localized_items, localized_weathers)
+        .with_areas(areas);
+        report.issues.sort_by_key(|i| (i.fish, i.field));
+        report.skipped.sort();
+        (data, report)
+    }
+
+    /// Books that unlock at least one of `fishes`, sorted by id.
+    fn folklore_books(&self, fishes: &[Fish]) -> Vec<FolkloreBook> {
+        let mut books: Vec<FolkloreBook> = self
+            .folklore
+            .iter()
+            .filter_map(|(id, book)| {
+                let id: u32 = id.parse().ok()?;
+                let fish: Vec<u32> = self
+                    .fishes
+                    .values()
+                    .filter(|f| f.folklore == Some(id))
+                    .map(|f| f.id)
+                    .filter(|f| fishes.iter().any(|fish| fish.id == *f))
+                    .collect();
+                if fish.is_empty() {
+                    return None;
+                }
+                // Tome names end in the area they cover, e.g. "... - Othard".
+                let zone = book.name.rsplit_once(" - ").map_or("", |(_, z)| z);
+                Some(FolkloreBook {
+                    id,
+                    name: book.name.clone(),
+                    zone: zone.to_string(),
+                    fish,
+                })
+            })
+            .collect();
+        books.sort_by_key(|b| b.id);
+        books
     }
 }
 
+#[cfg(feature = "carbuncle-data")]
 pub fn carbuncle_fishes() -> Result<FishData, Box<dyn Error>> {
-    let data = parse_data()?;
-    Ok(data.convert_to_fishdata())
+    carbuncle_fishes_from_str(DATA)
 }
 
-#[cfg(test)]
-mod tests {
+/// Parses fish data in the Carbuncle Plushy `data.json` format.
+pub fn carbuncle_fishes_from_str(data: &str) -> Result<FishData, Box<dyn Error>> {
+    carbuncle_fishes_with_report(data).map(|(data, _)| data)
+}
+
+/// Like `carbuncle_fishes_from_str`, but also returns the values the
+/// loader did not understand and replaced with a default.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(bytes = data.len())))]
+pub fn carbuncle_fishes_with_report(data: &str) -> Result<(FishData, DataReport), Box<dyn Error>> {
+    let (data, report) = parse_data(data)?.convert_to_fishdata();
+    #[cfg(feature = "tracing")]
+    {
+        for issue in &report.issues {
+            tracing::warn!(%issue, "data value not understood");
+        }
+        tracing::info!(
+            fishes = data.fishes().len(),
+            regions = data.regions().len(),
+            "loaded fish data"
+        );
+    }
+    Ok((data, report))
+}
 
-    use std::time::SystemTime;
+#[cfg(all(test, feature = "carbuncle-data"))]
+mod tests {
 
-    use crate::eorzea_time::EorzeaTime;
+    use crate::{eorzea_time::EorzeaTime, fish::Lure, lang::Lang, search::WindowQuery};
 
     use super::*;
     #[test]
@@ -296,22 +501,17 @@ mod tests {
         assert!(!weathers.is_empty());
         for w in weathers {
             let eorzea_weather: WeatherForecast = (&w).into();
-            let _ = eorzea_weather.weather_at(EorzeaTime::from_time(&SystemTime::now()).unwrap());
+            let _ = eorzea_weather.weather_at(EorzeaTime::now());
         }
     }
 
     #[test]
     fn parse_data_test() {
-        let data = parse_data().unwrap();
-        let fishes = data.convert_to_fishdata();
+        let data = parse_data(DATA).unwrap();
+        let (fishes, _) = data.convert_to_fishdata();
         for fish in fishes.fishes() {
-            let window = fish.next_window(
-                EorzeaTime::from_time(&SystemTime::now()).unwrap(),
-                false,
-                1_000,
-            );
-            if window.is_some() {
-                let w = window.unwrap();
+            let window = fish.next_window(&WindowQuery::new(EorzeaTime::now()));
+            if let Ok(w) = window {
                 println!(
                     "{:?}: {} - {:?}",
                     fish.name(),
@@ -323,4 +523,217 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn carbuncle_fishes_from_invalid_str() {
+        assert!(carbuncle_fishes_from_str("").is_err());
+        assert!(carbuncle_fishes_from_str("{\"FISH\": 1}").is_err());
+    }
+
+    #[test]
+    fn parse_patch_test() {
+        assert_eq!(parse_patch(2.0), (2, 0));
+        assert_eq!(parse_patch(6.1), (6, 1));
+        assert_eq!(parse_patch(5.55), (5, 55));
+        assert!(carbuncle_fishes().unwrap().latest_patch() > Some((7, 0)));
+    }
+
+    #[test]
+    fn shared_forecasts() {
+        let data = carbuncle_fishes().unwrap();
+        // Limsa Lominsa's upper and lower decks have the same rates.
+        let upper = data.region_by_id(128).unwrap();
+        let lower = data.region_by_id(129).unwrap();
+        assert!(Rc::ptr_eq(upper.shared_weather(), lower.shared_weather()));
+        let forecasts: Vec<&Rc<WeatherForecast>> =
+            data.regions().iter().map(|r| r.shared_weather()).collect();
+        let distinct = forecasts
+            .iter()
+            .enumerate()
+            .filter(|(i, f)| !forecasts[..*i].iter().any(|g| Rc::ptr_eq(f, g)))
+            .count();
+        assert!(distinct < data.regions().len());
+    }
+
+    #[test]
+    fn location_hierarchy() {
+        let data = carbuncle_fishes().unwrap();
+        let swiftperch = data.hole_by_id(59).unwrap();
+        assert_eq!(
+            data.location_path(swiftperch, Lang::English),
+            "La Noscea > Western La Noscea > Swiftperch"
+        );
+        let region = swiftperch.region();
+        let area = data.area_of(region).unwrap();
+        assert_eq!(area.localized_name(Lang::Japanese), "ラノシア");
+        assert!(
+            data.regions_in_area(area.id())
+                .iter()
+                .any(|r| r.id() == region.id())
+        );
+        assert!(data.regions().iter().all(|r| data.area_of(r).is_some()));
+    }
+
+    #[test]
+    fn weather_table_matches_data() {
+        let data = parse_data(DATA).unwrap();
+        let (fishes, _) = data.convert_to_fishdata();
+        for id in data.weather_types.keys() {
+            let weather = Weather::from_id(id.parse().unwrap());
+            assert!(
+                Weather::all().any(|w| w == weather),
+                "weather {} missing",
+                id
+            );
+            assert_eq!(Some(fishes.weather_name(&weather).as_str()), weather.name());
+        }
+    }
+
+    /// The weathers the data uses, in its types and in the zones' rates. The
+    /// weather table is only complete for these; a new one here needs its
+    /// entry checked against the game's Weather sheet.
+    #[test]
+    fn weathers_in_data() {
+        let data = parse_data(DATA).unwrap();
+        let mut ids: Vec<u32> = data
+            .weather_types
+            .keys()
+            .map(|id| id.parse().unwrap())
+            .chain(
+                data.weather_rates
+                    .values()
+                    .flat_map(|r| r.weather_rates.iter().map(|(id, _)| *id)),
+            )
+            .collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(
+            ids,
+            [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 14, 15, 16, 17, 49, 50, 148, 149
+            ]
+        );
+        for id in ids {
+            let weather = Weather::from_id(id);
+            assert!(
+                weather.name().is_some() && weather.icon().is_some(),
+                "{}",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn hole_lookup() {
+        let data = carbuncle_fishes().unwrap();
+        let fish = &data.fishes()[0];
+        let hole = data.hole_by_id(fish.location.id()).unwrap();
+        assert!(
+            data.fishes_at_hole(hole.id())
+                .iter()
+                .any(|f| f.id == fish.id)
+        );
+        let region = data.region_by_id(hole.region().id()).unwrap();
+        assert!(
+            data.holes_in_region(region.id())
+                .iter()
+                .any(|h| h.id() == hole.id())
+        );
+        assert!(data.hole_by_id(u32::MAX).is_none());
+    }
+
+    #[test]
+    fn folklore_books() {
+        let data = carbuncle_fishes().unwrap();
+        let books = data.folklore_books();
+        let othard = books.iter().find(|b| b.id == 2505).unwrap();
+        assert_eq!(othard.zone, "Othard");
+        assert!(!othard.fish.is_empty());
+        for id in &othard.fish {
+            assert!(data.fish_by_id(*id).unwrap().folklore);
+        }
+    }
+
+    #[test]
+    fn requirements() {
+        let data = carbuncle_fishes().unwrap();
+        // Basilosaurus Rex is mooched from Basilosaurus, itself mooched from Puff-paya.
+        let fish = data.fish_by_id(39881).unwrap();
+        let requirements = data.requirements(fish);
+        let names: Vec<&str> = requirements
+            .bait_chain
+            .iter()
+            .filter_map(|id| data.item_by_id(*id).map(|i| i.name()))
+            .collect();
+        assert_eq!(names, ["Mackerel Strip", "Puff-paya", "Basilosaurus"]);
+        assert_eq!(fish.requirements().bait_chain.len(), 1);
+    }
+
+    #[test]
+    fn lures() {
+        assert_eq!(Lure::from_name("Modest"), Some(Lure::Modest));
+        assert_eq!(Lure::from_name("Ambitious"), Some(Lure::Ambitious));
+        assert_eq!(Lure::from_name(""), None);
+        let data = carbuncle_fishes().unwrap();
+        assert!(
+            data.fishes()
+                .iter()
+                .any(|f| f.lure == Some(Lure::Ambitious))
+        );
+        assert!(data.fishes().iter().any(|f| f.lure == Some(Lure::Modest)));
+    }
+
+    #[test]
+    fn predators() {
+        let data = carbuncle_fishes().unwrap();
+        let fish = data.fish_by_id(8752).unwrap();
+        let predators = data.predators(fish);
+        assert_eq!(predators.len(), 1);
+        assert_eq!((predators[0].0, predators[0].1.id), (3, 5031));
+        assert!(fish.intuition_length().unwrap().as_secs() > 0);
+    }
+
+    #[test]
+    fn coded_values() {
+        use serde_json::json;
+        assert_eq!(Tug::try_from(&json!("heavy")), Ok(Tug::Heavy));
+        assert_eq!(Tug::try_from(&json!(2)), Ok(Tug::Medium));
+        assert_eq!(Tug::try_from(&json!("!")), Ok(Tug::Light));
+        assert_eq!(Tug::try_from(&Value::Null), Ok(Tug::Unknown));
+        assert_eq!(Tug::try_from(&json!(7)), Err(UnknownValue(json!(7))));
+        assert_eq!(
+            Hookset::try_from(&json!("Precision Hookset")),
+            Ok(Hookset::Precision)
+        );
+        assert_eq!(
+            Hookset::try_from(&json!("パワフルフッキング")),
+            Ok(Hookset::Powerful)
+        );
+        assert_eq!(Hookset::try_from(&json!(2)), Ok(Hookset::Precision));
+        assert_eq!(Lure::try_from(&json!("Ambitious")), Ok(Lure::Ambitious));
+        assert_eq!(Lure::try_from(&json!(2)), Ok(Lure::Modest));
+    }
+
+    #[test]
+    fn data_report() {
+        let (_, report) = carbuncle_fishes_with_report(builtin_data()).unwrap();
+        assert!(report.is_empty(), "{:?}", report);
+        assert!(report.skipped.contains(&4869));
+
+        let mut data: Value = serde_json::from_str(DATA).unwrap();
+        data["FISH"]["8752"]["tug"] = "wobbly".into();
+        data["FISH"]["8752"]["hookset"] = 1.into();
+        let (fishes, report) = carbuncle_fishes_with_report(&data.to_string()).unwrap();
+        assert_eq!(
+            report.issues,
+            vec![DataIssue {
+                fish: 8752,
+                field: "tug",
+                value: "wobbly".into()
+            }]
+        );
+        let fish = fishes.fish_by_id(8752).unwrap();
+        assert_eq!(fish.tug, Tug::Unknown);
+        assert_eq!(fish.hookset, Hookset::Powerful);
+    }
 }
@@ -0,0 +1,139 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::fish::{Expansion, Fish, FishData};
+
+/// Caught and total fish of one group.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Progress {
+    pub caught: usize,
+    pub total: usize,
+}
+
+impl Progress {
+    fn add(&mut self, caught: bool) {
+        self.caught += caught as usize;
+        self.total += 1;
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.total - self.caught
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.caught == self.total
+    }
+
+    /// Share caught, from 0 to 1. An empty group counts as complete.
+    pub fn ratio(&self) -> f64 {
+        match self.total {
+            0 => 1.0,
+            total => self.caught as f64 / total as f64,
+        }
+    }
+}
+
+/// Fishing log completion for a set of caught fish ids, grouped the ways
+/// the game and the front-ends show it. Groups without fish are left out.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Completion {
+    pub all: Progress,
+    pub big_fish: Progress,
+    pub by_patch: BTreeMap<(u8, u8), Progress>,
+    pub by_expansion: BTreeMap<Expansion, Progress>,
+    /// Big fish of each expansion.
+    pub big_fish_by_expansion: BTreeMap<Expansion, Progress>,
+    /// Keyed by region id.
+    pub by_zone: BTreeMap<u32, Progress>,
+    /// Keyed by folklore book id. Counts the book's fish that are in the
+    /// data.
+    pub by_folklore_book: BTreeMap<u32, Progress>,
+}
+
+impl Completion {
+    pub fn new(data: &FishData, caught: &HashSet<u32>) -> Completion {
+        let mut completion = Completion::default();
+        for fish in data.fishes() {
+            completion.add(fish, caught.contains(&fish.id));
+        }
+        for book in data.folklore_books() {
+            let progress = completion.by_folklore_book.entry(book.id).or_default();
+            for id in book
+                .fish
+                .iter()
+                .filter(|id| data.fish_by_id(**id).is_some())
+            {
+                progress.add(caught.contains(id));
+            }
+        }
+        completion.by_folklore_book.retain(|_, p| p.total > 0);
+        completion
+    }
+
+    fn add(&mut self, fish: &Fish, caught: bool) {
+        let expansion = fish.expansion();
+        self.all.add(caught);
+        self.by_patch.entry(fish.patch).or_default().add(caught);
+        self.by_expansion.entry(expansion).or_default().add(caught);
+        self.by_zone
+            .entry(fish.location.region().id())
+            .or_default()
+            .add(caught);
+        if fish.big_fish {
+            self.big_fish.add(caught);
+            self.big_fish_by_expansion
+                .entry(expansion)
+                .or_default()
+                .add(caught);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn completion() {
+        let data = fixture_data();
+        let caught = HashSet::from([ANYTIME_SPRAT, RAIN_CARP, CLEARING_PIKE]);
+        let completion = Completion::new(&data, &caught);
+        assert_eq!(
+            completion.all,
+            Progress {
+                caught: 3,
+                total: 5
+            }
+        );
+        assert_eq!(
+            completion.big_fish,
+            Progress {
+                caught: 1,
+                total: 1
+            }
+        );
+        assert!(completion.big_fish.is_complete());
+        assert_eq!(completion.by_patch[&(6, 0)].remaining(), 2);
+        let zone = |hole| data.hole_by_id(hole).unwrap().region().id();
+        assert_eq!(
+            completion.by_zone[&zone(MISTY_BANK)],
+            Progress {
+                caught: 2,
+                total: 2
+            }
+        );
+        assert_eq!(
+            completion.by_zone[&zone(QUIET_PIER)],
+            Progress {
+                caught: 1,
+                total: 3
+            }
+        );
+        assert_eq!(completion.big_fish_by_expansion.len(), 1);
+        assert!(completion.by_folklore_book.is_empty());
+
+        let none = Completion::new(&data, &HashSet::new());
+        assert_eq!(none.all.ratio(), 0.0);
+        assert_eq!(Progress::default().ratio(), 1.0);
+    }
+}
@@ -0,0 +1,104 @@
+//! Windows of any weather and time of day condition, for content other than
+//! fish such as hunt marks that spawn in certain weather.
+
+use std::rc::Rc;
+
+use crate::{
+    eorzea_time::{EORZEA_SUN, EorzeaDuration, EorzeaTimeSpan, SUN_IN_ESEC},
+    fish::Region,
+    search::{self, NoWindow, WindowQuery},
+    weather::Weather,
+};
+
+/// A weather and time of day condition in a region, solved like a fish's.
+#[derive(Debug, Clone)]
+pub struct ConditionWindow {
+    /// Eorzea time of day from and to, wrapping past midnight if the end is
+    /// not after the start. `None` for any time.
+    pub et_range: Option<(EorzeaDuration, EorzeaDuration)>,
+    /// Weathers one of which must come before `weather`. Empty for any.
+    pub previous_weather: Vec<Weather>,
+    /// Empty for any weather.
+    pub weather: Vec<Weather>,
+    pub region: Rc<Region>,
+}
+
+impl ConditionWindow {
+    /// The next window matching `query`. Fish Eyes does not apply, and a
+    /// weather override replaces `previous_weather` and `weather`.
+    pub fn next_window(&self, query: &WindowQuery) -> Result<EorzeaTimeSpan, NoWindow> {
+        let (previous_weather, weather) = match &query.weather_override {
+            Some((previous, current)) => (previous.as_slice(), current.as_slice()),
+            None => (self.previous_weather.as_slice(), self.weather.as_slice()),
+        };
+        // Equal start and end are the whole day.
+        let (start, end) = self.et_range.unwrap_or((EORZEA_SUN, EORZEA_SUN));
+        let (start, end) = (start % EORZEA_SUN, end % EORZEA_SUN);
+        search::next_window_in(
+            self.region.weather(),
+            previous_weather,
+            weather,
+            |day| search::hours_on_day(day, start, end),
+            query,
+        )
+    }
+
+    /// Fraction of the time the condition holds, as `Fish::uptime`.
+    pub fn uptime(&self) -> f64 {
+        let length = match self.et_range {
+            Some((start, end)) => {
+                let (start, end) = (start % EORZEA_SUN, end % EORZEA_SUN);
+                match (end.total_seconds() + SUN_IN_ESEC - start.total_seconds()) % SUN_IN_ESEC {
+                    0 => SUN_IN_ESEC,
+                    l => l,
+                }
+            }
+            None => SUN_IN_ESEC,
+        };
+        let weather = self.region.weather();
+        length as f64 / SUN_IN_ESEC as f64
+            * weather.probability(&self.weather)
+            * weather.probability(&self.previous_weather)
+    }
+
+    /// 1 for a condition that always holds, 10 for one that holds a tenth of
+    /// the time.
+    pub fn rarity(&self) -> f64 {
+        1.0 / self.uptime()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn condition_windows() {
+        let data = fixture_data();
+        let marsh = Rc::clone(data.fish_by_id(RAIN_CARP).unwrap().location.region());
+        let mut condition = ConditionWindow {
+            et_range: None,
+            previous_weather: vec![Weather::RAIN],
+            weather: vec![Weather::CLOUDS],
+            region: marsh,
+        };
+        let bells = |window: EorzeaTimeSpan| {
+            let (start, end) = (window.start(), window.end());
+            (start.sun(), start.bell(), end.sun(), end.bell())
+        };
+        let query = WindowQuery::new(epoch());
+        // The whole weather period at any time of day.
+        assert_eq!(bells(condition.next_window(&query).unwrap()), (1, 16, 2, 0));
+        // The same as Clearing Pike's.
+        condition.et_range = Some((
+            EorzeaDuration::new(18, 0, 0).unwrap(),
+            EorzeaDuration::new(22, 0, 0).unwrap(),
+        ));
+        let pike = data.fish_by_id(CLEARING_PIKE).unwrap();
+        assert_eq!(condition.next_window(&query), pike.next_window(&query));
+        assert_eq!(condition.rarity(), pike.rarity());
+        condition.weather = vec![Weather::FAIR_SKIES];
+        assert!(condition.next_window(&query).is_err());
+    }
+}
@@ -0,0 +1,404 @@
+use std::{fmt::Display, time::Duration};
+
+use chrono::{DateTime, Local, Utc};
+use chrono_tz::Tz;
+
+use crate::{
+    eorzea_time::{EorzeaTime, EorzeaTimeSpan},
+    lang::Lang,
+};
+
+/// The clock times are shown in.
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeDisplay {
+    #[default]
+    Local,
+    /// UTC, which the game servers run on.
+    Server,
+    Eorzea,
+}
+
+impl Display for TimeDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TimeDisplay::Local => "LT",
+            TimeDisplay::Server => "ST",
+            TimeDisplay::Eorzea => "ET",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl TimeDisplay {
+    pub fn next(&self) -> TimeDisplay {
+        match self {
+            TimeDisplay::Local => TimeDisplay::Server,
+            TimeDisplay::Server => TimeDisplay::Eorzea,
+            TimeDisplay::Eorzea => TimeDisplay::Local,
+        }
+    }
+}
+
+/// How `WindowDisplay::window` shows a window.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum WindowFormat {
+    /// Start and end, e.g. "14:30 LT - 14:55 LT".
+    Span,
+    /// Time until it opens or closes, e.g. "in 1h 05min".
+    Countdown,
+    /// The countdown and the start, e.g. "in 1h 05min (14:30 LT)". Windows
+    /// more than a day away show their date instead.
+    Combined,
+}
+
+/// The fixed words of window texts. `{}` is replaced by a time or duration,
+/// in order.
+struct Words {
+    in_time: &'static str,
+    opens_in: &'static str,
+    open_until: &'static str,
+    left: &'static str,
+    open_left: &'static str,
+    ended: &'static str,
+    ended_ago: &'static str,
+    date_format: &'static str,
+    under_minute: &'static str,
+    minutes: &'static str,
+    hours: &'static str,
+    days: &'static str,
+}
+
+fn words(lang: Lang) -> Words {
+    match lang {
+        Lang::English => Words {
+            in_time: "in {}",
+            opens_in: "opens in {}",
+            open_until: "open until {}",
+            left: "{} left",
+            open_left: "open, {} left",
+            ended: "ended",
+            ended_ago: "ended {} ago",
+            date_format: "%Y-%m-%d",
+            under_minute: "<1 min",
+            minutes: "{} min",
+            hours: "{}h {}min",
+            days: "{}d {}h",
+        },
+        Lang::Japanese => Words {
+            in_time: "{}後",
+            opens_in: "{}後に開始",
+            open_until: "{}まで",
+            left: "残り{}",
+            open_left: "開放中 残り{}",
+            ended: "終了",
+            ended_ago: "{}前に終了",
+            date_format: "%Y-%m-%d",
+            under_minute: "1分未満",
+            minutes: "{}分",
+            hours: "{}時間{}分",
+            days: "{}日{}時間",
+        },
+        Lang::German => Words {
+            in_time: "in {}",
+            opens_in: "öffnet in {}",
+            open_until: "offen bis {}",
+            left: "noch {}",
+            open_left: "offen, noch {}",
+            ended: "vorbei",
+            ended_ago: "seit {} vorbei",
+            date_format: "%d.%m.%Y",
+            under_minute: "<1 Min.",
+            minutes: "{} Min.",
+            hours: "{} Std. {} Min.",
+            days: "{} T. {} Std.",
+        },
+        Lang::French => Words {
+            in_time: "dans {}",
+            opens_in: "ouvre dans {}",
+            open_until: "ouvert jusqu'à {}",
+            left: "encore {}",
+            open_left: "ouvert, encore {}",
+            ended: "terminé",
+            ended_ago: "terminé il y a {}",
+            date_format: "%d/%m/%Y",
+            under_minute: "<1 min",
+            minutes: "{} min",
+            hours: "{} h {} min",
+            days: "{} j {} h",
+        },
+        Lang::Korean => Words {
+            in_time: "{} 후",
+            opens_in: "{} 후 시작",
+            open_until: "{}까지",
+            left: "{} 남음",
+            open_left: "열림, {} 남음",
+            ended: "종료",
+            ended_ago: "{} 전 종료",
+            date_format: "%Y-%m-%d",
+            under_minute: "1분 미만",
+            minutes: "{}분",
+            hours: "{}시간 {}분",
+            days: "{}일 {}시간",
+        },
+    }
+}
+
+/// A duration in minutes, e.g. "25 min", "2h 05min" or "1d 03h", and
+/// "<1 min" for less than a minute.
+pub fn duration_text(duration: Duration, lang: Lang) -> String {
+    let words = words(lang);
+    let fill = |template: &str, first: u64, second: Option<u64>| {
+        let text = template.replacen("{}", &first.to_string(), 1);
+        match second {
+            Some(second) => text.replacen("{}", &format!("{:0>2}", second), 1),
+            None => text,
+        }
+    };
+    match duration.as_secs() / 60 {
+        0 => words.under_minute.to_string(),
+        m if m < 60 => fill(words.minutes, m, None),
+        m if m < 24 * 60 => fill(words.hours, m / 60, Some(m % 60)),
+        m => fill(words.days, m / (24 * 60), Some(m / 60 % 24)),
+    }
+}
+
+/// Wording of `format_relative`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum RelativeStyle {
+    /// "in 5 min", "25 min left", "ended".
+    Short,
+    /// "opens in 5 min", "open, 25 min left", "ended 3 min ago".
+    Long,
+}
+
+/// Where `now` is relative to `span`, in real time. Shared by everything
+/// that words a countdown, including `WindowFormat::Countdown`, so they
+/// never disagree.
+pub fn format_relative(
+    span: &EorzeaTimeSpan,
+    now: EorzeaTime,
+    style: RelativeStyle,
+    lang: Lang,
+) -> String {
+    let words = words(lang);
+    let real = |later: EorzeaTime, earlier| {
+        later
+            .duration_since(earlier)
+            .map_or(Duration::ZERO, |d| d.to_real())
+    };
+    let (template, duration) = match (now < span.start(), now < span.end(), style) {
+        (true, _, RelativeStyle::Short) => (words.in_time, real(span.start(), now)),
+        (true, _, RelativeStyle::Long) => (words.opens_in, real(span.start(), now)),
+        (_, true, RelativeStyle::Short) => (words.left, real(span.end(), now)),
+        (_, true, RelativeStyle::Long) => (words.open_left, real(span.end(), now)),
+        (_, _, RelativeStyle::Short) => return words.ended.to_string(),
+        (_, _, RelativeStyle::Long) => (words.ended_ago, real(now, span.end())),
+    };
+    template.replace("{}", &duration_text(duration, lang))
+}
+
+/// Formats times and windows in one clock, so every front-end shows them
+/// the same way. Local time can use a timezone instead of the system one.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WindowDisplay {
+    display: TimeDisplay,
+    timezone: Option<Tz>,
+    lang: Lang,
+}
+
+impl WindowDisplay {
+    pub fn new(display: TimeDisplay) -> WindowDisplay {
+        WindowDisplay {
+            display,
+            ..Default::default()
+        }
+    }
+
+    /// Shows local time in `timezone`, or in the system's if `None`.
+    pub fn timezone(mut self, timezone: Option<Tz>) -> WindowDisplay {
+        self.timezone = timezone;
+        self
+    }
+
+    pub fn lang(mut self, lang: Lang) -> WindowDisplay {
+        self.lang = lang;
+        self
+    }
+
+    pub fn display(&self) -> TimeDisplay {
+        self.display
+    }
+
+    fn format(&self, time: EorzeaTime, format: &str) -> String {
+        let utc = DateTime::<Utc>::from(time.to_system_time());
+        match (self.display, self.timezone) {
+            (TimeDisplay::Eorzea, _) => unreachable!("Eorzea time is not a real clock"),
+            (TimeDisplay::Local, Some(tz)) => utc
+                .with_timezone(&tz)
+                .format(&format!("{} %Z", format))
+                .to_string(),
+            (TimeDisplay::Local, None) => {
+                format!("{} LT", utc.with_timezone(&Local).format(format))
+            }
+            (TimeDisplay::Server, _) => format!("{} ST", utc.format(format)),
+        }
+    }
+
+    /// Time of day, e.g. "14:32 LT".
+    pub fn time(&self, time: EorzeaTime) -> String {
+        match self.display {
+            TimeDisplay::Eorzea => format!("{:0>2}:{:0>2} ET", time.bell(), time.minute()),
+            _ => self.format(time, "%H:%M"),
+        }
+    }
+
+    /// Date and time in the language's date order.
+    pub fn date_time(&self, time: EorzeaTime) -> String {
+        match self.display {
+            TimeDisplay::Eorzea => format!("{} ET", time),
+            _ => self.format(time, &format!("{} %H:%M:%S", words(self.lang).date_format)),
+        }
+    }
+
+    pub fn span(&self, span: &EorzeaTimeSpan) -> String {
+        format!("{} - {}", self.time(span.start()), self.time(span.end()))
+    }
+
+    /// Name of the clock, e.g. "LT" or the timezone's.
+    pub fn label(&self) -> String {
+        match (self.display, self.timezone) {
+            (TimeDisplay::Local, Some(tz)) => tz.name().to_string(),
+            (display, _) => display.to_string(),
+        }
+    }
+
+    /// Where `now` is relative to `span`, see `format_relative`.
+    pub fn relative(&self, span: &EorzeaTimeSpan, now: EorzeaTime, style: RelativeStyle) -> String {
+        format_relative(span, now, style, self.lang)
+    }
+
+    /// `window` as seen at `now`, in `format`.
+    pub fn window<'a>(
+        &'a self,
+        window: &'a EorzeaTimeSpan,
+        now: EorzeaTime,
+        format: WindowFormat,
+    ) -> FormattedWindow<'a> {
+        FormattedWindow {
+            display: self,
+            window,
+            now,
+            format,
+        }
+    }
+}
+
+/// A window formatted by `WindowDisplay::window`.
+pub struct FormattedWindow<'a> {
+    display: &'a WindowDisplay,
+    window: &'a EorzeaTimeSpan,
+    now: EorzeaTime,
+    format: WindowFormat,
+}
+
+impl Display for FormattedWindow<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let display = self.display;
+        let words = words(display.lang);
+        let fill = |template: &str, value: String| template.replace("{}", &value);
+        let (start, end) = (self.window.start(), self.window.end());
+        let until_start = start.duration_since(self.now).map(|d| d.to_real());
+        let relative =
+            || format_relative(self.window, self.now, RelativeStyle::Short, display.lang);
+        let text = match (self.format, until_start) {
+            (WindowFormat::Span, _) => display.span(self.window),
+            (WindowFormat::Countdown, _) => relative(),
+            (WindowFormat::Combined, Err(_)) => fill(words.open_until, display.time(end)),
+            (WindowFormat::Combined, Ok(d)) if d.as_secs() < 24 * 60 * 60 => {
+                format!("{} ({})", relative(), display.time(start))
+            }
+            (WindowFormat::Combined, Ok(_)) => display.date_time(start),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eorzea_time::EorzeaDuration;
+
+    #[test]
+    fn window_formats() {
+        let start = EorzeaTime::new(1000, 1, 1, 12, 0, 0).unwrap();
+        let window = EorzeaTimeSpan::new(start, EorzeaDuration::new(2, 0, 0).unwrap());
+        let display = WindowDisplay::new(TimeDisplay::Eorzea);
+        let show = |now, format| display.window(&window, now, format).to_string();
+        // Six bells of Eorzea time are 17:30 real minutes.
+        let before = start - EorzeaDuration::new(6, 0, 0).unwrap();
+        assert_eq!(show(before, WindowFormat::Countdown), "in 17 min");
+        assert_eq!(show(before, WindowFormat::Combined), "in 17 min (12:00 ET)");
+        assert_eq!(show(before, WindowFormat::Span), "12:00 ET - 14:00 ET");
+        let open = start + EorzeaDuration::new(1, 0, 0).unwrap();
+        assert_eq!(show(open, WindowFormat::Combined), "open until 14:00 ET");
+        assert_eq!(show(open, WindowFormat::Countdown), "2 min left");
+        let german = display.lang(Lang::German);
+        assert_eq!(
+            german
+                .window(&window, open, WindowFormat::Combined)
+                .to_string(),
+            "offen bis 14:00 ET"
+        );
+    }
+
+    #[test]
+    fn durations() {
+        let english = |secs| duration_text(Duration::from_secs(secs), Lang::English);
+        assert_eq!(english(25 * 60), "25 min");
+        assert_eq!(english(125 * 60), "2h 05min");
+        assert_eq!(english(59), "<1 min");
+        assert_eq!(english(27 * 60 * 60), "1d 03h");
+        let japanese = |secs| duration_text(Duration::from_secs(secs), Lang::Japanese);
+        assert_eq!(japanese(25 * 60), "25分");
+        assert_eq!(japanese(125 * 60), "2時間05分");
+        assert_eq!(japanese(59), "1分未満");
+    }
+
+    #[test]
+    fn relative() {
+        let start = EorzeaTime::new(1000, 1, 1, 12, 0, 0).unwrap();
+        let window = EorzeaTimeSpan::new(start, EorzeaDuration::new(12, 0, 0).unwrap());
+        let show = |now, style| format_relative(&window, now, style, Lang::English);
+        let bells = |n| EorzeaDuration::new(n, 0, 0).unwrap();
+        let (short, long) = (RelativeStyle::Short, RelativeStyle::Long);
+        // Six bells of Eorzea time are 17:30 real minutes.
+        assert_eq!(show(start - bells(6), short), "in 17 min");
+        assert_eq!(show(start - bells(6), long), "opens in 17 min");
+        // Ten Eorzea minutes are 29 real seconds.
+        let soon = start - EorzeaDuration::new(0, 10, 0).unwrap();
+        assert_eq!(show(soon, short), "in <1 min");
+        let days = start - EorzeaDuration::from_suns(30);
+        assert_eq!(show(days, long), "opens in 1d 11h");
+        assert_eq!(show(start, short), "35 min left");
+        assert_eq!(show(start + bells(11), long), "open, 2 min left");
+        let last = window.end() - EorzeaDuration::new(0, 10, 0).unwrap();
+        assert_eq!(show(last, short), "<1 min left");
+        assert_eq!(show(window.end(), short), "ended");
+        assert_eq!(show(window.end() + bells(2), long), "ended 5 min ago");
+        assert_eq!(
+            format_relative(&window, start, long, Lang::German),
+            "offen, noch 35 Min."
+        );
+        // The list's countdown is the same text.
+        let display = WindowDisplay::new(TimeDisplay::Eorzea);
+        for now in [start - bells(6), start, window.end()] {
+            assert_eq!(
+                display
+                    .window(&window, now, WindowFormat::Countdown)
+                    .to_string(),
+                show(now, short)
+            );
+        }
+    }
+}
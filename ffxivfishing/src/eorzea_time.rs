@@ -2,13 +2,15 @@ use std::{
     cmp::{max, min},
     fmt,
     ops::Rem,
+    sync::atomic::{AtomicI64, Ordering},
     time::{Duration, SystemTime, SystemTimeError, UNIX_EPOCH},
 };
 
-pub const EORZEA_WEATHER_PERIOD: EorzeaDuration = EorzeaDuration {
-    esec: BELL_IN_ESEC * 8,
-};
-pub const EORZEA_SUN: EorzeaDuration = EorzeaDuration { esec: SUN_IN_ESEC };
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
+
+pub const EORZEA_WEATHER_PERIOD: EorzeaDuration = EorzeaDuration::from_bells(8);
+pub const EORZEA_SUN: EorzeaDuration = EorzeaDuration::from_suns(1);
 
 const EORZEA_TIME_CONST: f64 = 3600.0 / 175.0;
 
@@ -24,12 +26,59 @@ pub const EORZEA_ZERO_TIMESPAN: EorzeaTimeSpan = EorzeaTimeSpan {
     duration: EorzeaDuration { esec: 0 },
 };
 
-#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Eq, Ord)]
+/// Correction for the system clock in milliseconds, see `set_clock_offset`.
+static CLOCK_OFFSET_MS: AtomicI64 = AtomicI64::new(0);
+
+/// Corrects every reading of the current time in this process by `offset`,
+/// e.g. 90 seconds if the system clock runs 90 seconds behind.
+#[cfg(feature = "chrono")]
+pub fn set_clock_offset(offset: TimeDelta) {
+    CLOCK_OFFSET_MS.store(offset.num_milliseconds(), Ordering::Relaxed);
+}
+
+#[cfg(feature = "chrono")]
+pub fn clock_offset() -> TimeDelta {
+    TimeDelta::milliseconds(CLOCK_OFFSET_MS.load(Ordering::Relaxed))
+}
+
+/// The current time with the clock offset applied.
+pub fn corrected_now() -> SystemTime {
+    with_offset(SystemTime::now(), CLOCK_OFFSET_MS.load(Ordering::Relaxed))
+}
+
+fn with_offset(time: SystemTime, offset_ms: i64) -> SystemTime {
+    let magnitude = Duration::from_millis(offset_ms.unsigned_abs());
+    match offset_ms < 0 {
+        true => time - magnitude,
+        false => time + magnitude,
+    }
+}
+
+/// Every Eorzea bell that begins in `[start, end)`, with the real time it
+/// begins at. A bell lasts 175 real seconds.
+pub fn et_hours_between(start: SystemTime, end: SystemTime) -> Vec<(EorzeaTime, SystemTime)> {
+    let bell = EorzeaDuration::from_bells(1).to_real().as_secs();
+    let since_epoch = start.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let first = since_epoch
+        .as_nanos()
+        .div_ceil(bell as u128 * 1_000_000_000) as u64;
+    (first..)
+        .map(|n| {
+            (
+                EorzeaTime::from_esecs(n * BELL_IN_ESEC),
+                UNIX_EPOCH + Duration::from_secs(n * bell),
+            )
+        })
+        .take_while(|(_, real)| *real < end)
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Eq, Ord, Hash)]
 pub struct EorzeaTime {
     timestamp: u64,
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Eq, Ord, Hash)]
 pub struct EorzeaDuration {
     esec: u64,
 }
@@ -89,10 +138,13 @@ impl EorzeaTime {
         }
     }
 
+    /// The current Eorzea time, corrected by the clock offset.
     pub fn now() -> EorzeaTime {
-        EorzeaTime::from_time(&SystemTime::now()).unwrap()
+        EorzeaTime::from_time(&corrected_now()).unwrap()
     }
 
+    /// Converts an exact instant; unlike `now` it ignores the clock offset,
+    /// so instants meant as "now" should come from `corrected_now`.
     pub fn from_time(time: &SystemTime) -> Result<EorzeaTime, SystemTimeError> {
         let eorzea_time = (time.duration_since(UNIX_EPOCH)?.as_secs() as f64) * EORZEA_TIME_CONST;
         Ok(EorzeaTime {
@@ -113,7 +165,35 @@ impl EorzeaTime {
         self.timestamp -= self.timestamp % d.esec;
     }
 
-    fn duration_since(&self, other: EorzeaTime) -> Result<EorzeaDuration, EorzeaDurationError> {
+    /// Number of the weather period this time falls into, counted from the
+    /// Eorzea epoch.
+    pub fn weather_period_index(&self) -> u64 {
+        self.timestamp / EORZEA_WEATHER_PERIOD.esec
+    }
+
+    /// Start of the weather period this time falls into.
+    pub fn weather_period_start(&self) -> EorzeaTime {
+        EorzeaTime::from_esecs(self.weather_period_index() * EORZEA_WEATHER_PERIOD.esec)
+    }
+
+    /// When the current weather period ends and the next weather begins.
+    pub fn next_weather_change(&self) -> EorzeaTime {
+        self.weather_period_start() + EORZEA_WEATHER_PERIOD
+    }
+
+    /// The first time at or after this one when the Eorzea clock shows
+    /// `bell:minute`.
+    pub fn next_time_of_day(&self, bell: u8, minute: u8) -> EorzeaTime {
+        let offset = bell as u64 * BELL_IN_ESEC + minute as u64 * MINUTE_IN_ESEC;
+        let mut time =
+            EorzeaTime::from_esecs(self.timestamp - self.timestamp % SUN_IN_ESEC + offset);
+        if time < *self {
+            time += EORZEA_SUN;
+        }
+        time
+    }
+
+    pub fn duration_since(&self, other: EorzeaTime) -> Result<EorzeaDuration, EorzeaDurationError> {
         if other.timestamp > self.timestamp {
             return Err(EorzeaDurationError);
         }
@@ -197,14 +277,88 @@ impl EorzeaDuration {
             .map(|et| EorzeaDuration { esec: et.timestamp })
     }
 
-    pub fn from_esecs(esec: u64) -> EorzeaDuration {
+    pub const fn from_esecs(esec: u64) -> EorzeaDuration {
         EorzeaDuration { esec }
     }
 
-    pub fn total_seconds(&self) -> u64 {
+    pub const fn from_minutes(minutes: u64) -> EorzeaDuration {
+        EorzeaDuration::from_esecs(minutes * MINUTE_IN_ESEC)
+    }
+
+    pub const fn from_bells(bells: u64) -> EorzeaDuration {
+        EorzeaDuration::from_esecs(bells * BELL_IN_ESEC)
+    }
+
+    pub const fn from_suns(suns: u64) -> EorzeaDuration {
+        EorzeaDuration::from_esecs(suns * SUN_IN_ESEC)
+    }
+
+    pub fn from_real(duration: Duration) -> EorzeaDuration {
+        EorzeaDuration {
+            esec: (duration.as_secs() as f64 * EORZEA_TIME_CONST).round() as u64,
+        }
+    }
+
+    pub fn to_real(&self) -> Duration {
+        Duration::from_secs((self.esec as f64 / EORZEA_TIME_CONST).round() as u64)
+    }
+
+    pub const fn total_seconds(&self) -> u64 {
         self.esec
     }
 
+    /// Whole years, moons, suns, bells, minutes and seconds, largest first.
+    fn units(&self) -> [(u64, &'static str); 6] {
+        let sizes = [
+            YEAR_IN_ESEC,
+            MOON_IN_ESEC,
+            SUN_IN_ESEC,
+            BELL_IN_ESEC,
+            MINUTE_IN_ESEC,
+            1,
+        ];
+        let mut rest = self.esec;
+        let mut units = [(0, ""); 6];
+        for (i, size) in sizes.into_iter().enumerate() {
+            units[i] = (rest / size, UNIT_NAMES[i]);
+            rest %= size;
+        }
+        units
+    }
+
+    /// The two largest units, e.g. "3 suns 4 bells" or "45 minutes".
+    pub fn humanize(&self) -> String {
+        let parts: Vec<String> = self
+            .units()
+            .into_iter()
+            .skip_while(|(count, _)| *count == 0)
+            .take(2)
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, unit)| unit_text(count, unit))
+            .collect();
+        match parts.is_empty() {
+            true => unit_text(0, "second"),
+            false => parts.join(" "),
+        }
+    }
+
+    /// Short form for logs, e.g. "3d4h" or "1y2M30s": years, moons (M),
+    /// suns (d), bells (h), minutes (m) and seconds, leaving out zeros.
+    pub fn compact(&self) -> String {
+        let letters = ["y", "M", "d", "h", "m", "s"];
+        let text: String = self
+            .units()
+            .into_iter()
+            .zip(letters)
+            .filter(|((count, _), _)| *count > 0)
+            .map(|((count, _), letter)| format!("{}{}", count, letter))
+            .collect();
+        match text.is_empty() {
+            true => "0s".to_string(),
+            false => text,
+        }
+    }
+
     pub fn year(&self) -> u16 {
         (1 + self.esec / YEAR_IN_ESEC) as u16
     }
@@ -264,18 +418,35 @@ impl EorzeaTimeSpan {
         EorzeaTimeSpan::new_start_end(max_start, min_end)
     }
 }
+/// E.g. "1 moon 3 suns 02:00:00"; durations below a sun are only
+/// "bells:minutes:seconds".
 impl std::fmt::Display for EorzeaDuration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (count, unit) in self.units().into_iter().take(3) {
+            if count > 0 {
+                write!(f, "{} ", unit_text(count, unit))?;
+            }
+        }
         write!(
             f,
             "{:0>2}:{:0>2}:{:0>2}",
-            self.esec / BELL_IN_ESEC,
+            self.esec % SUN_IN_ESEC / BELL_IN_ESEC,
             (self.esec % BELL_IN_ESEC) / MINUTE_IN_ESEC,
             self.esec % MINUTE_IN_ESEC
         )
     }
 }
 
+/// Unit names for `EorzeaDuration::humanize`, largest first.
+const UNIT_NAMES: [&str; 6] = ["year", "moon", "sun", "bell", "minute", "second"];
+
+fn unit_text(count: u64, unit: &str) -> String {
+    match count {
+        1 => format!("1 {}", unit),
+        n => format!("{} {}s", n, unit),
+    }
+}
+
 impl std::fmt::Display for EorzeaTimeSpan {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{} for {}", self.start, self.duration)
@@ -293,9 +464,26 @@ impl Rem for EorzeaDuration {
 
 #[cfg(test)]
 mod tests {
-    use std::{time::Duration, vec};
+    use std::{collections::HashMap, time::Duration, vec};
 
     use super::*;
+
+    #[test]
+    fn et_hours() {
+        let real = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+        let hours = et_hours_between(real(175 * 100), real(175 * 103));
+        assert_eq!(hours.len(), 3);
+        assert_eq!(
+            hours[0],
+            (EorzeaTime::from_esecs(100 * BELL_IN_ESEC), real(175 * 100))
+        );
+        assert_eq!(hours[2].0.bell(), 102 % 24);
+        assert_eq!(EorzeaTime::from_time(&hours[1].1).unwrap(), hours[1].0);
+        // A bell that began just before the start is left out.
+        let later = et_hours_between(real(175 * 100) + Duration::from_millis(1), real(175 * 102));
+        assert_eq!(later.len(), 1);
+        assert!(et_hours_between(real(10), real(10)).is_empty());
+    }
     #[test]
     pub fn new_eorzea_time() {
         assert_eq!(
@@ -383,6 +571,95 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn eorzea_duration_real() {
+        assert_eq!(
+            EorzeaDuration::from_real(Duration::from_secs(175)),
+            EorzeaDuration::new(1, 0, 0).unwrap()
+        );
+        assert_eq!(EORZEA_SUN.to_real(), Duration::from_secs(70 * 60));
+    }
+
+    #[test]
+    pub fn next_time_of_day() {
+        let time = EorzeaTime::new(1000, 1, 1, 14, 30, 0).unwrap();
+        assert_eq!(
+            time.next_time_of_day(18, 0),
+            EorzeaTime::new(1000, 1, 1, 18, 0, 0).unwrap()
+        );
+        assert_eq!(
+            time.next_time_of_day(6, 15),
+            EorzeaTime::new(1000, 1, 2, 6, 15, 0).unwrap()
+        );
+        assert_eq!(time.next_time_of_day(14, 30), time);
+    }
+
+    #[test]
+    pub fn offset_corrects_time() {
+        let time = UNIX_EPOCH + Duration::from_secs(7200);
+        assert_eq!(
+            with_offset(time, 3_600_500),
+            time + Duration::from_millis(3_600_500)
+        );
+        assert_eq!(with_offset(time, -90_000), time - Duration::from_secs(90));
+        assert_eq!(with_offset(time, 0), time);
+    }
+
+    #[test]
+    pub fn weather_periods() {
+        let time = EorzeaTime::new(1, 1, 2, 9, 30, 0).unwrap();
+        assert_eq!(time.weather_period_index(), 4);
+        assert_eq!(
+            time.weather_period_start(),
+            EorzeaTime::new(1, 1, 2, 8, 0, 0).unwrap()
+        );
+        assert_eq!(
+            time.next_weather_change(),
+            EorzeaTime::new(1, 1, 2, 16, 0, 0).unwrap()
+        );
+        // A period start is its own period's start, not the previous one's.
+        let start = time.weather_period_start();
+        assert_eq!(start.weather_period_start(), start);
+        assert_eq!(start.next_weather_change(), time.next_weather_change());
+    }
+
+    #[test]
+    pub fn eorzea_duration_constructors() {
+        const PERIOD: EorzeaDuration = EorzeaDuration::from_bells(8);
+        assert_eq!(PERIOD, EORZEA_WEATHER_PERIOD);
+        assert_eq!(
+            EorzeaDuration::from_minutes(90),
+            EorzeaDuration::new(1, 30, 0).unwrap()
+        );
+        assert_eq!(EorzeaDuration::from_suns(1), EORZEA_SUN);
+        let mut durations = vec![EORZEA_SUN, EorzeaDuration::from_minutes(5), PERIOD];
+        durations.sort();
+        assert_eq!(durations[0], EorzeaDuration::from_minutes(5));
+        let counts: HashMap<EorzeaDuration, u32> = durations.into_iter().map(|d| (d, 1)).collect();
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    pub fn eorzea_duration_display() {
+        let three_suns = EorzeaDuration::from_suns(3);
+        assert_eq!(three_suns.to_string(), "3 suns 00:00:00");
+        assert_eq!(
+            EorzeaDuration::new(2, 5, 0).unwrap().to_string(),
+            "02:05:00"
+        );
+        let long = EorzeaDuration::new_ext(1, 2, 1, 4, 0, 30).unwrap();
+        assert_eq!(long.to_string(), "1 year 2 moons 1 sun 04:00:30");
+        assert_eq!(three_suns.humanize(), "3 suns");
+        assert_eq!(long.humanize(), "1 year 2 moons");
+        assert_eq!(
+            EorzeaDuration::new(0, 45, 0).unwrap().humanize(),
+            "45 minutes"
+        );
+        assert_eq!(EorzeaDuration::from_esecs(0).humanize(), "0 seconds");
+        assert_eq!(long.compact(), "1y2M1d4h30s");
+        assert_eq!(EorzeaDuration::from_esecs(0).compact(), "0s");
+    }
+
     #[test]
     pub fn eorzea_time_span() {
         let time_span =
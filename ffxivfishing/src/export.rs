@@ -0,0 +1,272 @@
+use std::time::SystemTime;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{
+    available::UpcomingWindow,
+    eorzea_time::{EorzeaTime, EorzeaTimeSpan},
+    fish::{Fish, FishData},
+    lang::Lang,
+    search::WindowQuery,
+};
+
+/// The windows of `fish` overlapping `start..end`, joining windows that
+/// continue into the next weather period.
+fn fish_windows(fish: &Fish, start: EorzeaTime, end: EorzeaTime) -> Vec<EorzeaTimeSpan> {
+    let mut windows: Vec<EorzeaTimeSpan> = vec![];
+    let mut time = start;
+    while let Ok(window) = fish.next_window(&WindowQuery::new(time).include_ongoing(time == start))
+    {
+        if window.start() >= end || window.end() <= time {
+            break;
+        }
+        time = window.end();
+        match windows.last_mut() {
+            Some(last) if last.end() >= window.start() => {
+                if let Ok(joined) = EorzeaTimeSpan::new_start_end(last.start(), window.end()) {
+                    *last = joined;
+                }
+            }
+            _ => windows.push(window),
+        }
+    }
+    windows
+}
+
+impl FishData {
+    /// Every window of the fish `filter` returns true for that overlaps
+    /// `start..end`, sorted by start. Unlike `upcoming_windows`, windows
+    /// split at weather changes are joined, as a calendar wants them.
+    pub fn windows_between(
+        &self,
+        start: EorzeaTime,
+        end: EorzeaTime,
+        filter: impl Fn(&Fish) -> bool,
+    ) -> Vec<UpcomingWindow<'_>> {
+        let mut windows: Vec<UpcomingWindow> = self
+            .fishes()
+            .iter()
+            .filter(|f| filter(f))
+            .flat_map(|fish| {
+                fish_windows(fish, start, end)
+                    .into_iter()
+                    .map(move |window| UpcomingWindow { fish, window })
+            })
+            .collect();
+        windows.sort_by_key(|w| (w.window.start(), w.fish.id));
+        windows
+    }
+}
+
+/// One exported window with its names in the chosen language.
+struct Row<'a> {
+    id: u32,
+    name: &'a str,
+    location: &'a str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+fn rows<'a>(
+    data: &'a FishData,
+    windows: &'a [UpcomingWindow],
+    lang: Lang,
+) -> impl Iterator<Item = Row<'a>> {
+    windows.iter().map(move |w| Row {
+        id: w.fish.id,
+        name: data
+            .localized_item_name(w.fish.id, lang)
+            .unwrap_or(w.fish.name()),
+        location: w.fish.location.localized_name(lang),
+        start: w.window.start().to_system_time().into(),
+        end: w.window.end().to_system_time().into(),
+    })
+}
+
+fn rfc3339(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn csv_field(text: &str) -> String {
+    match text.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", text.replace('"', "\"\"")),
+        false => text.to_string(),
+    }
+}
+
+/// The windows as CSV with a header row and times in UTC.
+pub fn to_csv(data: &FishData, windows: &[UpcomingWindow], lang: Lang) -> String {
+    let mut csv = "id,name,location,start,end\n".to_string();
+    for row in rows(data, windows, lang) {
+        csv += &format!(
+            "{},{},{},{},{}\n",
+            row.id,
+            csv_field(row.name),
+            csv_field(row.location),
+            rfc3339(row.start),
+            rfc3339(row.end)
+        );
+    }
+    csv
+}
+
+fn ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Folds `line` into lines of at most 75 bytes as RFC 5545 asks, each
+/// continuation starting with a space.
+fn ics_line(ics: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            ics.push_str("\r\n ");
+            width = 1;
+        }
+        ics.push(c);
+        width += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+fn ics_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// The windows as an iCalendar file with one event each, described by the
+/// fish's conditions. `now` is the creation time the events are stamped
+/// with.
+pub fn to_ics(data: &FishData, windows: &[UpcomingWindow], lang: Lang, now: SystemTime) -> String {
+    let mut ics = String::new();
+    ics_line(&mut ics, "BEGIN:VCALENDAR");
+    ics_line(&mut ics, "VERSION:2.0");
+    ics_line(&mut ics, "PRODID:-//ffxivfishing//fish windows//EN");
+    for (row, window) in rows(data, windows, lang).zip(windows) {
+        ics_line(&mut ics, "BEGIN:VEVENT");
+        ics_line(
+            &mut ics,
+            &format!("UID:{}-{}@ffxivfishing", row.id, row.start.timestamp()),
+        );
+        ics_line(&mut ics, &format!("DTSTAMP:{}", ics_time(now.into())));
+        ics_line(&mut ics, &format!("DTSTART:{}", ics_time(row.start)));
+        ics_line(&mut ics, &format!("DTEND:{}", ics_time(row.end)));
+        ics_line(&mut ics, &format!("SUMMARY:{}", ics_text(row.name)));
+        ics_line(&mut ics, &format!("LOCATION:{}", ics_text(row.location)));
+        ics_line(
+            &mut ics,
+            &format!(
+                "DESCRIPTION:{}",
+                ics_text(&data.conditions_summary(window.fish, lang))
+            ),
+        );
+        ics_line(&mut ics, "END:VEVENT");
+    }
+    ics_line(&mut ics, "END:VCALENDAR");
+    ics
+}
+
+/// A window as `to_json` writes it.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonRow<'a> {
+    id: u32,
+    name: &'a str,
+    location: &'a str,
+    start: String,
+    end: String,
+}
+
+/// The windows as a JSON array of objects with times in UTC.
+#[cfg(feature = "serde")]
+pub fn to_json(data: &FishData, windows: &[UpcomingWindow], lang: Lang) -> String {
+    let rows: Vec<JsonRow> = rows(data, windows, lang)
+        .map(|row| JsonRow {
+            id: row.id,
+            name: row.name,
+            location: row.location,
+            start: rfc3339(row.start),
+            end: rfc3339(row.end),
+        })
+        .collect();
+    serde_json::to_string_pretty(&rows).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eorzea_time::EorzeaDuration, fixtures::*};
+
+    fn windows(data: &FishData) -> Vec<UpcomingWindow<'_>> {
+        let end = epoch() + EorzeaDuration::from_suns(2);
+        data.windows_between(epoch(), end, |f| [DAWN_PERCH, RAIN_CARP].contains(&f.id))
+    }
+
+    #[test]
+    fn windows_between() {
+        let data = fixture_data();
+        let spans: Vec<(u32, u8, u8, u8)> = windows(&data)
+            .iter()
+            .map(|w| {
+                let (start, end) = (w.window.start(), w.window.end());
+                (w.fish.id, start.sun(), start.bell(), end.bell())
+            })
+            .collect();
+        // Rain Carp's 0:00-8:00 and 8:00-16:00 windows are joined.
+        assert_eq!(
+            spans,
+            vec![
+                (RAIN_CARP, 1, 0, 16),
+                (DAWN_PERCH, 1, 4, 8),
+                (DAWN_PERCH, 2, 4, 8),
+                (RAIN_CARP, 2, 8, 16),
+            ]
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let data = fixture_data();
+        let csv = to_csv(&data, &windows(&data), Lang::English);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], "id,name,location,start,end");
+        assert!(lines[1].starts_with("1004,Rain Carp,Misty Bank,"));
+        assert_eq!(csv_field("a, \"b\""), "\"a, \"\"b\"\"\"");
+    }
+
+    #[test]
+    fn ics() {
+        let data = fixture_data();
+        let ics = to_ics(
+            &data,
+            &windows(&data),
+            Lang::English,
+            SystemTime::UNIX_EPOCH,
+        );
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 4);
+        assert!(ics.contains("SUMMARY:Rain Carp\r\n"));
+        assert!(ics.lines().all(|line| line.len() <= 75));
+
+        let mut folded = String::new();
+        ics_line(&mut folded, &"x".repeat(80));
+        assert_eq!(
+            folded,
+            format!("{}\r\n {}\r\n", "x".repeat(75), "x".repeat(5))
+        );
+        assert_eq!(ics_text("a,b;c"), "a\\,b\\;c");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() {
+        let data = fixture_data();
+        let json: serde_json::Value =
+            serde_json::from_str(&to_json(&data, &windows(&data), Lang::English)).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 4);
+        assert_eq!(json[1]["name"], "Dawn Perch");
+    }
+}
@@ -1,27 +1,47 @@
 use std::{
+    collections::{HashMap, HashSet},
     fmt::Display,
     rc::Rc,
-    time::{Duration, SystemTime},
+    time::Duration,
 };
 
 use crate::{
-    eorzea_time::{EORZEA_SUN, EORZEA_WEATHER_PERIOD, EorzeaDuration, EorzeaTime, EorzeaTimeSpan},
+    bite::BiteTimes,
+    eorzea_time::{
+        EORZEA_SUN, EORZEA_WEATHER_PERIOD, EorzeaDuration, EorzeaTime, EorzeaTimeSpan, SUN_IN_ESEC,
+    },
+    lang::{Lang, LocalizedName},
+    provisioning::ProvisioningSchedule,
+    search::{self, NoWindow, SearchHorizon, WindowQuery},
     weather::{Weather, WeatherForecast},
 };
 
+/// A top-level part of the map such as La Noscea, grouping regions.
+#[derive(Debug, Clone)]
+pub struct Area {
+    id: u32,
+    name: LocalizedName,
+}
+
+/// A territory with its own weather, e.g. Western La Noscea.
 #[derive(Debug, Clone)]
 pub struct Region {
+    id: u32,
     name: String,
-    weather: WeatherForecast,
+    localized_name: LocalizedName,
+    area: Option<u32>,
+    weather: Rc<WeatherForecast>,
 }
 
 #[derive(Debug)]
 pub struct FishingHole {
+    id: u32,
     name: String,
+    localized_name: LocalizedName,
     region: Rc<Region>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Tug {
     Light,
     Medium,
@@ -29,14 +49,44 @@ pub enum Tug {
     Unknown,
 }
 
+impl Tug {
+    /// Parses English, German, French and Japanese names as well as "!" to
+    /// "!!!".
+    pub fn from_name(name: &str) -> Option<Tug> {
+        match name.trim().to_lowercase().as_str() {
+            "light" | "leicht" | "léger" | "弱" | "!" => Some(Tug::Light),
+            "medium" | "mittel" | "moyen" | "中" | "!!" => Some(Tug::Medium),
+            "heavy" | "schwer" | "lourd" | "強" | "!!!" => Some(Tug::Heavy),
+            _ => None,
+        }
+    }
+}
+
 impl From<&str> for Tug {
     fn from(value: &str) -> Self {
-        match value.to_lowercase().as_str() {
-            "light" => Tug::Light,
-            "medium" => Tug::Medium,
-            "heavy" => Tug::Heavy,
-            _ => Tug::Unknown,
+        Tug::from_name(value).unwrap_or(Tug::Unknown)
+    }
+}
+
+/// Names or codes 1 to 3, the number of exclamation marks. `null` is
+/// `Tug::Unknown`.
+#[cfg(feature = "serde")]
+impl TryFrom<&serde_json::Value> for Tug {
+    type Error = UnknownValue;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        match value {
+            serde_json::Value::Null => Some(Tug::Unknown),
+            serde_json::Value::String(name) => Tug::from_name(name),
+            serde_json::Value::Number(code) => match code.as_u64() {
+                Some(1) => Some(Tug::Light),
+                Some(2) => Some(Tug::Medium),
+                Some(3) => Some(Tug::Heavy),
+                _ => None,
+            },
+            _ => None,
         }
+        .ok_or_else(|| UnknownValue(value.clone()))
     }
 }
 
@@ -55,19 +105,52 @@ impl Display for Tug {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Hookset {
     Precision,
     Powerful,
     Unknown,
 }
+impl Hookset {
+    /// Parses the hookset or action name, e.g. "Powerful" or "Precision
+    /// Hookset", in English, German, French or Japanese.
+    pub fn from_name(name: &str) -> Option<Hookset> {
+        let name = name.trim().to_lowercase();
+        let has = |stems: &[&str]| stems.iter().any(|s| name.contains(s));
+        if has(&["precision", "präzis", "précis", "プレシジョン"]) {
+            Some(Hookset::Precision)
+        } else if has(&["powerful", "kraft", "puissant", "パワフル"]) {
+            Some(Hookset::Powerful)
+        } else {
+            None
+        }
+    }
+}
+
 impl From<&str> for Hookset {
     fn from(value: &str) -> Self {
-        match value.to_lowercase().as_str() {
-            "precision" => Hookset::Precision,
-            "powerful" => Hookset::Powerful,
-            _ => Hookset::Unknown,
+        Hookset::from_name(value).unwrap_or(Hookset::Unknown)
+    }
+}
+
+/// Names or codes in the order the actions were added: 1 is Powerful, 2 is
+/// Precision. `null` is `Hookset::Unknown`.
+#[cfg(feature = "serde")]
+impl TryFrom<&serde_json::Value> for Hookset {
+    type Error = UnknownValue;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        match value {
+            serde_json::Value::Null => Some(Hookset::Unknown),
+            serde_json::Value::String(name) => Hookset::from_name(name),
+            serde_json::Value::Number(code) => match code.as_u64() {
+                Some(1) => Some(Hookset::Powerful),
+                Some(2) => Some(Hookset::Precision),
+                _ => None,
+            },
+            _ => None,
         }
+        .ok_or_else(|| UnknownValue(value.clone()))
     }
 }
 
@@ -98,20 +181,172 @@ pub struct Intuition {
     requirements: Vec<(u8, u32)>,
 }
 impl Intuition {
-    pub(crate) fn new(length: Duration, requirements: Vec<(u8, u32)>) -> Self {
+    pub fn new(length: Duration, requirements: Vec<(u8, u32)>) -> Self {
         Self {
             length,
             requirements,
         }
     }
+    pub fn length(&self) -> Duration {
+        self.length
+    }
+    pub fn requirements(&self) -> &[(u8, u32)] {
+        &self.requirements
+    }
+}
+
+/// How often and how long a fish's windows open, from `Fish::window_stats`.
+/// Lengths are real time.
+#[derive(Debug, PartialEq, Clone)]
+pub struct WindowStats {
+    pub windows: u32,
+    pub windows_per_real_day: f64,
+    pub average_length: Duration,
+    pub min_length: Duration,
+    pub max_length: Duration,
+    /// Longest time without an open window in the searched span.
+    pub longest_gap: Duration,
 }
 
+/// Why a fish cannot be caught at some time, from `Fish::why_not_open`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Reason {
+    /// The Eorzea time of day is outside the fish's window.
+    WrongHours {
+        start: EorzeaDuration,
+        end: EorzeaDuration,
+    },
+    WrongWeather {
+        current: Weather,
+        needed: Vec<Weather>,
+    },
+    WrongPreviousWeather {
+        previous: Weather,
+        needed: Vec<Weather>,
+    },
+    /// `(count, fish id)` pairs to catch first for the intuition buff.
+    IntuitionUnmet { predators: Vec<(u8, u32)> },
+    /// The folklore book for the fish has to be read first.
+    FolkloreMissing,
+}
+
+/// Everything a catch attempt depends on, gathered from a fish.
 #[derive(Debug)]
+pub struct Requirements<'a> {
+    pub folklore: bool,
+    pub fish_eyes: bool,
+    pub snagging: bool,
+    pub lure: Option<Lure>,
+    /// `(count, fish id)` pairs to catch for intuition, empty without.
+    pub predators: &'a [(u8, u32)],
+    pub previous_weather: &'a [Weather],
+    pub weather: &'a [Weather],
+    /// Eorzea time of day the fish bites in, `None` if it bites all day.
+    pub window: Option<(EorzeaDuration, EorzeaDuration)>,
+    /// Bait or mooch, first to last. Only the last entry is known without
+    /// fish data, see `FishData::bait_chain`.
+    pub bait_chain: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Expansion {
+    ARealmReborn,
+    Heavensward,
+    Stormblood,
+    Shadowbringers,
+    Endwalker,
+    Dawntrail,
+}
+
+impl Expansion {
+    pub const ALL: [Expansion; 6] = [
+        Expansion::ARealmReborn,
+        Expansion::Heavensward,
+        Expansion::Stormblood,
+        Expansion::Shadowbringers,
+        Expansion::Endwalker,
+        Expansion::Dawntrail,
+    ];
+
+    /// The expansion a patch belongs to. Patches after Dawntrail count as
+    /// Dawntrail until the data knows better.
+    pub fn from_patch((major, _): (u8, u8)) -> Expansion {
+        match major {
+            0..=2 => Expansion::ARealmReborn,
+            3 => Expansion::Heavensward,
+            4 => Expansion::Stormblood,
+            5 => Expansion::Shadowbringers,
+            6 => Expansion::Endwalker,
+            _ => Expansion::Dawntrail,
+        }
+    }
+}
+
+impl Display for Expansion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Expansion::ARealmReborn => "A Realm Reborn",
+            Expansion::Heavensward => "Heavensward",
+            Expansion::Stormblood => "Stormblood",
+            Expansion::Shadowbringers => "Shadowbringers",
+            Expansion::Endwalker => "Endwalker",
+            Expansion::Dawntrail => "Dawntrail",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Lure {
-    Moderate,
+    Modest,
     Ambitious,
 }
 
+impl Lure {
+    /// Parses the lure names used by the data, e.g. "Ambitious", and the
+    /// item names in English and Japanese.
+    pub fn from_name(name: &str) -> Option<Lure> {
+        let name = name.trim().to_lowercase();
+        let has = |stems: &[&str]| stems.iter().any(|s| name.contains(s));
+        if has(&["modest", "モデスト"]) {
+            Some(Lure::Modest)
+        } else if has(&["ambitious", "アンビシャス"]) {
+            Some(Lure::Ambitious)
+        } else {
+            None
+        }
+    }
+}
+
+/// Names or codes in item order: 1 is Ambitious, 2 is Modest.
+#[cfg(feature = "serde")]
+impl TryFrom<&serde_json::Value> for Lure {
+    type Error = UnknownValue;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        match value {
+            serde_json::Value::String(name) => Lure::from_name(name),
+            serde_json::Value::Number(code) => match code.as_u64() {
+                Some(1) => Some(Lure::Ambitious),
+                Some(2) => Some(Lure::Modest),
+                _ => None,
+            },
+            _ => None,
+        }
+        .ok_or_else(|| UnknownValue(value.clone()))
+    }
+}
+
+impl Display for Lure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Lure::Modest => "Modest Lure",
+            Lure::Ambitious => "Ambitious Lure",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 #[derive(Debug)]
 pub struct Fish {
     pub id: u32,
@@ -125,13 +360,14 @@ pub struct Fish {
     pub tug: Tug,
     pub hookset: Hookset,
     pub intuition: Option<Intuition>,
-    pub lure: Lure,
-    pub lure_proc: bool,
+    /// Lure whose proc the fish needs, if any.
+    pub lure: Option<Lure>,
     pub snagging: bool,
     pub gig: bool,
     pub folklore: bool,
     pub fish_eyes: bool,
     pub patch: (u8, u8),
+    pub big_fish: bool,
 }
 
 impl Fish {
@@ -148,13 +384,13 @@ impl Fish {
         tug: Tug,
         hookset: Hookset,
         intuition: Option<Intuition>,
-        lure: Lure,
-        lure_proc: bool,
+        lure: Option<Lure>,
         snagging: bool,
         gig: bool,
         folklore: bool,
         fish_eyes: bool,
         patch: (u8, u8),
+        big_fish: bool,
     ) -> Fish {
         Self {
             id,
@@ -169,57 +405,244 @@ impl Fish {
             hookset,
             intuition,
             lure,
-            lure_proc,
             snagging,
             gig,
             folklore,
             fish_eyes,
             patch,
+            big_fish,
         }
     }
 
     pub fn window_on_day(&self, etime: EorzeaTime) -> EorzeaTimeSpan {
-        let mut day = etime;
-        day.round(EORZEA_SUN);
-        let start = day + self.window_start;
-        let mut end = day + self.window_end;
-        if end <= start {
-            end += EORZEA_SUN;
-        }
-        EorzeaTimeSpan::new_start_end(start, end).unwrap()
+        search::hours_on_day(etime, self.window_start, self.window_end)
+    }
+
+    /// Fish Eyes lifts the time restriction of fish from before Endwalker,
+    /// except for big fish.
+    pub fn fish_eyes_applies(&self) -> bool {
+        !self.big_fish && self.expansion() < Expansion::Endwalker
     }
 
-    pub fn next_window(
+    /// The next window matching `query`, see `search::next_window_in`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all, fields(fish = self.id))
+    )]
+    pub fn next_window(&self, query: &WindowQuery) -> Result<EorzeaTimeSpan, NoWindow> {
+        let (previous_weather_set, weather_set) = match &query.weather_override {
+            Some((previous, current)) => (previous.as_slice(), current.as_slice()),
+            None => (
+                self.previous_weather_set.as_slice(),
+                self.weather_set.as_slice(),
+            ),
+        };
+        let any_time = query.assume_fish_eyes && self.fish_eyes_applies();
+        let window = search::next_window_in(
+            &self.location.region.weather,
+            previous_weather_set,
+            weather_set,
+            |day| match any_time {
+                true => {
+                    let mut midnight = day;
+                    midnight.round(EORZEA_SUN);
+                    EorzeaTimeSpan::new(midnight, EORZEA_SUN)
+                }
+                false => self.window_on_day(day),
+            },
+            query,
+        );
+        #[cfg(feature = "tracing")]
+        if let Err(NoWindow { until }) = &window {
+            tracing::debug!(fish = self.id, %until, "no window within the horizon");
+        }
+        window
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Whether `time` is within the fish's hours, ignoring the weather.
+    pub(crate) fn in_hours(&self, time: EorzeaTime) -> bool {
+        // A window that wraps past midnight belongs to the previous day.
+        [time - EORZEA_SUN, time].into_iter().any(|day| {
+            let window = self.window_on_day(day);
+            window.start() <= time && time < window.end()
+        })
+    }
+    /// Everything keeping the fish from biting at `time`; empty if it bites.
+    /// The player's progress is unknown here, so intuition and folklore are
+    /// listed whenever the fish needs them, for callers to drop the ones
+    /// that are met.
+    pub fn why_not_open(&self, time: EorzeaTime) -> Vec<Reason> {
+        let mut reasons = vec![];
+        if !self.in_hours(time) {
+            reasons.push(Reason::WrongHours {
+                start: self.window_start,
+                end: self.window_end,
+            });
+        }
+        let weather = self.location.region().weather();
+        let current = *weather.weather_at(time);
+        if !self.weather_set.is_empty() && !self.weather_set.contains(&current) {
+            reasons.push(Reason::WrongWeather {
+                current,
+                needed: self.weather_set.clone(),
+            });
+        }
+        let previous = *weather.weather_at(time - EORZEA_WEATHER_PERIOD);
+        if !self.previous_weather_set.is_empty() && !self.previous_weather_set.contains(&previous) {
+            reasons.push(Reason::WrongPreviousWeather {
+                previous,
+                needed: self.previous_weather_set.clone(),
+            });
+        }
+        if let Some(intuition) = &self.intuition
+            && !intuition.requirements().is_empty()
+        {
+            reasons.push(Reason::IntuitionUnmet {
+                predators: intuition.requirements().to_vec(),
+            });
+        }
+        if self.folklore {
+            reasons.push(Reason::FolkloreMissing);
+        }
+        reasons
+    }
+    /// Statistics of the windows within `horizon` from now, `None` if there
+    /// are none.
+    pub fn window_stats(&self, horizon: SearchHorizon) -> Option<WindowStats> {
+        self.window_stats_from(EorzeaTime::now(), horizon)
+    }
+    /// Like `window_stats`, starting at `start`. Windows that continue into
+    /// the next weather period count as one.
+    pub fn window_stats_from(
         &self,
         start: EorzeaTime,
-        include_ongoing: bool,
-        mut limit: u32,
-    ) -> Option<EorzeaTimeSpan> {
-        let mut time = start;
-        while limit > 0 {
-            let next_weather = self.location.region.weather.find_pattern(
-                time,
-                &self.previous_weather_set,
-                &self.weather_set,
-                limit,
-            )?;
-            let weather_span = EorzeaTimeSpan::new(next_weather, EORZEA_WEATHER_PERIOD);
-            if let Ok(window) = self.window_on_day(time).overlap(&weather_span) {
-                let min_window = match include_ongoing {
-                    true => window.end(),
-                    false => window.start(),
-                };
-                if start <= min_window && window.duration().total_seconds() > 0 {
-                    return Some(window);
+        horizon: SearchHorizon,
+    ) -> Option<WindowStats> {
+        let end = horizon.end(start, EORZEA_WEATHER_PERIOD);
+        let windows: Vec<(EorzeaTime, EorzeaTime)> = self
+            .joined_windows(start, end, |_| false)
+            .iter()
+            .map(|w| (w.start().max(start), w.end().min(end)))
+            .collect();
+        if windows.is_empty() {
+            return None;
+        }
+        let real = |from: EorzeaTime, to: EorzeaTime| {
+            to.duration_since(from)
+                .map_or(Duration::ZERO, |d| d.to_real())
+        };
+        let lengths: Vec<Duration> = windows.iter().map(|(s, e)| real(*s, *e)).collect();
+        let mut gap_starts = vec![start];
+        gap_starts.extend(windows.iter().map(|(_, e)| *e));
+        let mut gap_ends: Vec<EorzeaTime> = windows.iter().map(|(s, _)| *s).collect();
+        gap_ends.push(end);
+        let days = real(start, end).as_secs_f64() / (24.0 * 60.0 * 60.0);
+        Some(WindowStats {
+            windows: windows.len() as u32,
+            windows_per_real_day: windows.len() as f64 / days,
+            average_length: lengths.iter().sum::<Duration>() / windows.len() as u32,
+            min_length: *lengths.iter().min()?,
+            max_length: *lengths.iter().max()?,
+            longest_gap: gap_starts
+                .into_iter()
+                .zip(gap_ends)
+                .map(|(s, e)| real(s, e))
+                .max()?,
+        })
+    }
+    /// Up to `before_n` windows that opened before `time`, including one
+    /// that is still open, and `after_n` that open at or after it, oldest
+    /// first. Windows that continue into the next weather period count as
+    /// one.
+    pub fn windows_around(
+        &self,
+        time: EorzeaTime,
+        before_n: usize,
+        after_n: usize,
+    ) -> Vec<EorzeaTimeSpan> {
+        let horizon = SearchHorizon::default();
+        let until = horizon.end(time, EORZEA_WEATHER_PERIOD);
+        let max_lookback = EorzeaDuration::from_esecs(
+            horizon.periods(EORZEA_WEATHER_PERIOD) as u64 * EORZEA_WEATHER_PERIOD.total_seconds(),
+        );
+        let mut lookback = EorzeaDuration::from_suns(before_n as u64 + 1);
+        loop {
+            let from = time - lookback;
+            // One window more than needed tells that the last one is complete.
+            let windows = self.joined_windows(from, until, |windows| {
+                windows.iter().filter(|w| w.start() >= time).count() > after_n
+            });
+            let (mut past, future): (Vec<EorzeaTimeSpan>, Vec<EorzeaTimeSpan>) =
+                windows.into_iter().partition(|w| w.start() < time);
+            // The first window may have started before `from`.
+            let complete = past.len() > before_n || lookback >= max_lookback;
+            if complete || before_n == 0 {
+                past.drain(..past.len().saturating_sub(before_n));
+                past.extend(future.into_iter().take(after_n));
+                return past;
+            }
+            lookback = EorzeaDuration::from_esecs(lookback.total_seconds() * 2);
+        }
+    }
+    /// Windows from `from` until `until`, joined where they continue into the
+    /// next weather period, until `done` is true for the ones found so far.
+    fn joined_windows(
+        &self,
+        from: EorzeaTime,
+        until: EorzeaTime,
+        mut done: impl FnMut(&[EorzeaTimeSpan]) -> bool,
+    ) -> Vec<EorzeaTimeSpan> {
+        let mut windows: Vec<EorzeaTimeSpan> = vec![];
+        let mut time = from;
+        while let Ok(remaining) = until.duration_since(time) {
+            if done(&windows) {
+                break;
+            }
+            let query = WindowQuery::new(time)
+                .include_ongoing(time == from)
+                .horizon(SearchHorizon::Eorzea(remaining));
+            let window = match self.next_window(&query) {
+                Ok(w) if w.start() < until => w,
+                _ => break,
+            };
+            time = window.end();
+            match windows.last_mut() {
+                Some(last) if last.end() == window.start() => {
+                    *last = EorzeaTimeSpan::new_start_end(last.start(), window.end()).unwrap()
                 }
+                _ => windows.push(window),
             }
-            time += EORZEA_WEATHER_PERIOD;
-            limit -= 1;
         }
-        None
+        windows
     }
-    pub fn name(&self) -> &str {
-        &self.name
+    /// Approximate share of time the fish is available, from the length of its
+    /// daily window and the chance of its weather conditions.
+    pub fn uptime(&self) -> f64 {
+        let length = match (self.window_end.total_seconds() + SUN_IN_ESEC
+            - self.window_start.total_seconds())
+            % SUN_IN_ESEC
+        {
+            0 => SUN_IN_ESEC,
+            l => l,
+        };
+        let weather = &self.location.region.weather;
+        length as f64 / SUN_IN_ESEC as f64
+            * weather.probability(&self.weather_set)
+            * weather.probability(&self.previous_weather_set)
+    }
+    /// How rare the fish is: 1 for a fish that is always available, 10 for one
+    /// that is available a tenth of the time.
+    pub fn rarity(&self) -> f64 {
+        1.0 / self.uptime()
+    }
+    /// True if neither time nor weather restrict the fish.
+    pub fn always_available(&self) -> bool {
+        self.uptime() >= 1.0
+    }
+    pub fn expansion(&self) -> Expansion {
+        Expansion::from_patch(self.patch)
     }
     pub fn start(&self) -> &EorzeaDuration {
         &self.window_start
@@ -229,10 +652,25 @@ impl Fish {
     }
 
     pub fn weather_now(&self) -> &Weather {
-        self.location
-            .region
-            .weather
-            .weather_at(EorzeaTime::from_time(&SystemTime::now()).unwrap())
+        self.location.region.weather.weather_at(EorzeaTime::now())
+    }
+    /// How long the intuition buff lasts, `None` if the fish needs none.
+    pub fn intuition_length(&self) -> Option<Duration> {
+        self.intuition.as_ref().map(|i| i.length())
+    }
+    pub fn requirements(&self) -> Requirements<'_> {
+        Requirements {
+            folklore: self.folklore,
+            fish_eyes: self.fish_eyes,
+            snagging: self.snagging,
+            lure: self.lure,
+            predators: self.intuition.as_ref().map_or(&[], |i| i.requirements()),
+            previous_weather: &self.previous_weather_set,
+            weather: &self.weather_set,
+            window: (self.window_start != self.window_end)
+                .then_some((self.window_start, self.window_end)),
+            bait_chain: self.bait_id().into_iter().collect(),
+        }
     }
     pub fn bait_id(&self) -> Option<u32> {
         match self.bait {
@@ -243,49 +681,230 @@ impl Fish {
     }
 }
 
+impl Area {
+    pub fn new(id: u32, name: LocalizedName) -> Area {
+        Area { id, name }
+    }
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name.en
+    }
+    pub fn localized_name(&self, lang: Lang) -> &str {
+        self.name.get(lang)
+    }
+}
+
 impl FishingHole {
-    pub fn new(name: String, region: Rc<Region>) -> FishingHole {
-        FishingHole { name, region }
+    pub fn new(id: u32, name: String, region: Rc<Region>) -> FishingHole {
+        FishingHole {
+            id,
+            localized_name: LocalizedName {
+                en: name.clone(),
+                ..Default::default()
+            },
+            name,
+            region,
+        }
+    }
+    /// Sets the names in other languages than English.
+    pub fn with_localized_name(mut self, name: LocalizedName) -> FishingHole {
+        self.localized_name = name;
+        self
+    }
+    pub fn id(&self) -> u32 {
+        self.id
     }
     pub fn name(&self) -> &str {
         &self.name
     }
+    pub fn localized_name(&self, lang: Lang) -> &str {
+        self.localized_name.get(lang)
+    }
+    pub fn region(&self) -> &Rc<Region> {
+        &self.region
+    }
 }
 
 impl Region {
-    pub fn new(name: String, weather: WeatherForecast) -> Region {
-        Region { name, weather }
+    pub fn new(id: u32, name: String, weather: impl Into<Rc<WeatherForecast>>) -> Region {
+        Region {
+            id,
+            localized_name: LocalizedName {
+                en: name.clone(),
+                ..Default::default()
+            },
+            name,
+            area: None,
+            weather: weather.into(),
+        }
+    }
+    /// Sets the names in other languages than English.
+    pub fn with_localized_name(mut self, name: LocalizedName) -> Region {
+        self.localized_name = name;
+        self
+    }
+    /// Places the region in the `Area` with id `area`.
+    pub fn with_area(mut self, area: u32) -> Region {
+        self.area = Some(area);
+        self
+    }
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn localized_name(&self, lang: Lang) -> &str {
+        self.localized_name.get(lang)
+    }
+    pub fn area_id(&self) -> Option<u32> {
+        self.area
+    }
+    pub fn weather(&self) -> &WeatherForecast {
+        &self.weather
+    }
+    /// The forecast handle, shared by regions with the same weather rates.
+    pub fn shared_weather(&self) -> &Rc<WeatherForecast> {
+        &self.weather
+    }
+}
+
+/// Game path of an icon id, e.g. `ui/icon/027000/027004.tex` for 27004.
+pub fn icon_path(icon: u32) -> String {
+    format!("ui/icon/{:0>6}/{:0>6}.tex", icon / 1000 * 1000, icon)
+}
+
+#[derive(Debug, Clone)]
+pub struct Item {
+    id: u32,
+    name: String,
+    icon: u32,
+    ilvl: u32,
+}
+
+impl Item {
+    pub fn new(id: u32, name: String, icon: u32, ilvl: u32) -> Item {
+        Item {
+            id,
+            name,
+            icon,
+            ilvl,
+        }
+    }
+    pub fn id(&self) -> u32 {
+        self.id
     }
     pub fn name(&self) -> &str {
         &self.name
     }
+    pub fn icon(&self) -> u32 {
+        self.icon
+    }
+    pub fn icon_path(&self) -> String {
+        icon_path(self.icon)
+    }
+    pub fn ilvl(&self) -> u32 {
+        self.ilvl
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum FishingItem {
-    Fish(String, u32),
-    Bait(String, u32),
+    Fish(Item),
+    Bait(Item),
 }
 impl FishingItem {
-    pub fn name(&self) -> &str {
+    pub fn item(&self) -> &Item {
         match self {
-            FishingItem::Fish(name, _) => name,
-            FishingItem::Bait(name, _) => name,
+            FishingItem::Fish(item) => item,
+            FishingItem::Bait(item) => item,
         }
     }
+    pub fn name(&self) -> &str {
+        self.item().name()
+    }
     pub fn id(&self) -> u32 {
+        self.item().id()
+    }
+}
+
+/// A folklore tome and the fish it unlocks.
+#[derive(Debug, Clone)]
+pub struct FolkloreBook {
+    pub id: u32,
+    pub name: String,
+    pub zone: String,
+    pub fish: Vec<u32>,
+}
+
+/// Why an entry could not be added to `FishData`.
+#[derive(Debug, PartialEq)]
+pub enum DataError {
+    DuplicateId(u32),
+    UnknownRegion(u32),
+    UnknownHole(u32),
+    UnknownItem(u32),
+    UnknownFish(u32),
+}
+
+impl Display for DataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            FishingItem::Fish(_, id) => *id,
-            FishingItem::Bait(_, id) => *id,
+            DataError::DuplicateId(id) => write!(f, "id {} is already in use", id),
+            DataError::UnknownRegion(id) => write!(f, "unknown region {}", id),
+            DataError::UnknownHole(id) => write!(f, "unknown fishing hole {}", id),
+            DataError::UnknownItem(id) => write!(f, "unknown item {}", id),
+            DataError::UnknownFish(id) => write!(f, "unknown fish {}", id),
         }
     }
 }
 
+impl std::error::Error for DataError {}
+
+/// A data value none of the parsers understood.
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct UnknownValue(pub serde_json::Value);
+
+#[cfg(feature = "serde")]
+impl Display for UnknownValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown value {}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for UnknownValue {}
+
+/// Something `FishData::merge` had to decide between the two data sets.
+#[derive(Debug, PartialEq)]
+pub enum MergeConflict {
+    /// The overlay's fish replaced the base one.
+    FishReplaced(u32),
+    /// A region, hole or item with this id differs in name; the base one
+    /// was kept.
+    NameMismatch {
+        id: u32,
+        base: String,
+        overlay: String,
+    },
+}
+
+#[derive(Default)]
 pub struct FishData {
     fishes: Vec<Fish>,
     fishing_holes: Vec<Rc<FishingHole>>,
     regions: Vec<Rc<Region>>,
+    areas: Vec<Area>,
     items: Vec<FishingItem>,
+    weather_names: HashMap<u32, String>,
+    folklore_books: Vec<FolkloreBook>,
+    localized_items: HashMap<u32, LocalizedName>,
+    localized_weathers: HashMap<u32, LocalizedName>,
+    provisioning: ProvisioningSchedule,
+    bite_times: BiteTimes,
 }
 
 impl FishData {
@@ -294,13 +913,229 @@ impl FishData {
         fishing_holes: Vec<Rc<FishingHole>>,
         regions: Vec<Rc<Region>>,
         items: Vec<FishingItem>,
+        weather_names: HashMap<u32, String>,
+        folklore_books: Vec<FolkloreBook>,
     ) -> FishData {
         FishData {
             fishes,
             fishing_holes,
             regions,
+            areas: vec![],
             items,
+            weather_names,
+            folklore_books,
+            localized_items: HashMap::new(),
+            localized_weathers: HashMap::new(),
+            provisioning: ProvisioningSchedule::new(),
+            bite_times: BiteTimes::new(),
+        }
+    }
+    /// Adds item and weather names in other languages than English.
+    pub fn with_localized_names(
+        mut self,
+        items: HashMap<u32, LocalizedName>,
+        weathers: HashMap<u32, LocalizedName>,
+    ) -> FishData {
+        self.localized_items = items;
+        self.localized_weathers = weathers;
+        self
+    }
+    /// Adds the areas the regions are grouped in.
+    pub fn with_areas(mut self, areas: Vec<Area>) -> FishData {
+        self.areas = areas;
+        self
+    }
+    /// Adds the items Grand Company provisioning asks for, see
+    /// `FishData::provisioning_candidates`.
+    pub fn with_provisioning(mut self, schedule: ProvisioningSchedule) -> FishData {
+        self.provisioning = schedule;
+        self
+    }
+    /// Adds how long fish take to bite, see `FishData::estimated_casts`.
+    pub fn with_bite_times(mut self, bite_times: BiteTimes) -> FishData {
+        self.bite_times = bite_times;
+        self
+    }
+    /// Adds a region. Its id must be new. A forecast with the same rates as
+    /// a known region's is replaced by that region's shared one.
+    pub fn add_region(&mut self, mut region: Region) -> Result<Rc<Region>, DataError> {
+        if self.region_by_id(region.id()).is_some() {
+            return Err(DataError::DuplicateId(region.id()));
+        }
+        if let Some(known) = self
+            .regions
+            .iter()
+            .find(|r| r.weather.same_rates(&region.weather))
+        {
+            region.weather = Rc::clone(&known.weather);
+        }
+        let region = Rc::new(region);
+        self.regions.push(region.clone());
+        Ok(region)
+    }
+    /// Adds a fishing hole whose region was added before.
+    pub fn add_fishing_hole(&mut self, hole: FishingHole) -> Result<Rc<FishingHole>, DataError> {
+        if self.hole_by_id(hole.id()).is_some() {
+            return Err(DataError::DuplicateId(hole.id()));
+        }
+        if self.region_by_id(hole.region().id()).is_none() {
+            return Err(DataError::UnknownRegion(hole.region().id()));
+        }
+        let hole = Rc::new(hole);
+        self.fishing_holes.push(hole.clone());
+        Ok(hole)
+    }
+    /// Adds a bait or other item. Fish are registered by `add_fish`.
+    pub fn add_item(&mut self, item: Item) -> Result<(), DataError> {
+        if self.item_by_id(item.id()).is_some() {
+            return Err(DataError::DuplicateId(item.id()));
+        }
+        self.items.push(FishingItem::Bait(item));
+        Ok(())
+    }
+    /// Adds a fish and registers it as an item so it can be mooched. Its
+    /// fishing hole, bait and predators have to be in the data already.
+    /// `Fish` knows no icon or item level, so the item gets 0 for both.
+    pub fn add_fish(&mut self, fish: Fish) -> Result<(), DataError> {
+        if self.fish_by_id(fish.id).is_some() || self.item_by_id(fish.id).is_some() {
+            return Err(DataError::DuplicateId(fish.id));
+        }
+        if self.hole_by_id(fish.location.id()).is_none() {
+            return Err(DataError::UnknownHole(fish.location.id()));
+        }
+        if let Some(bait) = fish.bait_id()
+            && self.item_by_id(bait).is_none()
+        {
+            return Err(DataError::UnknownItem(bait));
+        }
+        let predators = fish
+            .intuition
+            .as_ref()
+            .map_or(&[][..], |i| i.requirements());
+        if let Some((_, id)) = predators
+            .iter()
+            .find(|(_, id)| self.fish_by_id(*id).is_none())
+        {
+            return Err(DataError::UnknownFish(*id));
+        }
+        self.items.push(FishingItem::Fish(Item::new(
+            fish.id,
+            fish.name.clone(),
+            0,
+            0,
+        )));
+        self.fishes.push(fish);
+        Ok(())
+    }
+    /// Adds everything from `overlay` to this data. Overlay fish replace
+    /// fish with the same id, so corrected windows or conditions win;
+    /// regions, holes and items already present are kept. Returns what
+    /// collided.
+    pub fn merge(&mut self, overlay: FishData) -> Vec<MergeConflict> {
+        let mut conflicts = vec![];
+        let mut mismatch = |id, base: &str, overlay: &str| {
+            if base != overlay {
+                conflicts.push(MergeConflict::NameMismatch {
+                    id,
+                    base: base.to_string(),
+                    overlay: overlay.to_string(),
+                });
+            }
+        };
+        for region in overlay.regions {
+            match self.region_by_id(region.id()) {
+                Some(base) => mismatch(region.id(), base.name(), region.name()),
+                // Shares the forecast of a known region with the same rates.
+                // The id is new, so this cannot fail.
+                None => _ = self.add_region(Rc::unwrap_or_clone(region)),
+            }
+        }
+        for hole in overlay.fishing_holes {
+            match self.hole_by_id(hole.id()) {
+                Some(base) => mismatch(hole.id(), base.name(), hole.name()),
+                None => {
+                    // Point the hole at this data's region with the same id.
+                    let region = self
+                        .region_by_id(hole.region().id())
+                        .cloned()
+                        .unwrap_or(hole.region().clone());
+                    self.fishing_holes.push(Rc::new(
+                        FishingHole::new(hole.id(), hole.name.clone(), region)
+                            .with_localized_name(hole.localized_name.clone()),
+                    ));
+                }
+            }
+        }
+        for area in overlay.areas {
+            match self.area_by_id(area.id()) {
+                Some(base) => mismatch(area.id(), base.name(), area.name()),
+                None => self.areas.push(area),
+            }
+        }
+        for item in overlay.items {
+            match self.item_by_id(item.id()) {
+                Some(base) => mismatch(item.id(), base.name(), item.name()),
+                None => self.items.push(item),
+            }
+        }
+        for mut fish in overlay.fishes {
+            if let Some(hole) = self.hole_by_id(fish.location.id()) {
+                fish.location = hole.clone();
+            }
+            match self.fishes.iter().position(|f| f.id == fish.id) {
+                Some(i) => {
+                    conflicts.push(MergeConflict::FishReplaced(fish.id));
+                    self.fishes[i] = fish;
+                }
+                None => self.fishes.push(fish),
+            }
+        }
+        for (id, name) in overlay.weather_names {
+            self.weather_names.entry(id).or_insert(name);
+        }
+        for (id, name) in overlay.localized_items {
+            self.localized_items.entry(id).or_insert(name);
         }
+        for (id, name) in overlay.localized_weathers {
+            self.localized_weathers.entry(id).or_insert(name);
+        }
+        for book in overlay.folklore_books {
+            if !self.folklore_books.iter().any(|b| b.id == book.id) {
+                self.folklore_books.push(book);
+            }
+        }
+        self.provisioning.merge(overlay.provisioning);
+        self.bite_times.merge(overlay.bite_times);
+        conflicts
+    }
+    /// Removes a fish and its item, unless another fish is mooched with it.
+    pub fn remove_fish(&mut self, id: u32) -> Option<Fish> {
+        let position = self.fishes.iter().position(|f| f.id == id)?;
+        let fish = self.fishes.remove(position);
+        self.remove_orphaned_items();
+        Some(fish)
+    }
+    /// Keeps only the fish `keep` returns true for, e.g. the ones still to
+    /// catch. Items of removed fish go too, unless a kept fish is mooched
+    /// with them.
+    pub fn retain_fishes(&mut self, keep: impl FnMut(&Fish) -> bool) {
+        self.fishes.retain(keep);
+        self.remove_orphaned_items();
+    }
+    /// Drops fish items that are neither a fish nor mooch bait anymore.
+    fn remove_orphaned_items(&mut self) {
+        let used: HashSet<u32> = self
+            .fishes
+            .iter()
+            .flat_map(|f| match f.bait {
+                Bait::Mooch(id) => vec![f.id, id],
+                _ => vec![f.id],
+            })
+            .collect();
+        self.items.retain(|item| match item {
+            FishingItem::Fish(item) => used.contains(&item.id()),
+            FishingItem::Bait(_) => true,
+        });
     }
     pub fn item_by_id(&self, id: u32) -> Option<&FishingItem> {
         self.items.iter().find(|item| item.id() == id)
@@ -312,6 +1147,115 @@ impl FishData {
     pub fn fishes(&self) -> &Vec<Fish> {
         &self.fishes
     }
+    pub fn fishing_holes(&self) -> &Vec<Rc<FishingHole>> {
+        &self.fishing_holes
+    }
+    pub fn regions(&self) -> &Vec<Rc<Region>> {
+        &self.regions
+    }
+    pub fn hole_by_id(&self, id: u32) -> Option<&Rc<FishingHole>> {
+        self.fishing_holes.iter().find(|h| h.id() == id)
+    }
+    pub fn region_by_id(&self, id: u32) -> Option<&Rc<Region>> {
+        self.regions.iter().find(|r| r.id() == id)
+    }
+    pub fn holes_in_region(&self, region_id: u32) -> Vec<&Rc<FishingHole>> {
+        self.fishing_holes
+            .iter()
+            .filter(|h| h.region().id() == region_id)
+            .collect()
+    }
+    pub fn areas(&self) -> &Vec<Area> {
+        &self.areas
+    }
+    pub fn area_by_id(&self, id: u32) -> Option<&Area> {
+        self.areas.iter().find(|a| a.id() == id)
+    }
+    pub fn regions_in_area(&self, area_id: u32) -> Vec<&Rc<Region>> {
+        self.regions
+            .iter()
+            .filter(|r| r.area_id() == Some(area_id))
+            .collect()
+    }
+    pub fn area_of(&self, region: &Region) -> Option<&Area> {
+        self.area_by_id(region.area_id()?)
+    }
+    /// Where `hole` is, e.g. "La Noscea > Western La Noscea > Swiftperch".
+    /// The area is left out if it is unknown.
+    pub fn location_path(&self, hole: &FishingHole, lang: Lang) -> String {
+        let region = hole.region();
+        let mut path = vec![];
+        if let Some(area) = self.area_of(region) {
+            path.push(area.localized_name(lang));
+        }
+        path.push(region.localized_name(lang));
+        path.push(hole.localized_name(lang));
+        path.join(" > ")
+    }
+    pub fn fishes_at_hole(&self, hole_id: u32) -> Vec<&Fish> {
+        self.fishes
+            .iter()
+            .filter(|f| f.location.id() == hole_id)
+            .collect()
+    }
+    /// Requirements of `fish` with the full bait chain: the bait first, then
+    /// every fish to mooch up to `fish`.
+    pub fn requirements<'a>(&self, fish: &'a Fish) -> Requirements<'a> {
+        let mut requirements = fish.requirements();
+        let mut bait = fish.bait_id();
+        requirements.bait_chain.clear();
+        while let Some(id) = bait {
+            // Guard against cycles in broken data.
+            if requirements.bait_chain.contains(&id) {
+                break;
+            }
+            requirements.bait_chain.insert(0, id);
+            bait = self.fish_by_id(id).and_then(|f| f.bait_id());
+        }
+        requirements
+    }
+    /// Predators to catch for intuition before `fish` bites, with the
+    /// number of each. Predators missing from the data are skipped.
+    pub fn predators(&self, fish: &Fish) -> Vec<(u8, &Fish)> {
+        fish.intuition.as_ref().map_or(vec![], |i| {
+            i.requirements()
+                .iter()
+                .filter_map(|(count, id)| Some((*count, self.fish_by_id(*id)?)))
+                .collect()
+        })
+    }
+    pub fn folklore_books(&self) -> &Vec<FolkloreBook> {
+        &self.folklore_books
+    }
+    pub fn provisioning(&self) -> &ProvisioningSchedule {
+        &self.provisioning
+    }
+    pub fn bite_times(&self) -> &BiteTimes {
+        &self.bite_times
+    }
+    /// The newest patch any fish was added in.
+    pub fn latest_patch(&self) -> Option<(u8, u8)> {
+        self.fishes.iter().map(|f| f.patch).max()
+    }
+    pub fn weather_name(&self, weather: &Weather) -> String {
+        self.weather_names
+            .get(&weather.id())
+            .cloned()
+            .unwrap_or(weather.to_string())
+    }
+    /// The weather's name in `lang`, falling back to `weather_name`.
+    pub fn localized_weather_name(&self, weather: &Weather, lang: Lang) -> String {
+        self.localized_weathers
+            .get(&weather.id())
+            .map_or(self.weather_name(weather), |n| n.get(lang).to_string())
+    }
+    /// The item's name in `lang`, falling back to its English name.
+    pub fn localized_item_name(&self, id: u32, lang: Lang) -> Option<&str> {
+        match self.localized_items.get(&id) {
+            Some(name) => Some(name.get(lang)),
+            None => self.item_by_id(id).map(|i| i.item().name()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -322,15 +1266,13 @@ mod tests {
     pub fn next_window() {
         let weather = WeatherForecast::new(
             "Region".to_string(),
-            vec![(50, Weather::Clouds), (100, Weather::Sunny)],
+            vec![(50, Weather::CLOUDS), (100, Weather::RAIN)],
+        );
+        let fishing_hole = FishingHole::new(
+            0,
+            "Fishing Hole".to_string(),
+            Rc::new(Region::new(0, "Region".to_string(), weather)),
         );
-        let fishing_hole = FishingHole {
-            name: "Fishing Hole".to_string(),
-            region: Rc::new(Region {
-                name: "Region".to_string(),
-                weather,
-            }),
-        };
         let fish = Fish {
             id: 0,
             name: "".to_string(),
@@ -338,8 +1280,8 @@ mod tests {
             window_start: EorzeaDuration::new(1, 0, 0).unwrap(),
             window_end: EorzeaDuration::new(2, 0, 0).unwrap(),
             bait: Bait::Bait(0),
-            previous_weather_set: vec![Weather::Clouds],
-            weather_set: vec![Weather::Clouds],
+            previous_weather_set: vec![Weather::CLOUDS],
+            weather_set: vec![Weather::CLOUDS],
             tug: Tug::Light,
             hookset: Hookset::Precision,
             intuition: None,
@@ -348,29 +1290,90 @@ mod tests {
             folklore: false,
             fish_eyes: false,
             patch: (7, 0),
-            lure: Lure::Moderate,
-            lure_proc: false,
+            big_fish: false,
+            lure: None,
         };
         let result = fish
-            .next_window(EorzeaTime::new(1, 1, 2, 2, 0, 0).unwrap(), false, 1000)
+            .next_window(
+                &WindowQuery::new(EorzeaTime::new(1, 1, 2, 2, 0, 0).unwrap())
+                    .horizon(SearchHorizon::Periods(1000)),
+            )
             .unwrap();
         assert_eq!(result.start(), EorzeaTime::new(1, 1, 3, 1, 0, 0).unwrap());
         assert_eq!(result.end(), EorzeaTime::new(1, 1, 3, 2, 0, 0).unwrap());
     }
 
+    #[test]
+    pub fn next_window_query() {
+        let weather = WeatherForecast::new(
+            "Region".to_string(),
+            vec![(50, Weather::CLOUDS), (100, Weather::RAIN)],
+        );
+        let region = Rc::new(Region::new(0, "Region".to_string(), weather));
+        let mut fish = Fish::new(
+            0,
+            "".to_string(),
+            Rc::new(FishingHole::new(0, "Fishing Hole".to_string(), region)),
+            EorzeaDuration::new(1, 0, 0).unwrap(),
+            EorzeaDuration::new(2, 0, 0).unwrap(),
+            Bait::Bait(0),
+            vec![Weather::CLOUDS],
+            vec![Weather::CLOUDS],
+            Tug::Light,
+            Hookset::Precision,
+            None,
+            None,
+            false,
+            false,
+            false,
+            false,
+            (3, 0),
+            false,
+        );
+        let start = EorzeaTime::new(1, 1, 2, 2, 0, 0).unwrap();
+
+        let result = fish
+            .next_window(&WindowQuery::new(start).assume_fish_eyes(true))
+            .unwrap();
+        assert_eq!(result.start(), EorzeaTime::new(1, 1, 2, 16, 0, 0).unwrap());
+        assert_eq!(result.end(), EorzeaTime::new(1, 1, 3, 0, 0, 0).unwrap());
+
+        fish.big_fish = true;
+        let result = fish
+            .next_window(&WindowQuery::new(start).assume_fish_eyes(true))
+            .unwrap();
+        assert_eq!(result.start(), EorzeaTime::new(1, 1, 3, 1, 0, 0).unwrap());
+
+        let result = fish
+            .next_window(
+                &WindowQuery::new(start).required_weather_override(vec![], vec![Weather::RAIN]),
+            )
+            .unwrap();
+        assert_eq!(result.start(), EorzeaTime::new(1, 1, 4, 1, 0, 0).unwrap());
+        assert_eq!(
+            fish.location.region().weather().weather_at(result.start()),
+            &Weather::RAIN
+        );
+
+        assert_eq!(
+            fish.next_window(&WindowQuery::new(start).horizon(SearchHorizon::Periods(2))),
+            Err(NoWindow {
+                until: EorzeaTime::new(1, 1, 2, 16, 0, 0).unwrap()
+            })
+        );
+    }
+
     #[test]
     pub fn next_window_weather_border() {
         let weather = WeatherForecast::new(
             "Region".to_string(),
-            vec![(50, Weather::Clouds), (100, Weather::Sunny)],
+            vec![(50, Weather::CLOUDS), (100, Weather::RAIN)],
+        );
+        let fishing_hole = FishingHole::new(
+            0,
+            "Fishing Hole".to_string(),
+            Rc::new(Region::new(0, "Region".to_string(), weather)),
         );
-        let fishing_hole = FishingHole {
-            name: "Fishing Hole".to_string(),
-            region: Rc::new(Region {
-                name: "Region".to_string(),
-                weather,
-            }),
-        };
         let fish = Fish {
             id: 0,
             name: "".to_string(),
@@ -378,8 +1381,8 @@ mod tests {
             window_start: EorzeaDuration::new(7, 30, 0).unwrap(),
             window_end: EorzeaDuration::new(8, 30, 0).unwrap(),
             bait: Bait::Bait(0),
-            previous_weather_set: vec![Weather::Clouds],
-            weather_set: vec![Weather::Clouds],
+            previous_weather_set: vec![Weather::CLOUDS],
+            weather_set: vec![Weather::CLOUDS],
             tug: Tug::Light,
             hookset: Hookset::Precision,
             snagging: false,
@@ -387,12 +1390,15 @@ mod tests {
             folklore: false,
             fish_eyes: false,
             patch: (7, 0),
+            big_fish: false,
             intuition: None,
-            lure: Lure::Moderate,
-            lure_proc: false,
+            lure: None,
         };
         let result = fish
-            .next_window(EorzeaTime::new(1, 1, 2, 0, 0, 0).unwrap(), false, 1000)
+            .next_window(
+                &WindowQuery::new(EorzeaTime::new(1, 1, 2, 0, 0, 0).unwrap())
+                    .horizon(SearchHorizon::Periods(1000)),
+            )
             .unwrap();
         assert_eq!(result.start(), EorzeaTime::new(1, 1, 3, 7, 30, 0).unwrap());
         assert_eq!(result.end(), EorzeaTime::new(1, 1, 3, 8, 0, 0).unwrap());
@@ -402,15 +1408,13 @@ mod tests {
     pub fn next_window_day_border() {
         let weather = WeatherForecast::new(
             "Region".to_string(),
-            vec![(50, Weather::Clouds), (100, Weather::Sunny)],
+            vec![(50, Weather::CLOUDS), (100, Weather::RAIN)],
+        );
+        let fishing_hole = FishingHole::new(
+            0,
+            "Fishing Hole".to_string(),
+            Rc::new(Region::new(0, "Region".to_string(), weather)),
         );
-        let fishing_hole = FishingHole {
-            name: "Fishing Hole".to_string(),
-            region: Rc::new(Region {
-                name: "Region".to_string(),
-                weather,
-            }),
-        };
         let fish = Fish {
             id: 0,
             name: "".to_string(),
@@ -418,8 +1422,8 @@ mod tests {
             window_start: EorzeaDuration::new(23, 30, 0).unwrap(),
             window_end: EorzeaDuration::new(1, 0, 0).unwrap(),
             bait: Bait::Bait(0),
-            previous_weather_set: vec![Weather::Clouds],
-            weather_set: vec![Weather::Clouds],
+            previous_weather_set: vec![Weather::CLOUDS],
+            weather_set: vec![Weather::CLOUDS],
             tug: Tug::Light,
             hookset: Hookset::Precision,
             snagging: false,
@@ -427,14 +1431,325 @@ mod tests {
             folklore: false,
             fish_eyes: false,
             patch: (7, 0),
+            big_fish: false,
             intuition: None,
-            lure: Lure::Moderate,
-            lure_proc: false,
+            lure: None,
         };
+        // The tail of the window that started the evening before.
         let result = fish
-            .next_window(EorzeaTime::new(1, 1, 3, 0, 0, 0).unwrap(), false, 1_000)
+            .next_window(
+                &WindowQuery::new(EorzeaTime::new(1, 1, 3, 0, 0, 0).unwrap())
+                    .horizon(SearchHorizon::Periods(1_000)),
+            )
+            .unwrap();
+        assert_eq!(result.start(), EorzeaTime::new(1, 1, 3, 0, 0, 0).unwrap());
+        assert_eq!(result.end(), EorzeaTime::new(1, 1, 3, 1, 0, 0).unwrap());
+
+        let result = fish
+            .next_window(
+                &WindowQuery::new(EorzeaTime::new(1, 1, 3, 1, 0, 0).unwrap())
+                    .horizon(SearchHorizon::Periods(1_000)),
+            )
             .unwrap();
         assert_eq!(result.start(), EorzeaTime::new(1, 1, 4, 23, 30, 0).unwrap());
         assert_eq!(result.end(), EorzeaTime::new(1, 1, 5, 0, 0, 0).unwrap());
+
+        assert_eq!(fish.uptime(), 1.5 / 24.0 * 0.5 * 0.5);
+        assert_eq!(fish.rarity(), 64.0);
+        assert!(!fish.always_available());
+    }
+
+    #[test]
+    fn expansion_from_patch() {
+        assert_eq!(Expansion::from_patch((2, 5)), Expansion::ARealmReborn);
+        assert_eq!(Expansion::from_patch((5, 55)), Expansion::Shadowbringers);
+        assert_eq!(Expansion::from_patch((7, 3)), Expansion::Dawntrail);
+    }
+
+    #[test]
+    fn icon_paths() {
+        assert_eq!(icon_path(27004), "ui/icon/027000/027004.tex");
+        assert_eq!(icon_path(60201), "ui/icon/060000/060201.tex");
+    }
+
+    #[test]
+    fn shared_forecasts() {
+        let mut data = FishData::default();
+        let forecast = |rates| WeatherForecast::new("".to_string(), rates);
+        let a = data
+            .add_region(Region::new(
+                1,
+                "A".to_string(),
+                forecast(vec![(100, Weather::FOG)]),
+            ))
+            .unwrap();
+        let b = data
+            .add_region(Region::new(
+                2,
+                "B".to_string(),
+                forecast(vec![(100, Weather::FOG)]),
+            ))
+            .unwrap();
+        let c = data
+            .add_region(Region::new(
+                3,
+                "C".to_string(),
+                forecast(vec![(100, Weather::RAIN)]),
+            ))
+            .unwrap();
+        assert!(Rc::ptr_eq(a.shared_weather(), b.shared_weather()));
+        assert!(!Rc::ptr_eq(a.shared_weather(), c.shared_weather()));
+    }
+
+    #[test]
+    pub fn custom_data() {
+        let mut data = FishData::default();
+        let weather = WeatherForecast::new("Region".to_string(), vec![(100, Weather::CLOUDS)]);
+        let region = Region::new(1, "Region".to_string(), weather);
+        let other_region = Rc::new(Region::new(
+            2,
+            "Other".to_string(),
+            region.weather().clone(),
+        ));
+        let region = data.add_region(region).unwrap();
+        assert_eq!(
+            data.add_fishing_hole(FishingHole::new(10, "Elsewhere".to_string(), other_region))
+                .err(),
+            Some(DataError::UnknownRegion(2))
+        );
+        let hole = data
+            .add_fishing_hole(FishingHole::new(10, "Hole".to_string(), region))
+            .unwrap();
+        let fish = |id, bait| {
+            Fish::new(
+                id,
+                format!("Fish {}", id),
+                hole.clone(),
+                EorzeaDuration::new(0, 0, 0).unwrap(),
+                EorzeaDuration::new(0, 0, 0).unwrap(),
+                bait,
+                vec![],
+                vec![],
+                Tug::Light,
+                Hookset::Precision,
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                (7, 0),
+                false,
+            )
+        };
+        assert_eq!(
+            data.add_fish(fish(100, Bait::Bait(50))),
+            Err(DataError::UnknownItem(50))
+        );
+        data.add_item(Item::new(50, "Worm".to_string(), 0, 1))
+            .unwrap();
+        data.add_fish(fish(100, Bait::Bait(50))).unwrap();
+        data.add_fish(fish(101, Bait::Mooch(100))).unwrap();
+        assert_eq!(
+            data.add_fish(fish(101, Bait::Unknown)),
+            Err(DataError::DuplicateId(101))
+        );
+        assert_eq!(
+            data.requirements(data.fish_by_id(101).unwrap()).bait_chain,
+            [50, 100]
+        );
+        data.retain_fishes(|f| f.id != 100);
+        assert_eq!(data.fishes().len(), 1);
+        // 101 is still mooched with 100.
+        assert!(data.item_by_id(100).is_some());
+        assert!(data.remove_fish(101).is_some());
+        assert!(data.item_by_id(100).is_none());
+        assert!(data.item_by_id(101).is_none());
+        assert!(data.item_by_id(50).is_some());
+        data.add_fish(fish(101, Bait::Bait(50))).unwrap();
+        assert!(matches!(data.item_by_id(101), Some(FishingItem::Fish(_))));
+    }
+
+    /// A fish biting from `window_start` to `window_end` bells in any weather.
+    fn daily_fish(window_start: u8, window_end: u8) -> Fish {
+        let weather = WeatherForecast::new(
+            "Region".to_string(),
+            vec![(50, Weather::CLOUDS), (100, Weather::RAIN)],
+        );
+        let region = Rc::new(Region::new(0, "Region".to_string(), weather));
+        Fish::new(
+            0,
+            "".to_string(),
+            Rc::new(FishingHole::new(0, "Fishing Hole".to_string(), region)),
+            EorzeaDuration::new(window_start, 0, 0).unwrap(),
+            EorzeaDuration::new(window_end, 0, 0).unwrap(),
+            Bait::Unknown,
+            vec![],
+            vec![],
+            Tug::Unknown,
+            Hookset::Unknown,
+            None,
+            None,
+            false,
+            false,
+            false,
+            false,
+            (2, 0),
+            false,
+        )
+    }
+
+    #[test]
+    pub fn window_stats() {
+        let start = EorzeaTime::new(1000, 1, 1, 0, 0, 0).unwrap();
+        let horizon = SearchHorizon::Eorzea(EorzeaDuration::from_suns(10));
+        // Two bells a day, across no period boundary.
+        let stats = daily_fish(1, 3).window_stats_from(start, horizon).unwrap();
+        assert_eq!(stats.windows, 10);
+        assert_eq!(
+            stats.min_length,
+            EorzeaDuration::new(2, 0, 0).unwrap().to_real()
+        );
+        assert_eq!(stats.max_length, stats.min_length);
+        assert_eq!(
+            stats.longest_gap,
+            EorzeaDuration::new(22, 0, 0).unwrap().to_real()
+        );
+        // 70 minutes of real time per Eorzea day.
+        assert!((stats.windows_per_real_day - 24.0 * 60.0 / 70.0).abs() < 1e-9);
+        // 6AM to 10AM crosses the 8AM boundary but is one window.
+        let stats = daily_fish(6, 10).window_stats_from(start, horizon).unwrap();
+        assert_eq!(stats.windows, 10);
+        assert_eq!(
+            stats.average_length,
+            EorzeaDuration::new(4, 0, 0).unwrap().to_real()
+        );
+    }
+
+    #[test]
+    pub fn windows_around() {
+        let day = |sun, bell| EorzeaTime::new(1000, 1, sun, bell, 0, 0).unwrap();
+        let fish = daily_fish(6, 10);
+        let windows = fish.windows_around(day(10, 7), 2, 2);
+        let starts: Vec<EorzeaTime> = windows.iter().map(|w| w.start()).collect();
+        // The window open at 7AM counts as a past one.
+        assert_eq!(starts, vec![day(9, 6), day(10, 6), day(11, 6), day(12, 6)]);
+        assert!(
+            windows
+                .iter()
+                .all(|w| w.end() == w.start() + EorzeaDuration::new(4, 0, 0).unwrap())
+        );
+        assert_eq!(fish.windows_around(day(10, 12), 0, 1).len(), 1);
+        assert_eq!(fish.windows_around(day(10, 12), 3, 0).len(), 3);
+    }
+
+    #[test]
+    pub fn merge() {
+        let data = |hole_name: &str, fish: &[(u32, u8)]| {
+            let mut data = FishData::default();
+            let weather = WeatherForecast::new("Region".to_string(), vec![(100, Weather::CLOUDS)]);
+            let region = data
+                .add_region(Region::new(1, "Region".to_string(), weather))
+                .unwrap();
+            let hole = data
+                .add_fishing_hole(FishingHole::new(10, hole_name.to_string(), region))
+                .unwrap();
+            for (id, start) in fish {
+                let start = EorzeaDuration::new(*start, 0, 0).unwrap();
+                data.add_fish(Fish::new(
+                    *id,
+                    format!("Fish {}", id),
+                    hole.clone(),
+                    start,
+                    start,
+                    Bait::Unknown,
+                    vec![],
+                    vec![],
+                    Tug::Light,
+                    Hookset::Precision,
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    (7, 0),
+                    false,
+                ))
+                .unwrap();
+            }
+            data
+        };
+        let mut base = data("Hole", &[(100, 0), (101, 0)]);
+        let conflicts = base.merge(data("Renamed", &[(101, 5), (102, 0)]));
+        assert_eq!(
+            conflicts,
+            [
+                MergeConflict::NameMismatch {
+                    id: 10,
+                    base: "Hole".to_string(),
+                    overlay: "Renamed".to_string()
+                },
+                MergeConflict::FishReplaced(101)
+            ]
+        );
+        assert_eq!(base.fishes().len(), 3);
+        let replaced = base.fish_by_id(101).unwrap();
+        assert_eq!(replaced.start(), &EorzeaDuration::new(5, 0, 0).unwrap());
+        assert!(Rc::ptr_eq(&replaced.location, &base.fishing_holes()[0]));
+
+        let mut overlay = FishData::default();
+        let weather = WeatherForecast::new("Other".to_string(), vec![(100, Weather::CLOUDS)]);
+        overlay
+            .add_region(Region::new(2, "Other".to_string(), weather))
+            .unwrap();
+        base.merge(overlay);
+        let regions = base.regions();
+        assert_eq!(regions.len(), 2);
+        assert!(Rc::ptr_eq(&regions[0].weather, &regions[1].weather));
+    }
+
+    #[test]
+    pub fn why_not_open() {
+        use crate::fixtures::*;
+        let data = fixture_data();
+        let at = |sun, bell| EorzeaTime::new(1000, 1, sun, bell, 0, 0).unwrap();
+        let fish = |id| data.fish_by_id(id).unwrap();
+        assert!(fish(DAWN_PERCH).why_not_open(at(1, 5)).is_empty());
+        assert_eq!(
+            fish(DAWN_PERCH).why_not_open(at(1, 8)),
+            [Reason::WrongHours {
+                start: EorzeaDuration::new(4, 0, 0).unwrap(),
+                end: EorzeaDuration::new(8, 0, 0).unwrap(),
+            }]
+        );
+        assert_eq!(
+            fish(RAIN_CARP).why_not_open(at(1, 16)),
+            [Reason::WrongWeather {
+                current: Weather::CLOUDS,
+                needed: vec![Weather::RAIN],
+            }]
+        );
+        let intuition = Reason::IntuitionUnmet {
+            predators: vec![(2, RAIN_CARP)],
+        };
+        assert_eq!(
+            fish(CLEARING_PIKE).why_not_open(at(1, 19)),
+            std::slice::from_ref(&intuition)
+        );
+        assert_eq!(
+            fish(CLEARING_PIKE).why_not_open(at(2, 3)),
+            [
+                Reason::WrongHours {
+                    start: EorzeaDuration::new(18, 0, 0).unwrap(),
+                    end: EorzeaDuration::new(22, 0, 0).unwrap(),
+                },
+                Reason::WrongPreviousWeather {
+                    previous: Weather::CLOUDS,
+                    needed: vec![Weather::RAIN],
+                },
+                intuition,
+            ]
+        );
     }
 }
@@ -0,0 +1,257 @@
+//! A small hand-made data set for tests that should not depend on the
+//! Carbuncle Plushy data, which changes with every patch.
+//!
+//! One area with two regions, a fishing hole in each, a bait and five fish:
+//!
+//! | Id     | Fish           | Hole       | Bells | Weather                 |
+//! |--------|----------------|------------|-------|-------------------------|
+//! | `1001` | Anytime Sprat  | Quiet Pier | any   | any                     |
+//! | `1002` | Dawn Perch     | Quiet Pier | 4-8   | any                     |
+//! | `1003` | Night Eel      | Quiet Pier | 22-2  | any                     |
+//! | `1004` | Rain Carp      | Misty Bank | any   | Rain                    |
+//! | `1005` | Clearing Pike  | Misty Bank | 18-22 | Clouds after Rain       |
+//!
+//! Quiet Pier always has Fair Skies. Misty Bank has Rain or Clouds, half the
+//! time each. From [`epoch`], the first sun of the first moon of year 1000,
+//! Misty Bank's weather is:
+//!
+//! | Sun | 0:00   | 8:00 | 16:00  |
+//! |-----|--------|------|--------|
+//! | 1   | Rain   | Rain | Clouds |
+//! | 2   | Clouds | Rain | Clouds |
+//! | 3   | Clouds | Rain | Clouds |
+//!
+//! `Fish::next_window` splits windows at weather changes, so searching from
+//! the epoch, and then from the end of each window found, gives:
+//!
+//! | Fish          | Windows                                    |
+//! |---------------|--------------------------------------------|
+//! | Dawn Perch    | 1 4:00-8:00, 2 4:00-8:00, 3 4:00-8:00       |
+//! | Night Eel     | 1 0:00-2:00, 1 22:00-24:00, 2 0:00-2:00     |
+//! | Rain Carp     | 1 0:00-8:00, 1 8:00-16:00, 2 8:00-16:00     |
+//! | Clearing Pike | 1 18:00-22:00, 2 18:00-22:00, 3 18:00-22:00 |
+
+use std::{rc::Rc, time::Duration};
+
+use crate::{
+    eorzea_time::{EorzeaDuration, EorzeaTime},
+    fish::{Area, Bait, Fish, FishData, FishingHole, Hookset, Intuition, Item, Region, Tug},
+    lang::LocalizedName,
+    weather::{Weather, WeatherForecast},
+};
+
+pub const AREA: u32 = 1;
+pub const QUIET_PIER: u32 = 1;
+pub const MISTY_BANK: u32 = 2;
+pub const WORM: u32 = 100;
+pub const ANYTIME_SPRAT: u32 = 1001;
+pub const DAWN_PERCH: u32 = 1002;
+pub const NIGHT_EEL: u32 = 1003;
+pub const RAIN_CARP: u32 = 1004;
+pub const CLEARING_PIKE: u32 = 1005;
+
+/// The time the windows above are listed from.
+pub fn epoch() -> EorzeaTime {
+    EorzeaTime::new(1000, 1, 1, 0, 0, 0).unwrap()
+}
+
+fn english(name: &str) -> LocalizedName {
+    LocalizedName {
+        en: name.to_string(),
+        ..Default::default()
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn fish(
+    id: u32,
+    name: &str,
+    location: &Rc<FishingHole>,
+    bells: (u8, u8),
+    bait: Bait,
+    previous_weather_set: Vec<Weather>,
+    weather_set: Vec<Weather>,
+    intuition: Option<Intuition>,
+    big_fish: bool,
+) -> Fish {
+    Fish::new(
+        id,
+        name.to_string(),
+        Rc::clone(location),
+        EorzeaDuration::new(bells.0, 0, 0).unwrap(),
+        EorzeaDuration::new(bells.1, 0, 0).unwrap(),
+        bait,
+        previous_weather_set,
+        weather_set,
+        Tug::Light,
+        Hookset::Precision,
+        intuition,
+        None,
+        false,
+        false,
+        false,
+        false,
+        (6, 0),
+        big_fish,
+    )
+}
+
+/// The fixture data described in the module documentation.
+pub fn fixture_data() -> FishData {
+    let weather_names = [Weather::FAIR_SKIES, Weather::RAIN, Weather::CLOUDS]
+        .into_iter()
+        .map(|w| (w.id(), w.name().unwrap().to_string()))
+        .collect();
+    let mut data = FishData::new(vec![], vec![], vec![], vec![], weather_names, vec![])
+        .with_areas(vec![Area::new(AREA, english("Fixture Isles"))]);
+    let add_hole = |data: &mut FishData, id, name: &str, region: &str, rates| {
+        let forecast = WeatherForecast::new(region.to_string(), rates);
+        let region = Region::new(id, region.to_string(), forecast).with_area(AREA);
+        let region = data.add_region(region).unwrap();
+        data.add_fishing_hole(FishingHole::new(id, name.to_string(), region))
+            .unwrap()
+    };
+    let pier = add_hole(
+        &mut data,
+        QUIET_PIER,
+        "Quiet Pier",
+        "Fixture Coast",
+        vec![(100, Weather::FAIR_SKIES)],
+    );
+    let bank = add_hole(
+        &mut data,
+        MISTY_BANK,
+        "Misty Bank",
+        "Fixture Marsh",
+        vec![(50, Weather::RAIN), (100, Weather::CLOUDS)],
+    );
+    data.add_item(Item::new(WORM, "Fixture Worm".to_string(), 0, 1))
+        .unwrap();
+
+    let worm = || Bait::Bait(WORM);
+    let fishes = [
+        fish(
+            ANYTIME_SPRAT,
+            "Anytime Sprat",
+            &pier,
+            (0, 0),
+            worm(),
+            vec![],
+            vec![],
+            None,
+            false,
+        ),
+        fish(
+            DAWN_PERCH,
+            "Dawn Perch",
+            &pier,
+            (4, 8),
+            worm(),
+            vec![],
+            vec![],
+            None,
+            false,
+        ),
+        fish(
+            NIGHT_EEL,
+            "Night Eel",
+            &pier,
+            (22, 2),
+            Bait::Mooch(ANYTIME_SPRAT),
+            vec![],
+            vec![],
+            None,
+            false,
+        ),
+        fish(
+            RAIN_CARP,
+            "Rain Carp",
+            &bank,
+            (0, 0),
+            worm(),
+            vec![],
+            vec![Weather::RAIN],
+            None,
+            false,
+        ),
+        fish(
+            CLEARING_PIKE,
+            "Clearing Pike",
+            &bank,
+            (18, 22),
+            Bait::Mooch(RAIN_CARP),
+            vec![Weather::RAIN],
+            vec![Weather::CLOUDS],
+            Some(Intuition::new(
+                Duration::from_secs(60),
+                vec![(2, RAIN_CARP)],
+            )),
+            true,
+        ),
+    ];
+    for fish in fishes {
+        data.add_fish(fish).unwrap();
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eorzea_time::EorzeaTimeSpan, search::WindowQuery};
+
+    fn windows(data: &FishData, id: u32, n: usize) -> Vec<EorzeaTimeSpan> {
+        let fish = data.fish_by_id(id).unwrap();
+        let mut time = epoch();
+        (0..n)
+            .map(|_| {
+                let window = fish.next_window(&WindowQuery::new(time)).unwrap();
+                time = window.end();
+                window
+            })
+            .collect()
+    }
+
+    fn span(sun: u8, start: u8, end: u8) -> EorzeaTimeSpan {
+        let start = EorzeaTime::new(1000, 1, sun, start, 0, 0).unwrap();
+        EorzeaTimeSpan::new(
+            start,
+            EorzeaDuration::new(end - start.bell(), 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn weather_table() {
+        let data = fixture_data();
+        let forecast = data.region_by_id(MISTY_BANK).unwrap().weather();
+        let at =
+            |sun, bell| *forecast.weather_at(EorzeaTime::new(1000, 1, sun, bell, 0, 0).unwrap());
+        let (rain, clouds) = (Weather::RAIN, Weather::CLOUDS);
+        assert_eq!([at(1, 0), at(1, 8), at(1, 16)], [rain, rain, clouds]);
+        assert_eq!([at(2, 0), at(2, 8), at(2, 16)], [clouds, rain, clouds]);
+        assert_eq!([at(3, 0), at(3, 8), at(3, 16)], [clouds, rain, clouds]);
+    }
+
+    #[test]
+    fn window_table() {
+        let data = fixture_data();
+        assert_eq!(data.fishes().len(), 5);
+        assert!(data.fish_by_id(ANYTIME_SPRAT).unwrap().always_available());
+        assert_eq!(
+            windows(&data, DAWN_PERCH, 3),
+            [span(1, 4, 8), span(2, 4, 8), span(3, 4, 8)]
+        );
+        assert_eq!(
+            windows(&data, NIGHT_EEL, 3),
+            [span(1, 0, 2), span(1, 22, 24), span(2, 0, 2)]
+        );
+        assert_eq!(
+            windows(&data, RAIN_CARP, 3),
+            [span(1, 0, 8), span(1, 8, 16), span(2, 8, 16)]
+        );
+        assert_eq!(
+            windows(&data, CLEARING_PIKE, 3),
+            [span(1, 18, 22), span(2, 18, 22), span(3, 18, 22)]
+        );
+    }
+}
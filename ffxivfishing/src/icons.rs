@@ -0,0 +1,184 @@
+//! Icon images for front-ends: URLs of the game icons on public sites and,
+//! with the `net` feature, a downloader keeping them as PNG files.
+
+#[cfg(feature = "net")]
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::fish::{FishingItem, Item};
+#[cfg(feature = "net")]
+use crate::net::{Fetched, NetError, RateLimit, Transport};
+
+/// A site serving the game icons as PNG.
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub enum IconSource {
+    #[default]
+    Xivapi,
+    GarlandTools,
+}
+
+/// URL of the PNG for an icon id, e.g.
+/// `https://xivapi.com/i/027000/027004.png` for 27004 on XIVAPI.
+pub fn icon_url(icon: u32, source: IconSource) -> String {
+    match source {
+        IconSource::Xivapi => {
+            format!(
+                "https://xivapi.com/i/{:0>6}/{:0>6}.png",
+                icon / 1000 * 1000,
+                icon
+            )
+        }
+        IconSource::GarlandTools => {
+            format!("https://www.garlandtools.org/files/icons/item/{}.png", icon)
+        }
+    }
+}
+
+impl Item {
+    pub fn icon_url(&self, source: IconSource) -> String {
+        icon_url(self.icon(), source)
+    }
+}
+
+impl FishingItem {
+    /// Game path of the item's icon, see `fish::icon_path`.
+    pub fn icon_path(&self) -> String {
+        self.item().icon_path()
+    }
+    pub fn icon_url(&self, source: IconSource) -> String {
+        self.item().icon_url(source)
+    }
+}
+
+/// Downloads icons once into `dir` as `<icon id>.png`. Icons do not
+/// change, so a file that exists is never fetched again.
+#[cfg(feature = "net")]
+pub struct IconCache<T> {
+    transport: T,
+    dir: PathBuf,
+    source: IconSource,
+    limit: RateLimit,
+}
+
+#[cfg(feature = "net")]
+impl<T: Transport> IconCache<T> {
+    /// Fetches from XIVAPI, at most one icon a second.
+    pub fn new(transport: T, dir: impl Into<PathBuf>) -> IconCache<T> {
+        IconCache {
+            transport,
+            dir: dir.into(),
+            source: IconSource::default(),
+            limit: RateLimit::new(Duration::from_secs(1)),
+        }
+    }
+
+    pub fn source(mut self, source: IconSource) -> IconCache<T> {
+        self.source = source;
+        self
+    }
+
+    pub fn min_interval(mut self, interval: Duration) -> IconCache<T> {
+        self.limit = RateLimit::new(interval);
+        self
+    }
+
+    /// Where the icon is or will be stored.
+    pub fn file(&self, icon: u32) -> PathBuf {
+        self.dir.join(format!("{}.png", icon))
+    }
+
+    /// Path of the icon's PNG, downloading it first if needed.
+    pub async fn path(&self, icon: u32) -> Result<PathBuf, NetError> {
+        let path = self.file(icon);
+        if path.exists() {
+            return Ok(path);
+        }
+        self.limit.wait().await;
+        match self
+            .transport
+            .get(&icon_url(icon, self.source), None)
+            .await?
+        {
+            Fetched::Modified { body, .. } => {
+                write_file(&path, &body)?;
+                Ok(path)
+            }
+            Fetched::NotModified => Err(NetError::Status(304)),
+        }
+    }
+}
+
+/// Writes through a temporary file, so an interrupted download never
+/// leaves a truncated icon behind.
+#[cfg(feature = "net")]
+fn write_file(path: &Path, body: &[u8]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("png.tmp");
+    fs::write(&tmp, body)?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn urls() {
+        assert_eq!(
+            icon_url(27004, IconSource::Xivapi),
+            "https://xivapi.com/i/027000/027004.png"
+        );
+        assert_eq!(
+            icon_url(27004, IconSource::GarlandTools),
+            "https://www.garlandtools.org/files/icons/item/27004.png"
+        );
+        let bait = FishingItem::Bait(Item::new(2, "Bait".to_string(), 27004, 1));
+        assert_eq!(bait.icon_path(), "ui/icon/027000/027004.tex");
+    }
+
+    #[cfg(feature = "net")]
+    #[test]
+    fn downloads_once() {
+        use async_io::block_on;
+        use std::cell::Cell;
+
+        struct FakeServer {
+            requests: Cell<u32>,
+        }
+
+        impl Transport for FakeServer {
+            async fn get(&self, url: &str, _etag: Option<&str>) -> Result<Fetched, NetError> {
+                self.requests.set(self.requests.get() + 1);
+                Ok(Fetched::Modified {
+                    body: url.as_bytes().to_vec(),
+                    etag: None,
+                })
+            }
+        }
+
+        let dir = std::env::temp_dir().join(format!("ffxivfishing-icons-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let cache = IconCache::new(
+            FakeServer {
+                requests: Cell::new(0),
+            },
+            &dir,
+        )
+        .source(IconSource::GarlandTools)
+        .min_interval(Duration::ZERO);
+        let path = block_on(cache.path(27004)).unwrap();
+        assert_eq!(path, dir.join("27004.png"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            icon_url(27004, IconSource::GarlandTools)
+        );
+        block_on(cache.path(27004)).unwrap();
+        assert_eq!(cache.transport.requests.get(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,156 @@
+//! Property tests for the window and time math over generated fish.
+
+use std::{
+    rc::Rc,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use proptest::prelude::*;
+
+use crate::{
+    eorzea_time::{
+        EORZEA_SUN, EORZEA_WEATHER_PERIOD, EorzeaDuration, EorzeaTime, EorzeaTimeSpan, SUN_IN_ESEC,
+    },
+    fish::{Bait, Fish, FishingHole, Hookset, Region, Tug},
+    search::{SearchHorizon, WindowQuery},
+    weather::{Weather, WeatherForecast},
+};
+
+const SEARCH_PERIODS: u32 = 200;
+
+/// Weather rates over up to four weathers, as cumulative thresholds.
+fn forecast() -> impl Strategy<Value = WeatherForecast> {
+    prop::collection::btree_set(1u8..100, 0..4).prop_map(|thresholds| {
+        let rates = thresholds
+            .into_iter()
+            .chain([100])
+            .enumerate()
+            .map(|(i, n)| (n, Weather::from_id(i as u32 + 1)))
+            .collect();
+        WeatherForecast::new("Region".to_string(), rates)
+    })
+}
+
+fn weather_set() -> impl Strategy<Value = Vec<Weather>> {
+    prop::collection::vec((1u32..=5).prop_map(Weather::from_id), 0..3)
+}
+
+fn fish() -> impl Strategy<Value = Fish> {
+    (forecast(), 0u8..24, 0u8..24, weather_set(), weather_set()).prop_map(
+        |(forecast, start, end, previous, current)| {
+            let region = Rc::new(Region::new(1, "Region".to_string(), forecast));
+            let hole = Rc::new(FishingHole::new(1, "Hole".to_string(), region));
+            Fish::new(
+                1,
+                "Fish".to_string(),
+                hole,
+                EorzeaDuration::new(start, 0, 0).unwrap(),
+                EorzeaDuration::new(end, 0, 0).unwrap(),
+                Bait::Unknown,
+                previous,
+                current,
+                Tug::Unknown,
+                Hookset::Unknown,
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                (7, 0),
+                false,
+            )
+        },
+    )
+}
+
+fn query(time: EorzeaTime, include_ongoing: bool) -> WindowQuery {
+    WindowQuery::new(time)
+        .include_ongoing(include_ongoing)
+        .horizon(SearchHorizon::Periods(SEARCH_PERIODS))
+}
+
+/// From the second day on, since weather search looks one period back.
+fn query_time() -> impl Strategy<Value = EorzeaTime> {
+    (2 * SUN_IN_ESEC..40_000_000_000).prop_map(EorzeaTime::from_esecs)
+}
+
+/// Checks every weather period from `start` on, including windows that
+/// started the day before and wrap past midnight.
+fn brute_force_next_window(
+    fish: &Fish,
+    start: EorzeaTime,
+    include_ongoing: bool,
+    periods: u32,
+) -> Option<EorzeaTimeSpan> {
+    let forecast = fish.location.region().weather();
+    let matches = |set: &[Weather], weather| set.is_empty() || set.contains(weather);
+    let mut period = start.weather_period_start();
+    for _ in 0..periods {
+        if matches(
+            &fish.previous_weather_set,
+            forecast.weather_at(period - EORZEA_WEATHER_PERIOD),
+        ) && matches(&fish.weather_set, forecast.weather_at(period))
+        {
+            let weather_span = EorzeaTimeSpan::new(period, EORZEA_WEATHER_PERIOD);
+            for day in [period - EORZEA_SUN, period] {
+                if let Ok(window) = fish.window_on_day(day).overlap(&weather_span) {
+                    let min_window = match include_ongoing {
+                        true => window.end(),
+                        false => window.start(),
+                    };
+                    if start <= min_window && window.duration().total_seconds() > 0 {
+                        return Some(window);
+                    }
+                }
+            }
+        }
+        period += EORZEA_WEATHER_PERIOD;
+    }
+    None
+}
+
+proptest! {
+    #[test]
+    fn matches_brute_force_search(fish in fish(), time in query_time(), ongoing: bool) {
+        let window = fish.next_window(&query(time, ongoing)).ok();
+        let reference = brute_force_next_window(&fish, time, ongoing, SEARCH_PERIODS);
+        prop_assert_eq!(window, reference);
+    }
+
+    #[test]
+    fn window_ends_after_start(fish in fish(), time in query_time(), ongoing: bool) {
+        if let Ok(window) = fish.next_window(&query(time, ongoing)) {
+            prop_assert!(window.end() > window.start());
+        }
+    }
+
+    #[test]
+    fn window_not_before_query(fish in fish(), time in query_time()) {
+        if let Ok(window) = fish.next_window(&query(time, false)) {
+            prop_assert!(window.start() >= time);
+        }
+        if let Ok(window) = fish.next_window(&query(time, true)) {
+            prop_assert!(window.end() >= time);
+        }
+    }
+
+    #[test]
+    fn windows_do_not_overlap(fish in fish(), time in query_time()) {
+        let first = match fish.next_window(&query(time, false)) {
+            Ok(w) => w,
+            Err(_) => return Ok(()),
+        };
+        let after_start = first.start() + EorzeaDuration::from_esecs(1);
+        if let Ok(second) = fish.next_window(&query(after_start, false)) {
+            prop_assert!(second.start() >= first.end());
+        }
+    }
+
+    #[test]
+    fn real_time_round_trip(secs in 0u64..4_000_000_000) {
+        let real = UNIX_EPOCH + Duration::from_secs(secs);
+        let eorzea = EorzeaTime::from_time(&real).unwrap();
+        prop_assert_eq!(eorzea.to_system_time(), real);
+    }
+}
@@ -0,0 +1,81 @@
+/// The client languages the data has names for.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum Lang {
+    #[default]
+    English,
+    Japanese,
+    German,
+    French,
+    Korean,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 5] = [
+        Lang::English,
+        Lang::Japanese,
+        Lang::German,
+        Lang::French,
+        Lang::Korean,
+    ];
+
+    /// The two-letter code, as in the data's `name_en` fields.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lang::English => "en",
+            Lang::Japanese => "ja",
+            Lang::German => "de",
+            Lang::French => "fr",
+            Lang::Korean => "ko",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Lang> {
+        Lang::ALL
+            .into_iter()
+            .find(|l| l.code().eq_ignore_ascii_case(code))
+    }
+}
+
+/// A name in every client language.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct LocalizedName {
+    pub en: String,
+    pub ja: String,
+    pub de: String,
+    pub fr: String,
+    pub ko: String,
+}
+
+impl LocalizedName {
+    /// The name in `lang`, or the English one if that is missing.
+    pub fn get(&self, lang: Lang) -> &str {
+        let name = match lang {
+            Lang::English => &self.en,
+            Lang::Japanese => &self.ja,
+            Lang::German => &self.de,
+            Lang::French => &self.fr,
+            Lang::Korean => &self.ko,
+        };
+        match name.is_empty() {
+            true => &self.en,
+            false => name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn localized_name_falls_back_to_english() {
+        let name = LocalizedName {
+            en: "Silverfish".to_string(),
+            ja: "銀魚".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(name.get(Lang::Japanese), "銀魚");
+        assert_eq!(name.get(Lang::German), "Silverfish");
+        assert_eq!(Lang::from_code("DE"), Some(Lang::German));
+    }
+}
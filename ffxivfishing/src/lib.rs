@@ -1,4 +1,82 @@
+//! FFXIV fish data and the time and weather math behind fishing windows.
+//!
+//! - [`eorzea_time`]: Eorzea time, durations and spans, and conversion from
+//!   real time.
+//! - [`weather`]: weathers and zone forecasts.
+//! - [`weather_log`]: weathers seen in game, to check forecasts against.
+//! - [`fish`]: fish, fishing holes, regions, areas and the [`fish::FishData`]
+//!   collection, including window search.
+//! - [`carbuncledata`]: loading `FishData` from the Carbuncle Plushy data.
+//! - [`search`]: window queries and how far searches look ahead.
+//! - [`display`]: formatting times and windows in local, server or Eorzea
+//!   time.
+//! - [`export`]: the windows in a time range as CSV, iCalendar or JSON.
+//! - [`lang`]: client languages and localized names.
+//! - [`icons`]: URLs of item icons, and downloading them with `net`.
+//! - [`available`]: the fish that can be caught at a given time, and the
+//!   windows opening next.
+//! - [`summary`]: the canonical text describing a fish's conditions.
+//! - [`lookup`]: finding fish by id or a possibly misspelled name.
+//! - [`completion`]: fishing log progress for a set of caught fish.
+//! - [`provisioning`]: the daily Grand Company provisioning reset and the
+//!   fish it asks for.
+//! - [`bite`]: how long fish take to bite and how many casts fit into a
+//!   window.
+//! - [`watchlist`]: the fish a user follows.
+//! - [`planner`]: scheduling several target fish into one session.
+//! - [`sightseeing`]: sightseeing log vistas and when they can be logged.
+//! - [`conditions`]: windows of any weather and time of day condition, such
+//!   as hunt mark spawns.
+//! - `fixtures`: a small fixed data set for tests, with the
+//!   `test-fixtures` feature.
+//!
+//! [`prelude`] re-exports the types most users need.
+//!
+//! Cargo features, all on by default:
+//!
+//! - `serde`: serialization and the Carbuncle Plushy parser.
+//! - `carbuncle-data`: the embedded data and `carbuncle_fishes()`.
+//! - `chrono`: [`display`], [`export`] and the clock offset.
+//!
+//! Without them only the time, weather and window math is left, for
+//! callers bringing their own data.
+//!
+//! Off by default:
+//!
+//! - `test-fixtures`: adds `fixtures`.
+//! - `net`: adds `net`, HTTP caching and rate limiting for integrations,
+//!   and `icons::IconCache`.
+
+pub mod available;
+pub mod bite;
+#[cfg(feature = "serde")]
 pub mod carbuncledata;
+pub mod completion;
+pub mod conditions;
+#[cfg(feature = "chrono")]
+pub mod display;
 pub mod eorzea_time;
+#[cfg(feature = "chrono")]
+pub mod export;
 pub mod fish;
+#[cfg(any(test, feature = "test-fixtures"))]
+pub mod fixtures;
+pub mod icons;
+#[cfg(test)]
+mod invariants;
+pub mod lang;
+pub mod lookup;
+#[cfg(feature = "net")]
+pub mod net;
+pub mod planner;
+pub mod prelude;
+pub mod provisioning;
+pub mod search;
+pub mod sightseeing;
+pub mod summary;
+pub mod watchlist;
 pub mod weather;
+pub mod weather_log;
+
+/// This crate's version, for about screens and bug reports.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,101 @@
+use crate::{
+    fish::{Fish, FishData},
+    lang::Lang,
+};
+
+/// How `FishData::resolve_fish` matched a query.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NameMatch {
+    Id,
+    /// A name in any language, ignoring case.
+    Exact,
+    /// The closest name, `distance` typos away.
+    Fuzzy {
+        distance: usize,
+    },
+}
+
+/// Edits (insertions, deletions, substitutions) turning `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + (ca != *cb) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+impl FishData {
+    /// Finds the fish `query` stands for: its item id, its name in any
+    /// language, or failing that the closest name with at most one typo per
+    /// four characters. For pasted lists, where names are often misspelled.
+    pub fn resolve_fish(&self, query: &str) -> Option<(&Fish, NameMatch)> {
+        let query = query.trim();
+        if let Ok(id) = query.parse() {
+            return self.fish_by_id(id).map(|f| (f, NameMatch::Id));
+        }
+        let query = query.to_lowercase();
+        if query.is_empty() {
+            return None;
+        }
+        let names = |fish: &Fish| {
+            let mut names: Vec<String> = Lang::ALL
+                .iter()
+                .filter_map(|lang| self.localized_item_name(fish.id, *lang))
+                .map(str::to_lowercase)
+                .collect();
+            names.push(fish.name().to_lowercase());
+            names
+        };
+        if let Some(fish) = self.fishes().iter().find(|f| names(f).contains(&query)) {
+            return Some((fish, NameMatch::Exact));
+        }
+        let allowed = (query.chars().count() / 4).max(1);
+        self.fishes()
+            .iter()
+            .filter_map(|f| {
+                let distance = names(f)
+                    .iter()
+                    .map(|name| edit_distance(&query, name))
+                    .min()?;
+                Some((f, distance))
+            })
+            .filter(|(_, distance)| *distance <= allowed)
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(f, distance)| (f, NameMatch::Fuzzy { distance }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("carp", "carp"), 0);
+    }
+
+    #[test]
+    fn resolve() {
+        let data = fixture_data();
+        let resolve = |query| data.resolve_fish(query).map(|(fish, how)| (fish.id, how));
+        assert_eq!(resolve(" 1004 "), Some((RAIN_CARP, NameMatch::Id)));
+        assert_eq!(resolve("9999"), None);
+        assert_eq!(resolve("rain CARP"), Some((RAIN_CARP, NameMatch::Exact)));
+        assert_eq!(
+            resolve("Dawn Prech"),
+            Some((DAWN_PERCH, NameMatch::Fuzzy { distance: 2 }))
+        );
+        assert_eq!(resolve("Pike"), None);
+        assert_eq!(resolve(""), None);
+    }
+}
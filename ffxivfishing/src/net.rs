@@ -0,0 +1,238 @@
+//! Shared HTTP plumbing for integrations: a disk cache with ETag
+//! revalidation and a rate limit around whichever client does the
+//! requests, so every integration caches and throttles the same way.
+
+use std::{
+    cell::Cell,
+    fmt::Display,
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use async_io::Timer;
+
+/// What a `Transport` got back.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Modified {
+        body: Vec<u8>,
+        etag: Option<String>,
+    },
+    /// The server answered 304 to the ETag sent.
+    NotModified,
+}
+
+/// Does the actual GET requests for `CachedClient`.
+pub trait Transport {
+    /// Fetches `url`, sending `If-None-Match: etag` if there is one.
+    fn get(&self, url: &str, etag: Option<&str>)
+    -> impl Future<Output = Result<Fetched, NetError>>;
+}
+
+#[derive(Debug)]
+pub enum NetError {
+    Io(io::Error),
+    /// An HTTP status other than 200 and 304.
+    Status(u16),
+    Transport(String),
+}
+
+impl Display for NetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetError::Io(e) => write!(f, "cache error: {}", e),
+            NetError::Status(status) => write!(f, "HTTP status {}", status),
+            NetError::Transport(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for NetError {}
+
+impl From<io::Error> for NetError {
+    fn from(e: io::Error) -> Self {
+        NetError::Io(e)
+    }
+}
+
+/// Keeps requests at least `interval` apart.
+#[derive(Debug)]
+pub struct RateLimit {
+    interval: Duration,
+    next: Cell<Option<Instant>>,
+}
+
+impl RateLimit {
+    pub fn new(interval: Duration) -> RateLimit {
+        RateLimit {
+            interval,
+            next: Cell::new(None),
+        }
+    }
+
+    /// Waits until the next request may go out and reserves its slot.
+    pub async fn wait(&self) {
+        let now = Instant::now();
+        let slot = self.next.get().map_or(now, |next| next.max(now));
+        self.next.set(Some(slot + self.interval));
+        if slot > now {
+            Timer::at(slot).await;
+        }
+    }
+}
+
+/// GETs through a `Transport`, keeping responses in `cache_dir`. Fresh
+/// entries are served without a request, stale ones are revalidated with
+/// their ETag, and a cached copy is returned if the request fails.
+pub struct CachedClient<T> {
+    transport: T,
+    cache_dir: PathBuf,
+    max_age: Duration,
+    limit: RateLimit,
+}
+
+impl<T: Transport> CachedClient<T> {
+    /// Caches for an hour and sends at most one request a second.
+    pub fn new(transport: T, cache_dir: impl Into<PathBuf>) -> CachedClient<T> {
+        CachedClient {
+            transport,
+            cache_dir: cache_dir.into(),
+            max_age: Duration::from_secs(60 * 60),
+            limit: RateLimit::new(Duration::from_secs(1)),
+        }
+    }
+
+    /// How long a cached response is used without asking the server.
+    pub fn max_age(mut self, max_age: Duration) -> CachedClient<T> {
+        self.max_age = max_age;
+        self
+    }
+
+    pub fn min_interval(mut self, interval: Duration) -> CachedClient<T> {
+        self.limit = RateLimit::new(interval);
+        self
+    }
+
+    pub async fn get(&self, url: &str) -> Result<Vec<u8>, NetError> {
+        let body_path = self.cache_dir.join(cache_key(url));
+        let etag_path = body_path.with_extension("etag");
+        let cached = fs::read(&body_path).ok();
+        if let Some(body) = &cached
+            && age(&body_path).is_some_and(|age| age < self.max_age)
+        {
+            return Ok(body.clone());
+        }
+        let etag = match cached {
+            Some(_) => fs::read_to_string(&etag_path).ok(),
+            None => None,
+        };
+        self.limit.wait().await;
+        let fetched = self.transport.get(url, etag.as_deref()).await;
+        match (fetched, cached) {
+            (Ok(Fetched::Modified { body, etag }), _) => {
+                fs::create_dir_all(&self.cache_dir)?;
+                fs::write(&body_path, &body)?;
+                match etag {
+                    Some(etag) => fs::write(&etag_path, etag)?,
+                    None => _ = fs::remove_file(&etag_path),
+                }
+                Ok(body)
+            }
+            (Ok(Fetched::NotModified), Some(body)) => {
+                File::options()
+                    .append(true)
+                    .open(&body_path)?
+                    .set_modified(SystemTime::now())?;
+                Ok(body)
+            }
+            (Ok(Fetched::NotModified), None) => Err(NetError::Status(304)),
+            (Err(_), Some(body)) => Ok(body),
+            (Err(e), None) => Err(e),
+        }
+    }
+}
+
+fn age(path: &Path) -> Option<Duration> {
+    fs::metadata(path).ok()?.modified().ok()?.elapsed().ok()
+}
+
+/// File name for `url`, an FNV-1a hash so it stays the same across builds.
+fn cache_key(url: &str) -> String {
+    let hash = url.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_io::block_on;
+
+    struct FakeServer {
+        requests: Cell<u32>,
+        body: &'static str,
+        fail: bool,
+    }
+
+    impl Transport for FakeServer {
+        async fn get(&self, _url: &str, etag: Option<&str>) -> Result<Fetched, NetError> {
+            self.requests.set(self.requests.get() + 1);
+            match (self.fail, etag) {
+                (true, _) => Err(NetError::Transport("offline".to_string())),
+                (false, Some("v1")) => Ok(Fetched::NotModified),
+                (false, _) => Ok(Fetched::Modified {
+                    body: self.body.as_bytes().to_vec(),
+                    etag: Some("v1".to_string()),
+                }),
+            }
+        }
+    }
+
+    fn server(fail: bool) -> FakeServer {
+        FakeServer {
+            requests: Cell::new(0),
+            body: "data",
+            fail,
+        }
+    }
+
+    #[test]
+    fn caching() {
+        let dir = std::env::temp_dir().join(format!("ffxivfishing-net-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let url = "https://example.com/data.json";
+
+        let client = CachedClient::new(server(false), &dir).min_interval(Duration::ZERO);
+        assert_eq!(block_on(client.get(url)).unwrap(), b"data");
+        assert_eq!(block_on(client.get(url)).unwrap(), b"data");
+        assert_eq!(client.transport.requests.get(), 1);
+
+        // A stale entry is revalidated and kept on 304.
+        let client = client.max_age(Duration::ZERO);
+        assert_eq!(block_on(client.get(url)).unwrap(), b"data");
+        assert_eq!(client.transport.requests.get(), 2);
+
+        // Failing requests fall back to the cache.
+        let offline = CachedClient::new(server(true), &dir)
+            .max_age(Duration::ZERO)
+            .min_interval(Duration::ZERO);
+        assert_eq!(block_on(offline.get(url)).unwrap(), b"data");
+        assert!(block_on(offline.get("https://example.com/other")).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rate_limit() {
+        let limit = RateLimit::new(Duration::from_millis(30));
+        let start = Instant::now();
+        block_on(async {
+            for _ in 0..3 {
+                limit.wait().await;
+            }
+        });
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+}
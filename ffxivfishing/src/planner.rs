@@ -0,0 +1,214 @@
+use crate::{
+    eorzea_time::{EorzeaDuration, EorzeaTime, EorzeaTimeSpan},
+    fish::Fish,
+    search::{SearchHorizon, WindowQuery},
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PlanWarning {
+    /// The window is already open when arriving from the previous target.
+    LateArrival(EorzeaDuration),
+    /// Every window within the horizon collides with an earlier target.
+    Conflict,
+}
+
+#[derive(Debug)]
+pub struct PlanEntry<'a> {
+    pub fish: &'a Fish,
+    pub window: EorzeaTimeSpan,
+    pub warning: Option<PlanWarning>,
+}
+
+#[derive(Debug)]
+pub struct Plan<'a> {
+    pub entries: Vec<PlanEntry<'a>>,
+    /// Targets without any window inside the horizon.
+    pub unplanned: Vec<&'a Fish>,
+}
+
+pub struct Planner {
+    horizon: EorzeaDuration,
+    travel_buffer: EorzeaDuration,
+}
+
+impl Planner {
+    pub fn new(horizon: EorzeaDuration, travel_buffer: EorzeaDuration) -> Planner {
+        Planner {
+            horizon,
+            travel_buffer,
+        }
+    }
+
+    /// Orders `targets` into an itinerary starting at `start`.
+    ///
+    /// Targets are visited greedily by the earliest window that can still be
+    /// reached. Moving to a different fishing hole costs the travel buffer and
+    /// every target is fished until its window closes. Entries are returned in
+    /// visiting order, followed by the conflicting targets.
+    pub fn plan<'a>(&self, targets: &[&'a Fish], start: EorzeaTime) -> Plan<'a> {
+        let end = start + self.horizon;
+        let query = |time| {
+            WindowQuery::new(time)
+                .include_ongoing(true)
+                .horizon(SearchHorizon::Eorzea(self.horizon))
+        };
+        let mut remaining: Vec<&Fish> = targets.to_vec();
+        let mut entries = vec![];
+        let mut free_at = start;
+        let mut last_hole = None;
+
+        loop {
+            let next = remaining
+                .iter()
+                .enumerate()
+                .filter_map(|(i, fish)| {
+                    let arrival = match last_hole {
+                        Some(hole) if hole != fish.location.id() => free_at + self.travel_buffer,
+                        _ => free_at,
+                    };
+                    let mut window = fish.next_window(&query(arrival)).ok()?;
+                    if window.end() <= arrival {
+                        window = fish
+                            .next_window(&query(window.end() + EorzeaDuration::from_esecs(1)))
+                            .ok()?;
+                    }
+                    (window.start() < end).then_some((i, arrival, window))
+                })
+                .min_by_key(|(_, arrival, window)| (window.start().max(*arrival), window.end()));
+            let (i, arrival, window) = match next {
+                Some(n) => n,
+                None => break,
+            };
+            let fish = remaining.remove(i);
+            let warning = match arrival.duration_since(window.start().max(start)) {
+                Ok(missed) if missed.total_seconds() > 0 => Some(PlanWarning::LateArrival(missed)),
+                _ => None,
+            };
+            free_at = window.end();
+            last_hole = Some(fish.location.id());
+            entries.push(PlanEntry {
+                fish,
+                window,
+                warning,
+            });
+        }
+
+        let mut unplanned = vec![];
+        remaining
+            .sort_by_cached_key(|fish| fish.next_window(&query(start)).ok().map(|w| w.start()));
+        for fish in remaining {
+            match fish.next_window(&query(start)) {
+                Ok(window) if window.start() < end => entries.push(PlanEntry {
+                    fish,
+                    window,
+                    warning: Some(PlanWarning::Conflict),
+                }),
+                _ => unplanned.push(fish),
+            }
+        }
+        Plan { entries, unplanned }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::{
+        eorzea_time::EORZEA_SUN,
+        fish::{Bait, FishingHole, Hookset, Region, Tug},
+        weather::{Weather, WeatherForecast},
+    };
+
+    fn fish(id: u32, hole: &Rc<FishingHole>, start_bell: u8, end_bell: u8) -> Fish {
+        Fish::new(
+            id,
+            format!("Fish {}", id),
+            Rc::clone(hole),
+            EorzeaDuration::new(start_bell, 0, 0).unwrap(),
+            EorzeaDuration::new(end_bell, 0, 0).unwrap(),
+            Bait::Bait(0),
+            vec![],
+            vec![],
+            Tug::Light,
+            Hookset::Precision,
+            None,
+            None,
+            false,
+            false,
+            false,
+            false,
+            (7, 0),
+            false,
+        )
+    }
+
+    fn holes() -> (Rc<FishingHole>, Rc<FishingHole>) {
+        let region = Rc::new(Region::new(
+            0,
+            "Region".to_string(),
+            WeatherForecast::new("Region".to_string(), vec![(100, Weather::CLOUDS)]),
+        ));
+        (
+            Rc::new(FishingHole::new(1, "A".to_string(), Rc::clone(&region))),
+            Rc::new(FishingHole::new(2, "B".to_string(), region)),
+        )
+    }
+
+    #[test]
+    fn plan_orders_by_window() {
+        let (a, b) = holes();
+        let late = fish(1, &a, 6, 7);
+        let early = fish(2, &b, 2, 3);
+        let planner = Planner::new(EORZEA_SUN, EorzeaDuration::from_esecs(0));
+        let plan = planner.plan(&[&late, &early], EorzeaTime::new(1, 1, 2, 0, 0, 0).unwrap());
+        assert!(plan.unplanned.is_empty());
+        assert_eq!(
+            plan.entries.iter().map(|e| e.fish.id).collect::<Vec<u32>>(),
+            vec![2, 1]
+        );
+        assert!(plan.entries.iter().all(|e| e.warning.is_none()));
+    }
+
+    #[test]
+    fn plan_travel_buffer() {
+        let (a, b) = holes();
+        let first = fish(1, &a, 2, 3);
+        let second = fish(2, &b, 3, 4);
+        let same_hole = fish(3, &a, 3, 4);
+        let planner = Planner::new(EORZEA_SUN, EorzeaDuration::new(0, 30, 0).unwrap());
+        let start = EorzeaTime::new(1, 1, 2, 0, 0, 0).unwrap();
+
+        let plan = planner.plan(&[&first, &second], start);
+        assert_eq!(
+            plan.entries[1].warning,
+            Some(PlanWarning::LateArrival(
+                EorzeaDuration::new(0, 30, 0).unwrap()
+            ))
+        );
+
+        let plan = planner.plan(&[&first, &same_hole], start);
+        assert_eq!(plan.entries[1].warning, None);
+    }
+
+    #[test]
+    fn plan_conflict_and_unplanned() {
+        let (a, b) = holes();
+        let first = fish(1, &a, 2, 4);
+        let overlapping = fish(2, &b, 2, 4);
+        let outside = fish(3, &b, 20, 21);
+        let planner = Planner::new(
+            EorzeaDuration::new(10, 0, 0).unwrap(),
+            EorzeaDuration::from_esecs(0),
+        );
+        let plan = planner.plan(
+            &[&first, &overlapping, &outside],
+            EorzeaTime::new(1, 1, 2, 0, 0, 0).unwrap(),
+        );
+        assert_eq!(plan.entries.len(), 2);
+        assert_eq!(plan.entries[1].warning, Some(PlanWarning::Conflict));
+        assert_eq!(plan.unplanned.len(), 1);
+        assert_eq!(plan.unplanned[0].id, 3);
+    }
+}
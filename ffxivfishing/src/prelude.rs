@@ -0,0 +1,29 @@
+//! The commonly used types in one import: `use ffxivfishing::prelude::*;`.
+
+pub use crate::available::{AvailableFish, UpcomingWindow};
+pub use crate::bite::{BiteTime, BiteTimes};
+#[cfg(feature = "carbuncle-data")]
+pub use crate::carbuncledata::carbuncle_fishes;
+#[cfg(feature = "serde")]
+pub use crate::carbuncledata::carbuncle_fishes_from_str;
+pub use crate::completion::{Completion, Progress};
+pub use crate::conditions::ConditionWindow;
+#[cfg(feature = "chrono")]
+pub use crate::display::{
+    RelativeStyle, TimeDisplay, WindowDisplay, WindowFormat, format_relative,
+};
+pub use crate::eorzea_time::{
+    EorzeaDuration, EorzeaDurationError, EorzeaTime, EorzeaTimeCreationError, EorzeaTimeSpan,
+};
+pub use crate::fish::{
+    Area, Bait, DataError, Expansion, Fish, FishData, FishingHole, FishingItem, FolkloreBook, Item,
+    Lure, MergeConflict, Reason, Region, Requirements, WindowStats,
+};
+pub use crate::icons::IconSource;
+pub use crate::lang::{Lang, LocalizedName};
+pub use crate::lookup::NameMatch;
+pub use crate::provisioning::ProvisioningSchedule;
+pub use crate::search::{NoWindow, SearchHorizon, WindowQuery};
+pub use crate::sightseeing::Vista;
+pub use crate::watchlist::WatchList;
+pub use crate::weather::{Forecast, SpecialForecast, Weather, WeatherForecast};
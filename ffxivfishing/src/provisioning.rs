@@ -0,0 +1,109 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::fish::{Fish, FishData};
+
+/// Hour (UTC) at which Grand Company supply and provisioning missions
+/// change.
+pub const RESET_HOUR_UTC: u64 = 20;
+
+const DAY_SECS: u64 = 24 * 60 * 60;
+const RESET_OFFSET_SECS: u64 = RESET_HOUR_UTC * 60 * 60;
+
+/// The provisioning day `time` falls into, counted in days since the
+/// Unix epoch. A day starts at the daily reset, so 19:59 UTC still belongs
+/// to the previous one.
+pub fn provisioning_day(time: SystemTime) -> u64 {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    secs.saturating_sub(RESET_OFFSET_SECS) / DAY_SECS
+}
+
+/// When provisioning day `day` begins.
+pub fn day_start(day: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(day * DAY_SECS + RESET_OFFSET_SECS)
+}
+
+/// The next daily reset after `time`.
+pub fn next_reset(time: SystemTime) -> SystemTime {
+    day_start(provisioning_day(time) + 1)
+}
+
+/// The items Grand Company provisioning missions ask for on each day. The
+/// game picks them on its servers, so they have to come from outside,
+/// e.g. a community feed or the player's own supply window.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ProvisioningSchedule {
+    days: BTreeMap<u64, Vec<u32>>,
+}
+
+impl ProvisioningSchedule {
+    pub fn new() -> ProvisioningSchedule {
+        ProvisioningSchedule::default()
+    }
+
+    /// Sets the item ids requested on provisioning day `day`.
+    pub fn set_day(&mut self, day: u64, items: Vec<u32>) {
+        self.days.insert(day, items);
+    }
+
+    /// The item ids requested at `time`, empty if that day is unknown.
+    pub fn requested_at(&self, time: SystemTime) -> &[u32] {
+        self.days
+            .get(&provisioning_day(time))
+            .map_or(&[], |items| items.as_slice())
+    }
+
+    /// Adds the days of `other`, which win over days already known.
+    pub fn merge(&mut self, other: ProvisioningSchedule) {
+        self.days.extend(other.days);
+    }
+}
+
+impl FishData {
+    /// The fish Grand Company provisioning asks for on the day of `date`,
+    /// in the order of the schedule. Requests for other items are skipped.
+    pub fn provisioning_candidates(&self, date: SystemTime) -> Vec<&Fish> {
+        self.provisioning()
+            .requested_at(date)
+            .iter()
+            .filter_map(|id| self.fish_by_id(*id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    fn utc(day: u64, hour: u64, minute: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(day * DAY_SECS + hour * 3600 + minute * 60)
+    }
+
+    #[test]
+    fn reset() {
+        assert_eq!(provisioning_day(utc(100, 19, 59)), 99);
+        assert_eq!(provisioning_day(utc(100, 20, 0)), 100);
+        assert_eq!(provisioning_day(utc(101, 3, 0)), 100);
+        assert_eq!(next_reset(utc(100, 19, 59)), utc(100, 20, 0));
+        assert_eq!(next_reset(utc(100, 20, 0)), utc(101, 20, 0));
+        assert_eq!(day_start(100), utc(100, 20, 0));
+    }
+
+    #[test]
+    fn candidates() {
+        let mut schedule = ProvisioningSchedule::new();
+        schedule.set_day(100, vec![RAIN_CARP, WORM, DAWN_PERCH]);
+        let data = fixture_data().with_provisioning(schedule);
+        let ids = |time| -> Vec<u32> {
+            data.provisioning_candidates(time)
+                .iter()
+                .map(|f| f.id)
+                .collect()
+        };
+        assert_eq!(ids(utc(101, 12, 0)), [RAIN_CARP, DAWN_PERCH]);
+        assert!(ids(utc(101, 20, 0)).is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    eorzea_time::{EORZEA_SUN, EORZEA_WEATHER_PERIOD, EorzeaDuration, EorzeaTime, EorzeaTimeSpan},
+    weather::{Weather, WeatherForecast},
+};
+
+/// How far ahead a window or weather search looks before giving up.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SearchHorizon {
+    /// A span of real time.
+    Real(Duration),
+    /// A span of Eorzea time.
+    Eorzea(EorzeaDuration),
+    /// A number of weather periods.
+    Periods(u32),
+}
+
+impl SearchHorizon {
+    /// Number of periods of length `period` the horizon covers.
+    pub fn periods(&self, period: EorzeaDuration) -> u32 {
+        let duration = match self {
+            SearchHorizon::Real(d) => EorzeaDuration::from_real(*d),
+            SearchHorizon::Eorzea(d) => *d,
+            SearchHorizon::Periods(n) => return *n,
+        };
+        duration
+            .total_seconds()
+            .div_ceil(period.total_seconds())
+            .try_into()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// End of the search when starting at `start`: the end of the last
+    /// period that is still searched.
+    pub fn end(&self, start: EorzeaTime, period: EorzeaDuration) -> EorzeaTime {
+        let mut end = start;
+        end.round(period);
+        end + EorzeaDuration::from_esecs(self.periods(period) as u64 * period.total_seconds())
+    }
+}
+
+impl Default for SearchHorizon {
+    /// 30 real days, enough for every fish in the game so far.
+    fn default() -> Self {
+        SearchHorizon::Real(Duration::from_secs(30 * 24 * 60 * 60))
+    }
+}
+
+/// What `Fish::next_window` looks for. Starts with the fish's own
+/// conditions and the default horizon; the setters adjust it.
+#[derive(Debug, Clone)]
+pub struct WindowQuery {
+    pub(crate) start: EorzeaTime,
+    pub(crate) horizon: SearchHorizon,
+    pub(crate) include_ongoing: bool,
+    pub(crate) assume_fish_eyes: bool,
+    pub(crate) weather_override: Option<(Vec<Weather>, Vec<Weather>)>,
+}
+
+impl WindowQuery {
+    pub fn new(start: EorzeaTime) -> WindowQuery {
+        WindowQuery {
+            start,
+            horizon: SearchHorizon::default(),
+            include_ongoing: false,
+            assume_fish_eyes: false,
+            weather_override: None,
+        }
+    }
+
+    pub fn horizon(mut self, horizon: SearchHorizon) -> WindowQuery {
+        self.horizon = horizon;
+        self
+    }
+
+    /// Also return a window that is already open at the start.
+    pub fn include_ongoing(mut self, include_ongoing: bool) -> WindowQuery {
+        self.include_ongoing = include_ongoing;
+        self
+    }
+
+    /// Ignore the time of day for fish that Fish Eyes works on.
+    pub fn assume_fish_eyes(mut self, assume_fish_eyes: bool) -> WindowQuery {
+        self.assume_fish_eyes = assume_fish_eyes;
+        self
+    }
+
+    /// Search for these weathers instead of the fish's own. Empty sets match
+    /// every weather, as with the fish's.
+    pub fn required_weather_override(
+        mut self,
+        previous_weather_set: Vec<Weather>,
+        weather_set: Vec<Weather>,
+    ) -> WindowQuery {
+        self.weather_override = Some((previous_weather_set, weather_set));
+        self
+    }
+
+    pub fn start(&self) -> EorzeaTime {
+        self.start
+    }
+}
+
+/// Returned when a search found nothing within its horizon.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct NoWindow {
+    /// Where the search stopped.
+    pub until: EorzeaTime,
+}
+
+impl Display for NoWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no window before {}", self.until)
+    }
+}
+
+impl std::error::Error for NoWindow {}
+
+/// The hours from `start` to `end` on the day of `etime`, ending the next
+/// day if `end` is not after `start`.
+pub(crate) fn hours_on_day(
+    etime: EorzeaTime,
+    start: EorzeaDuration,
+    end: EorzeaDuration,
+) -> EorzeaTimeSpan {
+    let mut day = etime;
+    day.round(EORZEA_SUN);
+    let start = day + start;
+    let mut end = day + end;
+    if end <= start {
+        end += EORZEA_SUN;
+    }
+    EorzeaTimeSpan::new_start_end(start, end).unwrap()
+}
+
+/// The next window matching `query` of something that needs a weather
+/// pattern in `forecast` and daily hours, `hours_on_day(day)` being the
+/// hours starting on `day`. Each weather match is found with
+/// `Forecast::find_pattern`, one period at a time, and the next search
+/// continues after it, so no period is scanned twice. The forecast keeps the
+/// weathers it computed, so fish in the same region and later searches look
+/// them up instead. The search behind `Fish::next_window` and
+/// `Vista::next_window`.
+pub(crate) fn next_window_in(
+    forecast: &WeatherForecast,
+    previous_weather_set: &[Weather],
+    weather_set: &[Weather],
+    hours_on_day: impl Fn(EorzeaTime) -> EorzeaTimeSpan,
+    query: &WindowQuery,
+) -> Result<EorzeaTimeSpan, NoWindow> {
+    let no_window = || NoWindow {
+        until: query.horizon.end(query.start, EORZEA_WEATHER_PERIOD),
+    };
+    let mut limit = query.horizon.periods(EORZEA_WEATHER_PERIOD);
+    let mut time = query.start;
+    while limit > 0 {
+        let next_weather = forecast
+            .find_pattern(
+                time,
+                previous_weather_set,
+                weather_set,
+                SearchHorizon::Periods(limit),
+            )
+            .map_err(|_| no_window())?;
+        let weather_span = EorzeaTimeSpan::new(next_weather, EORZEA_WEATHER_PERIOD);
+        // Hours that wrap past midnight belong to the previous day.
+        for day in [next_weather - EORZEA_SUN, next_weather] {
+            if let Ok(window) = hours_on_day(day).overlap(&weather_span) {
+                let min_window = match query.include_ongoing {
+                    true => window.end(),
+                    false => window.start(),
+                };
+                if query.start <= min_window && window.duration().total_seconds() > 0 {
+                    return Ok(window);
+                }
+            }
+        }
+        let periods = next_weather
+            .weather_period_index()
+            .saturating_sub(time.weather_period_index());
+        limit = limit.saturating_sub(periods as u32 + 1);
+        time = next_weather + EORZEA_WEATHER_PERIOD;
+    }
+    Err(no_window())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eorzea_time::{EORZEA_SUN, EORZEA_WEATHER_PERIOD};
+
+    #[test]
+    fn horizon_periods() {
+        assert_eq!(SearchHorizon::Periods(7).periods(EORZEA_WEATHER_PERIOD), 7);
+        assert_eq!(
+            SearchHorizon::Eorzea(EORZEA_SUN).periods(EORZEA_WEATHER_PERIOD),
+            3
+        );
+        // One weather period is 23:20 real minutes; a started one counts.
+        assert_eq!(
+            SearchHorizon::Real(Duration::from_secs(60 * 60)).periods(EORZEA_WEATHER_PERIOD),
+            3
+        );
+        assert_eq!(
+            SearchHorizon::default().periods(EORZEA_WEATHER_PERIOD),
+            1852
+        );
+    }
+
+    #[test]
+    fn horizon_end() {
+        let start = EorzeaTime::new(1, 1, 1, 9, 30, 0).unwrap();
+        assert_eq!(
+            SearchHorizon::Periods(2).end(start, EORZEA_WEATHER_PERIOD),
+            EorzeaTime::new(1, 1, 2, 0, 0, 0).unwrap()
+        );
+    }
+}
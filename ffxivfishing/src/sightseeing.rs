@@ -0,0 +1,104 @@
+//! Sightseeing log vistas, which like fish need a time of day and a weather
+//! in their region, and are found with the same window search.
+
+use std::rc::Rc;
+
+use crate::{
+    eorzea_time::{EORZEA_SUN, EorzeaDuration, EorzeaTime, EorzeaTimeSpan},
+    fish::Region,
+    search::{self, NoWindow, WindowQuery},
+    weather::Weather,
+};
+
+/// A sightseeing log entry: be at the vista in one of `weather_set` between
+/// `window_start` and `window_end` and use `emote`.
+#[derive(Debug, Clone)]
+pub struct Vista {
+    /// Number in the sightseeing log.
+    pub number: u32,
+    pub name: String,
+    pub region: Rc<Region>,
+    pub window_start: EorzeaDuration,
+    pub window_end: EorzeaDuration,
+    /// Empty if any weather will do.
+    pub weather_set: Vec<Weather>,
+    /// The emote to use, e.g. `/lookout`.
+    pub emote: String,
+}
+
+impl Vista {
+    pub fn new(
+        number: u32,
+        name: String,
+        region: Rc<Region>,
+        window_start: EorzeaDuration,
+        window_end: EorzeaDuration,
+        weather_set: Vec<Weather>,
+        emote: String,
+    ) -> Vista {
+        Vista {
+            number,
+            name,
+            region,
+            window_start: window_start % EORZEA_SUN,
+            window_end: window_end % EORZEA_SUN,
+            weather_set,
+            emote,
+        }
+    }
+
+    pub fn window_on_day(&self, etime: EorzeaTime) -> EorzeaTimeSpan {
+        search::hours_on_day(etime, self.window_start, self.window_end)
+    }
+
+    /// The next window matching `query`. The query's fish settings, Fish
+    /// Eyes and the weather override, do not apply to vistas.
+    pub fn next_window(&self, query: &WindowQuery) -> Result<EorzeaTimeSpan, NoWindow> {
+        search::next_window_in(
+            self.region.weather(),
+            &[],
+            &self.weather_set,
+            |day| self.window_on_day(day),
+            query,
+        )
+    }
+}
+
+/// The window `vista` can be logged in at `now` or next after it.
+pub fn next_vista_window(vista: &Vista, now: EorzeaTime) -> Result<EorzeaTimeSpan, NoWindow> {
+    vista.next_window(&WindowQuery::new(now).include_ongoing(true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn vista_windows() {
+        let data = fixture_data();
+        let marsh = Rc::clone(data.fish_by_id(RAIN_CARP).unwrap().location.region());
+        // Clouds in Misty Bank's region from 18:00 to 5:00.
+        let vista = Vista::new(
+            1,
+            "Fixture Marsh at night".to_string(),
+            marsh,
+            EorzeaDuration::new(18, 0, 0).unwrap(),
+            EorzeaDuration::new(5, 0, 0).unwrap(),
+            vec![Weather::CLOUDS],
+            "/lookout".to_string(),
+        );
+        let bells = |window: &EorzeaTimeSpan| {
+            let (start, end) = (window.start(), window.end());
+            (start.sun(), start.bell(), end.sun(), end.bell())
+        };
+        let first = next_vista_window(&vista, epoch()).unwrap();
+        assert_eq!(bells(&first), (1, 18, 2, 0));
+        // Clouds go on past midnight into the hours that began the day before.
+        let second = vista.next_window(&WindowQuery::new(first.end())).unwrap();
+        assert_eq!(bells(&second), (2, 0, 2, 5));
+        // Still open an hour later.
+        let later = first.start() + EorzeaDuration::new(1, 0, 0).unwrap();
+        assert_eq!(next_vista_window(&vista, later), Ok(first));
+    }
+}
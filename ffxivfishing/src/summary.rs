@@ -0,0 +1,152 @@
+use crate::{
+    eorzea_time::EorzeaDuration,
+    fish::{Bait, Fish, FishData},
+    lang::Lang,
+    weather::Weather,
+};
+
+/// The fixed words of a summary.
+struct Words {
+    any_time: &'static str,
+    during: &'static str,
+    after: &'static str,
+    mooch: &'static str,
+    bait: &'static str,
+}
+
+fn words(lang: Lang) -> Words {
+    match lang {
+        Lang::English => Words {
+            any_time: "any time",
+            during: "during",
+            after: "after",
+            mooch: "mooch",
+            bait: "bait",
+        },
+        Lang::Japanese => Words {
+            any_time: "常時",
+            during: "天候",
+            after: "前天候",
+            mooch: "泳がせ釣り",
+            bait: "餌",
+        },
+        Lang::German => Words {
+            any_time: "jederzeit",
+            during: "bei",
+            after: "nach",
+            mooch: "Köderfisch",
+            bait: "Köder",
+        },
+        Lang::French => Words {
+            any_time: "à toute heure",
+            during: "par",
+            after: "après",
+            mooch: "pêche au vif",
+            bait: "appât",
+        },
+        Lang::Korean => Words {
+            any_time: "상시",
+            during: "날씨",
+            after: "이전 날씨",
+            mooch: "생미끼",
+            bait: "미끼",
+        },
+    }
+}
+
+/// E.g. "9AM" or "11:30PM" in English and "9:00" or "23:30" otherwise.
+fn clock_text(time: &EorzeaDuration, lang: Lang) -> String {
+    if lang != Lang::English {
+        return format!("{}:{:0>2}", time.bell(), time.minute());
+    }
+    let hour = match time.bell() % 12 {
+        0 => 12,
+        h => h,
+    };
+    let suffix = if time.bell() < 12 { "AM" } else { "PM" };
+    match time.minute() {
+        0 => format!("{}{}", hour, suffix),
+        m => format!("{}:{:0>2}{}", hour, m, suffix),
+    }
+}
+
+impl FishData {
+    fn weather_set_text(&self, set: &[Weather], lang: Lang) -> String {
+        set.iter()
+            .map(|w| self.localized_weather_name(w, lang))
+            .collect::<Vec<String>>()
+            .join("/")
+    }
+
+    /// The canonical description of when and how to catch `fish`, e.g.
+    /// "9AM–3PM ET during Fog after Clear Skies; mooch Glacier Core".
+    /// Front-ends should show this instead of formatting their own.
+    pub fn conditions_summary(&self, fish: &Fish, lang: Lang) -> String {
+        let words = words(lang);
+        let mut conditions = vec![];
+        if let Some((start, end)) = fish.requirements().window {
+            conditions.push(format!(
+                "{}–{} ET",
+                clock_text(&start, lang),
+                clock_text(&end, lang)
+            ));
+        }
+        if !fish.weather_set.is_empty() {
+            conditions.push(format!(
+                "{} {}",
+                words.during,
+                self.weather_set_text(&fish.weather_set, lang)
+            ));
+        }
+        if !fish.previous_weather_set.is_empty() {
+            conditions.push(format!(
+                "{} {}",
+                words.after,
+                self.weather_set_text(&fish.previous_weather_set, lang)
+            ));
+        }
+        let mut summary = match conditions.is_empty() {
+            true => words.any_time.to_string(),
+            false => conditions.join(" "),
+        };
+        let (verb, id) = match fish.bait {
+            Bait::Mooch(id) => (words.mooch, id),
+            Bait::Bait(id) => (words.bait, id),
+            Bait::Unknown => return summary,
+        };
+        let name = self.localized_item_name(id, lang).unwrap_or("?");
+        summary += &format!("; {} {}", verb, name);
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock() {
+        let at = |bell, minute| EorzeaDuration::new(bell, minute, 0).unwrap();
+        assert_eq!(clock_text(&at(0, 0), Lang::English), "12AM");
+        assert_eq!(clock_text(&at(9, 0), Lang::English), "9AM");
+        assert_eq!(clock_text(&at(12, 0), Lang::English), "12PM");
+        assert_eq!(clock_text(&at(23, 30), Lang::English), "11:30PM");
+        assert_eq!(clock_text(&at(23, 30), Lang::German), "23:30");
+    }
+
+    #[cfg(feature = "carbuncle-data")]
+    #[test]
+    fn conditions_summary() {
+        let data = crate::carbuncledata::carbuncle_fishes().unwrap();
+        let summary = |id, lang| data.conditions_summary(data.fish_by_id(id).unwrap(), lang);
+        assert_eq!(
+            summary(8776, Lang::English),
+            "4:30AM–11:30AM ET during Fog after Clear Skies/Fair Skies; mooch Silverfish"
+        );
+        assert_eq!(
+            summary(8776, Lang::Japanese),
+            "4:30–11:30 ET 天候 霧 前天候 快晴/晴れ; 泳がせ釣り 銀魚"
+        );
+        assert_eq!(summary(8752, Lang::English), "any time; mooch Silverfish");
+    }
+}
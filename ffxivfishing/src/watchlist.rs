@@ -0,0 +1,188 @@
+use crate::{
+    available::UpcomingWindow,
+    eorzea_time::EorzeaTime,
+    fish::{Fish, FishData},
+};
+
+/// Fish ids a user follows, in the order they arranged them. Serializes as a
+/// plain list of ids.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct WatchList {
+    ids: Vec<u32>,
+}
+
+impl WatchList {
+    pub fn new() -> WatchList {
+        WatchList::default()
+    }
+
+    pub fn ids(&self) -> &[u32] {
+        &self.ids
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.ids.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn contains(&self, id: u32) -> bool {
+        self.ids.contains(&id)
+    }
+
+    /// Place of `id` in the user's order.
+    pub fn position(&self, id: u32) -> Option<usize> {
+        self.ids.iter().position(|i| *i == id)
+    }
+
+    pub fn get(&self, index: usize) -> Option<u32> {
+        self.ids.get(index).copied()
+    }
+
+    /// Adds `id` at the end. Returns false if it was there already.
+    pub fn insert(&mut self, id: u32) -> bool {
+        let new = !self.contains(id);
+        if new {
+            self.ids.push(id);
+        }
+        new
+    }
+
+    /// Returns false if `id` was not in the list.
+    pub fn remove(&mut self, id: u32) -> bool {
+        let index = self.position(id);
+        if let Some(index) = index {
+            self.ids.remove(index);
+        }
+        index.is_some()
+    }
+
+    /// Adds or removes `id`. Returns whether it is watched now.
+    pub fn toggle(&mut self, id: u32) -> bool {
+        !self.remove(id) && self.insert(id)
+    }
+
+    /// Moves `id` up (negative) or down in the order by swapping it with
+    /// its neighbour. Returns false if it is not in the list or already at
+    /// that end.
+    pub fn move_by(&mut self, id: u32, offset: isize) -> bool {
+        let Some(from) = self.position(id) else {
+            return false;
+        };
+        match from.checked_add_signed(offset) {
+            Some(to) if to < self.ids.len() => {
+                self.ids.swap(from, to);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// This list followed by the ids of `other` it lacks.
+    pub fn union(&self, other: &WatchList) -> WatchList {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    /// The ids of this list that `other` has too, in this list's order.
+    pub fn intersection(&self, other: &WatchList) -> WatchList {
+        self.iter().filter(|id| other.contains(*id)).collect()
+    }
+
+    /// The ids of this list that `other` lacks.
+    pub fn difference(&self, other: &WatchList) -> WatchList {
+        self.iter().filter(|id| !other.contains(*id)).collect()
+    }
+
+    /// The watched fish `data` has, in the list's order.
+    pub fn fishes<'a>(&'a self, data: &'a FishData) -> impl Iterator<Item = &'a Fish> {
+        self.iter().filter_map(|id| data.fish_by_id(id))
+    }
+
+    /// The `n` soonest windows of watched fish, see
+    /// `FishData::upcoming_windows`.
+    pub fn upcoming_windows<'a>(
+        &self,
+        data: &'a FishData,
+        start: EorzeaTime,
+        n: usize,
+    ) -> Vec<UpcomingWindow<'a>> {
+        data.upcoming_windows_where(start, n, |fish| self.contains(fish.id))
+    }
+}
+
+impl FromIterator<u32> for WatchList {
+    /// Keeps the first of repeated ids.
+    fn from_iter<T: IntoIterator<Item = u32>>(iter: T) -> WatchList {
+        let mut list = WatchList::new();
+        for id in iter {
+            list.insert(id);
+        }
+        list
+    }
+}
+
+impl From<Vec<u32>> for WatchList {
+    fn from(ids: Vec<u32>) -> WatchList {
+        ids.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn editing() {
+        let mut list = WatchList::from(vec![1, 2, 3, 2]);
+        assert_eq!(list.ids(), [1, 2, 3]);
+        assert!(!list.insert(3));
+        assert!(!list.toggle(2));
+        assert!(list.toggle(4));
+        assert_eq!(list.ids(), [1, 3, 4]);
+        assert!(list.move_by(4, -2));
+        assert!(!list.move_by(4, -1));
+        assert!(!list.move_by(5, 1));
+        assert_eq!(list.ids(), [4, 3, 1]);
+        assert!(list.remove(3));
+        assert!(!list.remove(3));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = WatchList::from(vec![1, 2, 3]);
+        let b = WatchList::from(vec![4, 3, 1]);
+        assert_eq!(a.union(&b).ids(), [1, 2, 3, 4]);
+        assert_eq!(a.intersection(&b).ids(), [1, 3]);
+        assert_eq!(a.difference(&b).ids(), [2]);
+    }
+
+    #[test]
+    fn windows() {
+        let data = fixture_data();
+        let list = WatchList::from(vec![NIGHT_EEL, DAWN_PERCH, 9999]);
+        assert_eq!(list.fishes(&data).count(), 2);
+        let windows = list.upcoming_windows(&data, epoch(), 3);
+        let ids: Vec<u32> = windows.iter().map(|w| w.fish.id).collect();
+        assert_eq!(ids, [NIGHT_EEL, DAWN_PERCH, NIGHT_EEL]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let list: WatchList = serde_json::from_str("[5, 1]").unwrap();
+        assert_eq!(list.ids(), [5, 1]);
+        assert_eq!(serde_json::to_string(&list).unwrap(), "[5,1]");
+    }
+}
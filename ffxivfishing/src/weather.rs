@@ -1,78 +1,383 @@
-use std::time::{SystemTimeError, UNIX_EPOCH};
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    time::{SystemTimeError, UNIX_EPOCH},
+};
 
-use crate::eorzea_time::{EORZEA_WEATHER_PERIOD, EorzeaTime};
+use crate::{
+    eorzea_time::{EORZEA_WEATHER_PERIOD, EORZEA_ZERO_TIME, EorzeaDuration, EorzeaTime},
+    search::{NoWindow, SearchHorizon},
+};
 
-#[derive(PartialEq, Eq, Debug, Clone)]
-pub enum Weather {
-    Unknown,
-    Id(u32),
-    Sunny,
-    Clouds,
-    ClearSkies,
-    FairSkies,
-    Fog,
-    Wind,
+/// A weather, identified by its game id. Names come from the game's weather
+/// table, so a weather built from data and one of the named constants below
+/// compare equal whenever they are the same weather.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "WeatherRepr", into = "u32")
+)]
+pub struct Weather {
+    id: u32,
+}
+
+/// Weathers as `(id, name, icon, abbreviation)`, from the Weather sheet
+/// except the abbreviations. Complete for the weathers in the Carbuncle
+/// Plushy data, see `weathers_in_data`; beyond those it covers ids up to 58,
+/// and ids 59 to 147 are missing. Some weathers, like Fair Skies, have
+/// several ids for different zones or duties; they share a name and an
+/// abbreviation. Ids missing here still work but have no name.
+const TABLE: &[(u32, &str, Option<u32>, &str)] = &[
+    (1, "Clear Skies", Some(60201), "Clr"),
+    (2, "Fair Skies", Some(60202), "Fair"),
+    (3, "Clouds", Some(60203), "Cld"),
+    (4, "Fog", Some(60204), "Fog"),
+    (5, "Wind", Some(60205), "Wnd"),
+    (6, "Gales", Some(60206), "Gal"),
+    (7, "Rain", Some(60207), "Rain"),
+    (8, "Showers", Some(60208), "Shw"),
+    (9, "Thunder", Some(60209), "Thu"),
+    (10, "Thunderstorms", Some(60210), "Ths"),
+    (11, "Dust Storms", Some(60211), "Dust"),
+    (12, "Sandstorms", Some(60212), "Sand"),
+    (13, "Hot Spells", Some(60213), "Hot"),
+    (14, "Heat Waves", Some(60214), "Heat"),
+    (15, "Snow", Some(60215), "Snow"),
+    (16, "Blizzards", Some(60216), "Blz"),
+    (17, "Gloom", Some(60218), "Glm"),
+    (18, "Auroras", Some(60217), "Aur"),
+    (19, "Darkness", None, "Dark"),
+    (20, "Tension", None, "Tens"),
+    (21, "Clouds", Some(60203), "Cld"),
+    (22, "Storm Clouds", None, "StCl"),
+    (23, "Rough Seas", None, "Seas"),
+    (24, "Rough Seas", None, "Seas"),
+    (25, "Louring", None, "Lour"),
+    (26, "Heat Waves", Some(60214), "Heat"),
+    (27, "Gloom", Some(60218), "Glm"),
+    (28, "Gales", Some(60206), "Gal"),
+    (29, "Eruptions", None, "Erup"),
+    (30, "Fair Skies", Some(60202), "Fair"),
+    (31, "Fair Skies", Some(60202), "Fair"),
+    (32, "Fair Skies", Some(60202), "Fair"),
+    (33, "Fair Skies", Some(60202), "Fair"),
+    (34, "Fair Skies", Some(60202), "Fair"),
+    (35, "Irradiance", None, "Irr"),
+    (36, "Core Radiation", None, "Core"),
+    (37, "Core Radiation", None, "Core"),
+    (38, "Core Radiation", None, "Core"),
+    (39, "Core Radiation", None, "Core"),
+    (40, "Shelf Clouds", None, "Shlf"),
+    (41, "Shelf Clouds", None, "Shlf"),
+    (42, "Shelf Clouds", None, "Shlf"),
+    (43, "Shelf Clouds", None, "Shlf"),
+    (44, "Oppression", None, "Opp"),
+    (45, "Oppression", None, "Opp"),
+    (46, "Oppression", None, "Opp"),
+    (47, "Oppression", None, "Opp"),
+    (48, "Oppression", None, "Opp"),
+    (49, "Umbral Wind", Some(60219), "UWnd"),
+    (50, "Umbral Static", Some(60220), "UStc"),
+    (51, "Smoke", None, "Smk"),
+    (52, "Fair Skies", Some(60202), "Fair"),
+    (53, "Royal Levin", None, "RLev"),
+    (54, "Hyperelectricity", None, "Hyp"),
+    (55, "Royal Levin", None, "RLev"),
+    (56, "Oppression", None, "Opp"),
+    (57, "Thunder", Some(60209), "Thu"),
+    (58, "Thunder", Some(60209), "Thu"),
+    (148, "Moon Dust", Some(60222), "MDst"),
+    (149, "Astromagnetic Storms", Some(60223), "AStm"),
+];
+
+impl Weather {
+    /// Used when no weather rate applies.
+    pub const UNKNOWN: Weather = Weather::from_id(0);
+    pub const CLEAR_SKIES: Weather = Weather::from_id(1);
+    pub const FAIR_SKIES: Weather = Weather::from_id(2);
+    pub const CLOUDS: Weather = Weather::from_id(3);
+    pub const FOG: Weather = Weather::from_id(4);
+    pub const WIND: Weather = Weather::from_id(5);
+    pub const GALES: Weather = Weather::from_id(6);
+    pub const RAIN: Weather = Weather::from_id(7);
+    pub const SHOWERS: Weather = Weather::from_id(8);
+    pub const THUNDER: Weather = Weather::from_id(9);
+    pub const THUNDERSTORMS: Weather = Weather::from_id(10);
+    pub const DUST_STORMS: Weather = Weather::from_id(11);
+    pub const HEAT_WAVES: Weather = Weather::from_id(14);
+    pub const SNOW: Weather = Weather::from_id(15);
+    pub const BLIZZARDS: Weather = Weather::from_id(16);
+    pub const GLOOM: Weather = Weather::from_id(17);
+    pub const UMBRAL_WIND: Weather = Weather::from_id(49);
+    pub const UMBRAL_STATIC: Weather = Weather::from_id(50);
+    pub const ROYAL_LEVIN: Weather = Weather::from_id(53);
+    pub const MOON_DUST: Weather = Weather::from_id(148);
+    pub const ASTROMAGNETIC_STORMS: Weather = Weather::from_id(149);
+
+    pub const fn from_id(id: u32) -> Weather {
+        Weather { id }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    fn entry(&self) -> Option<&'static (u32, &'static str, Option<u32>, &'static str)> {
+        TABLE.iter().find(|(id, ..)| *id == self.id)
+    }
+
+    /// The canonical English name, if the id is known.
+    pub fn name(&self) -> Option<&'static str> {
+        self.entry().map(|(_, name, ..)| *name)
+    }
+
+    /// The game icon id, e.g. 60201 for `ui/icon/060000/060201.tex`.
+    pub fn icon(&self) -> Option<u32> {
+        self.entry().and_then(|(_, _, icon, _)| *icon)
+    }
+
+    /// A short English name of up to four letters for narrow displays,
+    /// e.g. "Cld" for Clouds.
+    pub fn abbreviation(&self) -> Option<&'static str> {
+        self.entry().map(|(.., abbreviation)| *abbreviation)
+    }
+
+    /// All weathers in the table, which may lack rarer special weathers.
+    pub fn all() -> impl Iterator<Item = Weather> {
+        TABLE.iter().map(|(id, ..)| Weather::from_id(*id))
+    }
+}
+
+impl Display for Weather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.id, self.name()) {
+            (0, _) => write!(f, "Unknown"),
+            (_, Some(name)) => write!(f, "{}", name),
+            (id, None) => write!(f, "Weather #{}", id),
+        }
+    }
+}
+
+/// Weathers used to be stored as `{ Id = n }`; both that and a plain id are
+/// accepted so older saved settings keep loading.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum WeatherRepr {
+    Plain(u32),
+    Legacy {
+        #[serde(rename = "Id")]
+        id: u32,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<WeatherRepr> for Weather {
+    fn from(value: WeatherRepr) -> Self {
+        match value {
+            WeatherRepr::Plain(id) | WeatherRepr::Legacy { id } => Weather::from_id(id),
+        }
+    }
+}
+
+impl From<Weather> for u32 {
+    fn from(value: Weather) -> Self {
+        value.id
+    }
+}
+
+/// Most weather periods a forecast remembers, about three years.
+const MAX_CACHED_PERIODS: usize = 1 << 16;
+
+/// Scores of consecutive weather periods from `first` on, filled in as
+/// searches walk forward. Periods before `first` or past a gap are computed
+/// without being kept.
+#[derive(Debug, Clone, Default)]
+struct PeriodCache {
+    first: u64,
+    scores: Vec<u8>,
 }
 
 #[derive(Debug, Clone)]
 pub struct WeatherForecast {
     region: String,
     weather_rates: Vec<(u8, Weather)>,
+    /// The weather for each possible score, so lookups skip the rate scan.
+    by_score: Vec<Weather>,
+    /// Regions with the same rates share a forecast, and so these scores.
+    cache: RefCell<PeriodCache>,
 }
 
 impl WeatherForecast {
     pub fn new(region: String, mut weather_rates: Vec<(u8, Weather)>) -> WeatherForecast {
-        weather_rates.sort_by(|(n, _), (n2, _)| n.cmp(n2));
+        weather_rates.sort_by_key(|(n, _)| *n);
+        let max_score = weather_rates.iter().map(|(n, _)| *n).max().unwrap_or(1);
+        let by_score = (0..max_score)
+            .map(|score| {
+                weather_rates
+                    .iter()
+                    .find(|(n, _)| *n > score)
+                    .map_or(Weather::UNKNOWN, |(_, w)| *w)
+            })
+            .collect();
         WeatherForecast {
             region,
             weather_rates,
+            by_score,
+            cache: RefCell::default(),
         }
     }
     pub fn weather_at(&self, time: EorzeaTime) -> &Weather {
+        self.by_score
+            .get(self.score_at(time) as usize)
+            .unwrap_or(&Weather::UNKNOWN)
+    }
+
+    fn score_at(&self, time: EorzeaTime) -> u8 {
+        let max_score = self.by_score.len().max(1) as u8;
+        let score = || eorzea_weather_score(time, max_score).unwrap_or(1);
+        let period = weather_period(time);
+        let mut cache = self.cache.borrow_mut();
+        if cache.scores.is_empty() {
+            cache.first = period;
+        }
+        match period.checked_sub(cache.first).map(|i| i as usize) {
+            Some(i) if i < cache.scores.len() => cache.scores[i],
+            Some(i) if i == cache.scores.len() && i < MAX_CACHED_PERIODS => {
+                let score = score();
+                cache.scores.push(score);
+                score
+            }
+            _ => score(),
+        }
+    }
+
+    /// Chance that any weather of `set` is active in a weather period. An
+    /// empty set matches every weather.
+    pub fn probability(&self, set: &[Weather]) -> f64 {
+        if set.is_empty() {
+            return 1.0;
+        }
         let max_score = self
             .weather_rates
             .iter()
-            .map(|(n, _)| n)
+            .map(|(n, _)| *n)
             .max()
-            .unwrap_or(&1u8);
+            .unwrap_or(1);
+        let mut lower = 0;
+        let mut matching = 0;
+        for (n, w) in &self.weather_rates {
+            if set.contains(w) {
+                matching += n - lower;
+            }
+            lower = *n;
+        }
+        matching as f64 / max_score as f64
+    }
 
-        let weather_score = eorzea_weather_score(time, *max_score).unwrap_or(1);
-        self.weather_rates
-            .iter()
-            .filter(|(n, _)| *n > weather_score)
-            .map(|(_, w)| w)
-            .next()
-            .unwrap_or(&Weather::Unknown)
+    /// Every weather that can occur, without duplicates.
+    pub fn weathers(&self) -> Vec<&Weather> {
+        let mut weathers: Vec<&Weather> = vec![];
+        for (_, w) in &self.weather_rates {
+            if !weathers.contains(&w) {
+                weathers.push(w);
+            }
+        }
+        weathers
     }
 
     pub fn region(&self) -> &str {
         &self.region
     }
 
+    /// True if both forecasts have the same weather rates, whatever their
+    /// region name.
+    pub fn same_rates(&self, other: &WeatherForecast) -> bool {
+        self.weather_rates == other.weather_rates
+    }
+
     pub fn find_pattern(
         &self,
         start: EorzeaTime,
         previous_weather_set: &[Weather],
         current_weather_set: &[Weather],
-        limit: u32,
-    ) -> Option<EorzeaTime> {
-        let mut time = start;
-        time.round(EORZEA_WEATHER_PERIOD);
-        time -= EORZEA_WEATHER_PERIOD;
+        horizon: SearchHorizon,
+    ) -> Result<EorzeaTime, NoWindow> {
+        Forecast::find_pattern(
+            self,
+            start,
+            previous_weather_set,
+            current_weather_set,
+            horizon,
+        )
+    }
 
-        let mut prev_weather = self.weather_at(time);
-        for _ in 0..limit {
-            time += EORZEA_WEATHER_PERIOD;
-            let current_weather = self.weather_at(time);
-            if (previous_weather_set.is_empty() || previous_weather_set.contains(prev_weather))
-                && (current_weather_set.is_empty() || current_weather_set.contains(current_weather))
-            {
-                return Some(time);
-            }
-            prev_weather = current_weather;
-        }
+    /// The Diadem. Its weather follows the usual 8-bell periods, with rates
+    /// of its own; the event weathers there cannot be forecast.
+    pub fn diadem() -> WeatherForecast {
+        WeatherForecast::new(
+            "The Diadem".to_string(),
+            vec![
+                (30, Weather::FAIR_SKIES),
+                (60, Weather::FOG),
+                (90, Weather::WIND),
+                (100, Weather::UMBRAL_WIND),
+            ],
+        )
+    }
+
+    pub fn eureka_anemos() -> WeatherForecast {
+        WeatherForecast::new(
+            "Eureka Anemos".to_string(),
+            vec![
+                (30, Weather::FAIR_SKIES),
+                (60, Weather::GALES),
+                (90, Weather::SHOWERS),
+                (100, Weather::SNOW),
+            ],
+        )
+    }
 
-        None
+    pub fn eureka_pagos() -> WeatherForecast {
+        WeatherForecast::new(
+            "Eureka Pagos".to_string(),
+            vec![
+                (10, Weather::FAIR_SKIES),
+                (28, Weather::FOG),
+                (46, Weather::HEAT_WAVES),
+                (64, Weather::SNOW),
+                (82, Weather::THUNDER),
+                (100, Weather::BLIZZARDS),
+            ],
+        )
+    }
+
+    pub fn eureka_pyros() -> WeatherForecast {
+        WeatherForecast::new(
+            "Eureka Pyros".to_string(),
+            vec![
+                (10, Weather::FAIR_SKIES),
+                (28, Weather::HEAT_WAVES),
+                (46, Weather::THUNDER),
+                (64, Weather::BLIZZARDS),
+                (82, Weather::UMBRAL_WIND),
+                (100, Weather::SNOW),
+            ],
+        )
+    }
+
+    pub fn eureka_hydatos() -> WeatherForecast {
+        WeatherForecast::new(
+            "Eureka Hydatos".to_string(),
+            vec![
+                (12, Weather::FAIR_SKIES),
+                (34, Weather::SHOWERS),
+                (56, Weather::GLOOM),
+                (78, Weather::THUNDERSTORMS),
+                (100, Weather::SNOW),
+            ],
+        )
     }
 
     pub fn find_next_n_patterns(
@@ -81,13 +386,13 @@ impl WeatherForecast {
         start: EorzeaTime,
         previous_weather_set: &[Weather],
         current_weather_set: &[Weather],
-        limit: u32,
+        horizon: SearchHorizon,
     ) -> Vec<EorzeaTime> {
         let mut result = Vec::new();
         let mut time = start;
         for _ in 0..n {
-            if let Some(t) =
-                self.find_pattern(time, previous_weather_set, current_weather_set, limit)
+            if let Ok(t) =
+                self.find_pattern(time, previous_weather_set, current_weather_set, horizon)
             {
                 result.push(t);
                 time = t;
@@ -100,6 +405,117 @@ impl WeatherForecast {
     }
 }
 
+/// Anything that has one weather per fixed-length period.
+pub trait Forecast {
+    fn weather_at(&self, time: EorzeaTime) -> &Weather;
+
+    /// Length of a weather period. Periods start at multiples of it.
+    fn period(&self) -> EorzeaDuration;
+
+    /// Start of the first period from `start` on with a weather of
+    /// `current_weather_set` that follows one of `previous_weather_set`.
+    /// Empty sets match every weather.
+    fn find_pattern(
+        &self,
+        start: EorzeaTime,
+        previous_weather_set: &[Weather],
+        current_weather_set: &[Weather],
+        horizon: SearchHorizon,
+    ) -> Result<EorzeaTime, NoWindow> {
+        let period = self.period();
+        let mut time = start;
+        time.round(period);
+        time -= period;
+
+        let mut prev_weather = self.weather_at(time);
+        for _ in 0..horizon.periods(period) {
+            time += period;
+            let current_weather = self.weather_at(time);
+            if (previous_weather_set.is_empty() || previous_weather_set.contains(prev_weather))
+                && (current_weather_set.is_empty() || current_weather_set.contains(current_weather))
+            {
+                return Ok(time);
+            }
+            prev_weather = current_weather;
+        }
+
+        Err(NoWindow {
+            until: horizon.end(start, period),
+        })
+    }
+}
+
+impl Forecast for WeatherForecast {
+    fn weather_at(&self, time: EorzeaTime) -> &Weather {
+        WeatherForecast::weather_at(self, time)
+    }
+
+    fn period(&self) -> EorzeaDuration {
+        EORZEA_WEATHER_PERIOD
+    }
+}
+
+/// Weather that does not follow the 8-bell rates but cycles through a fixed
+/// sequence with a period of its own. The Diadem and Eureka use the 8-bell
+/// rates, see `WeatherForecast::diadem` and the `eureka_*` zones.
+#[derive(Debug, Clone)]
+pub struct SpecialForecast {
+    region: String,
+    period: EorzeaDuration,
+    cycle: Vec<Weather>,
+    /// Index into `cycle` of the period starting at the Eorzea epoch.
+    offset: usize,
+}
+
+impl SpecialForecast {
+    pub fn new(
+        region: String,
+        period: EorzeaDuration,
+        cycle: Vec<Weather>,
+        offset: usize,
+    ) -> SpecialForecast {
+        SpecialForecast {
+            region,
+            period,
+            cycle,
+            offset,
+        }
+    }
+
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+}
+
+impl Forecast for SpecialForecast {
+    fn weather_at(&self, time: EorzeaTime) -> &Weather {
+        let periods = time
+            .duration_since(EorzeaTime::from_esecs(0))
+            .map_or(0, |d| {
+                d.total_seconds() / self.period.total_seconds().max(1)
+            });
+        match self.cycle.len() {
+            0 => &Weather::UNKNOWN,
+            n => &self.cycle[(periods as usize + self.offset) % n],
+        }
+    }
+
+    fn period(&self) -> EorzeaDuration {
+        self.period
+    }
+}
+
+/// Index of the weather period `time` is in, counting 1400 real seconds
+/// from the Unix epoch; the score depends on nothing else. Rounds to real
+/// seconds like `EorzeaTime::to_system_time`, but without floats, since this
+/// runs for every period a search looks at.
+fn weather_period(time: EorzeaTime) -> u64 {
+    let esecs = time
+        .duration_since(EORZEA_ZERO_TIME)
+        .map_or(0, |d| d.total_seconds());
+    (esecs * 175 + 1800) / 3600 / 1400
+}
+
 fn eorzea_weather_score(time: EorzeaTime, max_score: u8) -> Result<u8, SystemTimeError> {
     let unix_time_sec = time.to_system_time().duration_since(UNIX_EPOCH)?.as_secs();
     let bell = unix_time_sec / 175;
@@ -131,51 +547,71 @@ mod tests {
 
     #[test]
     fn pattern_search() {
-        let forecast = WeatherForecast {
-            region: "".to_string(),
-            weather_rates: vec![(50, Weather::Clouds), (100, Weather::Sunny)],
-        };
-        let weather_vec = vec![Weather::Sunny];
+        let forecast = WeatherForecast::new(
+            "".to_string(),
+            vec![(50, Weather::CLOUDS), (100, Weather::RAIN)],
+        );
+        let weather_vec = vec![Weather::RAIN];
         let result = forecast.find_pattern(
             EorzeaTime::new(1, 1, 1, 0, 0, 0).unwrap(),
             &weather_vec,
             &weather_vec,
-            1000,
+            SearchHorizon::Periods(1000),
         );
-        assert_eq!(result, Some(EorzeaTime::new(1, 1, 4, 0, 0, 0).unwrap()));
+        assert_eq!(result, Ok(EorzeaTime::new(1, 1, 4, 0, 0, 0).unwrap()));
 
-        let weather_vec2 = vec![Weather::Clouds];
+        let weather_vec2 = vec![Weather::CLOUDS];
         let result2 = forecast.find_pattern(
             EorzeaTime::new(1, 1, 1, 1, 1, 1).unwrap(),
             &weather_vec2,
             &weather_vec2,
-            1000,
+            SearchHorizon::Periods(1000),
         );
-        assert_eq!(result2, Some(EorzeaTime::new(1, 1, 1, 16, 0, 0).unwrap()));
+        assert_eq!(result2, Ok(EorzeaTime::new(1, 1, 1, 16, 0, 0).unwrap()));
     }
+    #[test]
+    fn probability() {
+        let forecast = WeatherForecast::new(
+            "".to_string(),
+            vec![
+                (20, Weather::CLOUDS),
+                (50, Weather::FOG),
+                (100, Weather::WIND),
+            ],
+        );
+        assert_eq!(forecast.probability(&[]), 1.0);
+        assert_eq!(forecast.probability(&[Weather::CLOUDS]), 0.2);
+        assert_eq!(forecast.probability(&[Weather::FOG, Weather::WIND]), 0.8);
+        assert_eq!(forecast.probability(&[Weather::RAIN]), 0.0);
+        assert_eq!(
+            forecast.weathers(),
+            vec![&Weather::CLOUDS, &Weather::FOG, &Weather::WIND]
+        );
+    }
+
     #[test]
     fn weather_at_real() {
-        let forecast = WeatherForecast {
-            region: "".to_string(),
-            weather_rates: vec![
-                (20, Weather::Clouds),
-                (50, Weather::ClearSkies),
-                (80, Weather::FairSkies),
-                (90, Weather::Fog),
-                (100, Weather::Wind),
+        let forecast = WeatherForecast::new(
+            "".to_string(),
+            vec![
+                (20, Weather::CLOUDS),
+                (50, Weather::CLEAR_SKIES),
+                (80, Weather::FAIR_SKIES),
+                (90, Weather::FOG),
+                (100, Weather::WIND),
             ],
-        };
+        );
         assert_eq!(
             forecast.weather_at(EorzeaTime::from_esecs(100_000)),
-            &Weather::FairSkies
+            &Weather::FAIR_SKIES
         );
         assert_eq!(
             forecast.weather_at(EorzeaTime::from_esecs(110_000)),
-            &Weather::FairSkies
+            &Weather::FAIR_SKIES
         );
         assert_eq!(
             forecast.weather_at(EorzeaTime::from_esecs(120_000)),
-            &Weather::ClearSkies
+            &Weather::CLEAR_SKIES
         );
     }
 
@@ -184,24 +620,24 @@ mod tests {
         let forecast = WeatherForecast::new(
             "".to_string(),
             vec![
-                (5, Weather::Clouds), // Weathers not acurate, only scores are relevant
-                (25, Weather::ClearSkies),
-                (65, Weather::FairSkies),
-                (80, Weather::Fog),
-                (90, Weather::Wind),
+                (5, Weather::CLOUDS), // Weathers not acurate, only scores are relevant
+                (25, Weather::CLEAR_SKIES),
+                (65, Weather::FAIR_SKIES),
+                (80, Weather::FOG),
+                (90, Weather::WIND),
             ],
         );
         assert_eq!(
             forecast.weather_at(EorzeaTime::from_esecs(100_000)),
-            &Weather::ClearSkies
+            &Weather::CLEAR_SKIES
         );
         assert_eq!(
             forecast.weather_at(EorzeaTime::from_esecs(110_000)),
-            &Weather::ClearSkies
+            &Weather::CLEAR_SKIES
         );
         assert_eq!(
             forecast.weather_at(EorzeaTime::from_esecs(120_000)),
-            &Weather::FairSkies
+            &Weather::FAIR_SKIES
         );
     }
 
@@ -209,32 +645,37 @@ mod tests {
     fn pattern_search_not_found() {
         let forecast = WeatherForecast::new(
             "".to_string(),
-            vec![(50, Weather::Clouds), (100, Weather::Sunny)],
+            vec![(50, Weather::CLOUDS), (100, Weather::RAIN)],
         );
-        let weather_vec = vec![Weather::Unknown];
+        let weather_vec = vec![Weather::UNKNOWN];
 
         let result = forecast.find_pattern(
             EorzeaTime::from_esecs(10_000),
             &weather_vec,
             &weather_vec,
-            1000,
+            SearchHorizon::Periods(1000),
+        );
+        assert_eq!(
+            result,
+            Err(NoWindow {
+                until: EorzeaTime::from_esecs(1000 * 8 * 3600)
+            })
         );
-        assert_eq!(result, None);
     }
 
     #[test]
     fn pattern_search_n() {
         let forecast = WeatherForecast::new(
             "".to_string(),
-            vec![(50, Weather::Clouds), (100, Weather::Sunny)],
+            vec![(50, Weather::CLOUDS), (100, Weather::RAIN)],
         );
-        let weather_vec = vec![Weather::Sunny];
+        let weather_vec = vec![Weather::RAIN];
         let result = forecast.find_next_n_patterns(
             3,
             EorzeaTime::from_esecs(10_000),
             &weather_vec,
             &weather_vec,
-            1000,
+            SearchHorizon::Periods(1000),
         );
         assert_eq!(result.len(), 3);
         assert_eq!(
@@ -245,4 +686,140 @@ mod tests {
                 .collect::<Vec<EorzeaTime>>()
         );
     }
+
+    #[test]
+    fn weather_equality() {
+        assert_eq!(Weather::from_id(3), Weather::CLOUDS);
+        assert!([Weather::FOG, Weather::from_id(3)].contains(&Weather::CLOUDS));
+        assert_eq!(Weather::CLOUDS.to_string(), "Clouds");
+        assert_eq!(Weather::from_id(999).to_string(), "Weather #999");
+    }
+
+    #[test]
+    fn weather_table() {
+        assert_eq!(Weather::UMBRAL_WIND.name(), Some("Umbral Wind"));
+        assert_eq!(Weather::GLOOM.icon(), Some(60218));
+        assert_eq!(Weather::from_id(999).icon(), None);
+        assert!(Weather::all().all(|w| w.name().is_some()));
+        assert_eq!(Weather::ROYAL_LEVIN.name(), Some("Royal Levin"));
+        assert_eq!(Weather::from_id(30).name(), Weather::FAIR_SKIES.name());
+        assert_eq!(Weather::CLOUDS.abbreviation(), Some("Cld"));
+        // Ids sharing a name share an abbreviation, and only those do.
+        let pairs: std::collections::HashSet<_> = Weather::all()
+            .map(|w| (w.name(), w.abbreviation()))
+            .collect();
+        let abbreviations: std::collections::HashSet<_> =
+            pairs.iter().map(|(_, abbreviation)| abbreviation).collect();
+        assert_eq!(abbreviations.len(), pairs.len());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn weather_deserialize_legacy() {
+        let legacy: Weather = serde_json::from_str(r#"{"Id": 4}"#).unwrap();
+        assert_eq!(legacy, Weather::FOG);
+        let plain: Weather = serde_json::from_str("4").unwrap();
+        assert_eq!(plain, Weather::FOG);
+        assert_eq!(serde_json::to_string(&Weather::FOG).unwrap(), "4");
+    }
+
+    #[test]
+    fn cached_scores() {
+        let forecast = WeatherForecast::eureka_pagos();
+        let uncached = |time| {
+            let max_score = forecast.by_score.len() as u8;
+            forecast.by_score[eorzea_weather_score(time, max_score).unwrap() as usize]
+        };
+        let start = EorzeaTime::new(1100, 3, 12, 0, 0, 0).unwrap();
+        let period = |n: u64| start + EorzeaDuration::from_bells(8 * n);
+        // Forward, then back before the first cached period, then past a gap,
+        // and at the last esec of a period, which rounds into the next one.
+        for n in (0..40).chain(0..5).chain(60..70) {
+            assert_eq!(*forecast.weather_at(period(n)), uncached(period(n)));
+            let last = period(n + 1) - EorzeaDuration::from_esecs(1);
+            assert_eq!(*forecast.weather_at(last), uncached(last));
+        }
+        let before = start - EorzeaDuration::from_bells(8 * 3);
+        assert_eq!(*forecast.weather_at(before), uncached(before));
+        assert_eq!(forecast.cache.borrow().scores.len(), 41);
+        // The integer period agrees with the rounded real time around period
+        // boundaries.
+        for n in 0..200u64 {
+            let boundary = start
+                .duration_since(EORZEA_ZERO_TIME)
+                .unwrap()
+                .total_seconds()
+                + n * 28800;
+            for esecs in boundary - 12..boundary + 12 {
+                let time = EorzeaTime::from_esecs(esecs);
+                let real = time.to_system_time().duration_since(UNIX_EPOCH).unwrap();
+                assert_eq!(weather_period(time), real.as_secs() / 1400);
+            }
+        }
+    }
+
+    #[test]
+    fn instanced_zones() {
+        let zones = [
+            WeatherForecast::diadem(),
+            WeatherForecast::eureka_anemos(),
+            WeatherForecast::eureka_pagos(),
+            WeatherForecast::eureka_pyros(),
+            WeatherForecast::eureka_hydatos(),
+        ];
+        for zone in &zones {
+            let all: Vec<Weather> = zone.weathers().into_iter().copied().collect();
+            assert_eq!(zone.probability(&all), 1.0, "{}", zone.region());
+            assert!(all.iter().all(|w| w.name().is_some()), "{}", zone.region());
+        }
+        assert_eq!(zones[1].probability(&[Weather::GALES]), 0.3);
+        assert_eq!(zones[2].probability(&[Weather::BLIZZARDS]), 0.18);
+        assert_eq!(zones[3].probability(&[Weather::UMBRAL_WIND]), 0.18);
+        assert_eq!(zones[4].probability(&[Weather::FAIR_SKIES]), 0.12);
+        // They share the 8-bell periods and scores of the open world: a
+        // weather period has one score everywhere, so Pagos and Pyros, whose
+        // thresholds match, change weather in step.
+        let start = EorzeaTime::new(1100, 3, 12, 0, 0, 0).unwrap();
+        let mut time = start;
+        for _ in 0..30 {
+            let pagos = zones[2].weather_at(time);
+            let pyros = zones[3].weather_at(time);
+            let slot = |zone: &WeatherForecast, weather| {
+                zone.weathers().iter().position(|w| *w == weather).unwrap()
+            };
+            assert_eq!(slot(&zones[2], pagos), slot(&zones[3], pyros));
+            time = time.next_weather_change();
+        }
+    }
+
+    #[test]
+    fn special_forecast() {
+        let period = EorzeaDuration::from_real(std::time::Duration::from_secs(600));
+        let forecast = SpecialForecast::new(
+            "".to_string(),
+            period,
+            vec![Weather::CLEAR_SKIES, Weather::FOG, Weather::RAIN],
+            1,
+        );
+        let at = |n: u64| EorzeaTime::from_esecs(n * period.total_seconds());
+        assert_eq!(Forecast::weather_at(&forecast, at(0)), &Weather::FOG);
+        assert_eq!(Forecast::weather_at(&forecast, at(1)), &Weather::RAIN);
+        assert_eq!(
+            Forecast::weather_at(&forecast, at(5)),
+            &Weather::CLEAR_SKIES
+        );
+        assert_eq!(
+            forecast.find_pattern(
+                at(2),
+                &[Weather::FOG],
+                &[Weather::RAIN],
+                SearchHorizon::Periods(10)
+            ),
+            Ok(at(4))
+        );
+        assert_eq!(
+            forecast.find_pattern(at(2), &[], &[Weather::WIND], SearchHorizon::Periods(10)),
+            Err(NoWindow { until: at(12) })
+        );
+    }
 }
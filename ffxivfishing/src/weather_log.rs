@@ -0,0 +1,199 @@
+//! A log of weathers seen in game, to check forecasts against. Each line is
+//! a `zone,unix_time,weather` CSV row; empty lines and lines starting with
+//! `#` are ignored.
+
+use std::{
+    fmt::Display,
+    fs::{self, File},
+    io::{self, Write},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{eorzea_time::EorzeaTime, fish::FishData};
+
+/// A weather seen in a zone at some time.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Observation {
+    pub zone: String,
+    /// Unix time in seconds.
+    pub time: u64,
+    pub weather: String,
+}
+
+#[derive(Debug)]
+pub enum LogError {
+    Io(io::Error),
+    /// A line that is not `zone,unix_time,weather`.
+    Parse {
+        line: usize,
+        message: String,
+    },
+}
+
+impl Display for LogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogError::Io(e) => write!(f, "{}", e),
+            LogError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for LogError {}
+
+impl From<io::Error> for LogError {
+    fn from(e: io::Error) -> Self {
+        LogError::Io(e)
+    }
+}
+
+impl Observation {
+    pub fn new(zone: String, time: SystemTime, weather: String) -> Observation {
+        Observation {
+            zone,
+            time: time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+            weather,
+        }
+    }
+
+    fn parse(line: usize, text: &str) -> Result<Observation, LogError> {
+        let error = |message: String| LogError::Parse { line, message };
+        let fields: Vec<&str> = text.split(',').map(str::trim).collect();
+        let [zone, time, weather] = fields[..] else {
+            return Err(error("expected zone,unix_time,weather".to_string()));
+        };
+        Ok(Observation {
+            zone: zone.to_string(),
+            time: time
+                .parse()
+                .map_err(|_| error(format!("invalid time {}", time)))?,
+            weather: weather.to_string(),
+        })
+    }
+
+    /// The forecast weather's name for the zone at the time, `None` if the
+    /// zone is unknown.
+    pub fn computed(&self, data: &FishData) -> Option<String> {
+        let region = data
+            .regions()
+            .iter()
+            .find(|r| r.name().eq_ignore_ascii_case(&self.zone))?;
+        let time = EorzeaTime::from_time(&(UNIX_EPOCH + Duration::from_secs(self.time))).ok()?;
+        Some(data.weather_name(region.weather().weather_at(time)))
+    }
+
+    /// Whether the forecast agrees, `None` if the zone is unknown.
+    pub fn matches_forecast(&self, data: &FishData) -> Option<bool> {
+        self.computed(data)
+            .map(|computed| computed.eq_ignore_ascii_case(&self.weather))
+    }
+}
+
+/// The observations in `text`, each with its line number.
+pub fn parse(text: &str) -> Result<Vec<(usize, Observation)>, LogError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(|(i, l)| Observation::parse(i + 1, l).map(|o| (i + 1, o)))
+        .collect()
+}
+
+pub fn read(path: &Path) -> Result<Vec<(usize, Observation)>, LogError> {
+    parse(&fs::read_to_string(path)?)
+}
+
+/// Adds a line to the log at `path`, creating it if needed.
+pub fn append(path: &Path, observation: &Observation) -> io::Result<()> {
+    let mut file = File::options().create(true).append(true).open(path)?;
+    writeln!(
+        file,
+        "{},{},{}",
+        observation.zone, observation.time, observation.weather
+    )
+}
+
+/// The observations of `zone`, ignoring case.
+pub fn in_zone<'a>(
+    observations: impl IntoIterator<Item = &'a Observation>,
+    zone: &'a str,
+) -> impl Iterator<Item = &'a Observation> {
+    observations
+        .into_iter()
+        .filter(move |o| o.zone.eq_ignore_ascii_case(zone))
+}
+
+/// The observations the forecast disagrees with, each with the computed
+/// weather. Unknown zones are left out.
+pub fn mismatches<'a>(
+    data: &FishData,
+    observations: impl IntoIterator<Item = &'a Observation>,
+) -> Vec<(&'a Observation, String)> {
+    observations
+        .into_iter()
+        .filter_map(|o| match o.matches_forecast(data) {
+            Some(false) => o.computed(data).map(|computed| (o, computed)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eorzea_time::EorzeaDuration, fixtures::*};
+
+    #[test]
+    fn parse_and_append() {
+        let text = "# zone,unix_time,weather\nFixture Marsh, 1000, Rain\n\nnot,a\n";
+        assert!(matches!(parse(text), Err(LogError::Parse { line: 4, .. })));
+        let observations = parse(&text[..text.len() - 6]).unwrap();
+        assert_eq!(
+            observations,
+            vec![(
+                2,
+                Observation {
+                    zone: "Fixture Marsh".to_string(),
+                    time: 1000,
+                    weather: "Rain".to_string()
+                }
+            )]
+        );
+
+        let path =
+            std::env::temp_dir().join(format!("ffxivfishing-weather-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        append(&path, &observations[0].1).unwrap();
+        append(&path, &observations[0].1).unwrap();
+        assert_eq!(read(&path).unwrap().len(), 2);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn checks_forecasts() {
+        let data = fixture_data();
+        let at = |time: EorzeaTime, weather: &str| {
+            Observation::new(
+                "fixture marsh".to_string(),
+                time.to_system_time(),
+                weather.to_string(),
+            )
+        };
+        // Clear of the period's start, which whole seconds might miss.
+        let time = epoch() + EorzeaDuration::new(1, 0, 0).unwrap();
+        let rain = at(time, "Rain");
+        let wrong = at(time, "Clouds");
+        let elsewhere = Observation {
+            zone: "Nowhere".to_string(),
+            ..rain.clone()
+        };
+        assert_eq!(rain.matches_forecast(&data), Some(true));
+        assert_eq!(elsewhere.matches_forecast(&data), None);
+        let observations = [rain, wrong.clone(), elsewhere];
+        assert_eq!(
+            mismatches(&data, &observations),
+            vec![(&wrong, "Rain".to_string())]
+        );
+        assert_eq!(in_zone(&observations, "Fixture Marsh").count(), 2);
+    }
+}